@@ -69,6 +69,12 @@
 //! The SDK provides several feature flags for customizing functionality:
 //! - `async`: Enable async support (default)
 //! - `std`: Enable standard library features (default)
+//! - `blocking`: Enable synchronous verification for callers without an async runtime
+//! - `cbor`: Enable CBOR serialization of messages
+//! - `compression`: Enable LZ4 payload compression in message pipelines
+//! - `kzg`: Enable KZG commitment opening checks for blob-carrying messages
+//! - `signing`: Enable ed25519 message signing and signature verification
+//! - `testing`: Enable seeded test vector generation for integration tests
 
 pub mod messages;
 pub mod types;
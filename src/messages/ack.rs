@@ -0,0 +1,105 @@
+//! Acknowledgements sent back to a message's source chain
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{skew, ChainId, FrostMessage};
+
+/// Metadata key marking a message as an acknowledgement, holding the ID of
+/// the acknowledged message.
+pub const ACK_FOR_KEY: &str = "ack_for";
+
+/// Outcome of processing a message on its destination chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AckStatus {
+    /// The message was executed
+    Executed,
+    /// Execution failed with the given reason
+    Failed(String),
+}
+
+/// Receipt for a processed message, carried as the payload of an
+/// acknowledgement message back to the source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Acknowledgement {
+    /// ID of the acknowledged message
+    pub message_id: Uuid,
+    /// Nonce of the acknowledged message
+    pub nonce: u64,
+    /// Processing outcome
+    pub status: AckStatus,
+}
+
+impl FrostMessage {
+    /// Build the acknowledgement of this message once it was processed.
+    ///
+    /// The acknowledgement is sent from this message's destination chain to
+    /// `to`, normally this message's source chain, with `nonce` taken from
+    /// that reverse corridor's sequence (e.g. a
+    /// [`NonceAllocator`](super::NonceAllocator)). Its payload is the JSON
+    /// encoded [`Acknowledgement`], and [`ACK_FOR_KEY`] records this
+    /// message's ID in its metadata; the source matches acknowledgements by
+    /// those, not by nonce.
+    ///
+    /// Despite the `into_` prefix this borrows the message, which usually
+    /// still has to be stored or reported after acknowledging it.
+    #[doc(alias = "to_ack")]
+    pub fn into_ack(&self, status: AckStatus, to: ChainId, nonce: u64) -> FrostMessage {
+        let ack = Acknowledgement {
+            message_id: self.id,
+            nonce: self.nonce,
+            status,
+        };
+        let payload = serde_json::to_vec(&ack).expect("acknowledgement serialization cannot fail");
+        let mut message = FrostMessage::new(self.to_chain, to, payload, nonce, skew::unix_now());
+        message
+            .metadata
+            .get_or_insert_with(Default::default)
+            .insert(ACK_FOR_KEY.to_string(), self.id.to_string());
+        message
+    }
+
+    /// Decode the acknowledgement carried by this message.
+    ///
+    /// Returns `None` unless the message is marked with [`ACK_FOR_KEY`] and
+    /// its payload is an acknowledgement of that same message.
+    pub fn acknowledgement(&self) -> Option<Acknowledgement> {
+        let ack_for: Uuid = self.metadata.as_ref()?.get(ACK_FOR_KEY)?.parse().ok()?;
+        let ack: Acknowledgement = serde_json::from_slice(&self.payload).ok()?;
+        (ack.message_id == ack_for).then_some(ack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ack_references_original_and_swaps_chains() {
+        let original = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"transfer".to_vec(), 9, 1_725_000_000);
+
+        let nonces = crate::messages::NonceAllocator::new();
+        nonces.observe(ChainId::Solana, ChainId::Ethereum, 2);
+
+        // The ack takes its nonce from the reverse corridor, not the original
+        let nonce = nonces.next(ChainId::Solana, ChainId::Ethereum);
+        let ack = original.into_ack(AckStatus::Executed, original.from_chain, nonce);
+        assert_eq!((ack.from_chain, ack.to_chain), (ChainId::Solana, ChainId::Ethereum));
+        assert_eq!(ack.nonce, 3);
+        assert_ne!(ack.id, original.id);
+        assert_eq!(
+            ack.acknowledgement(),
+            Some(Acknowledgement {
+                message_id: original.id,
+                nonce: 9,
+                status: AckStatus::Executed,
+            })
+        );
+
+        let failed = original.into_ack(AckStatus::Failed("reverted".to_string()), ChainId::Polkadot, 4);
+        assert_eq!((failed.from_chain, failed.to_chain), (ChainId::Solana, ChainId::Polkadot));
+        assert_eq!(failed.acknowledgement().unwrap().status, AckStatus::Failed("reverted".to_string()));
+
+        assert_eq!(original.acknowledgement(), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! Builder for validated message construction
+
+use super::{skew, ChainId, FrostMessage, Proof};
+use crate::types::AdapterError;
+
+/// Builder for [`FrostMessage`]
+///
+/// The message ID is generated on [`build`](Self::build), and the timestamp
+/// defaults to the current Unix time in seconds. Start one with
+/// [`FrostMessage::builder`].
+#[derive(Debug, Clone)]
+pub struct FrostMessageBuilder {
+    from_chain: ChainId,
+    to_chain: ChainId,
+    payload: Vec<u8>,
+    nonce: u64,
+    timestamp: Option<u64>,
+    proof: Option<Proof>,
+    signature: Option<Vec<u8>>,
+    fee: Option<u128>,
+    metadata: Vec<(String, String)>,
+    attachments: Vec<(String, Vec<u8>)>,
+}
+
+impl FrostMessageBuilder {
+    /// Start a message from `from_chain` to `to_chain`
+    pub fn new(from_chain: ChainId, to_chain: ChainId) -> Self {
+        Self {
+            from_chain,
+            to_chain,
+            payload: Vec::new(),
+            nonce: 0,
+            timestamp: None,
+            proof: None,
+            signature: None,
+            fee: None,
+            metadata: Vec::new(),
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Set the payload
+    pub fn payload(mut self, payload: impl Into<Vec<u8>>) -> Self {
+        self.payload = payload.into();
+        self
+    }
+
+    /// Set the nonce (default 0)
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Set the Unix timestamp in seconds (default now)
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Attach a proof
+    pub fn proof(mut self, proof: Proof) -> Self {
+        self.proof = Some(proof);
+        self
+    }
+
+    /// Set the signature
+    pub fn signature(mut self, signature: Vec<u8>) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
+    /// Set the relayer fee
+    pub fn fee(mut self, fee: u128) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Add a metadata entry, replacing an earlier one with the same key
+    pub fn metadata_entry(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), value.into()));
+        self
+    }
+
+    /// Add a named attachment
+    pub fn attachment(mut self, name: impl Into<String>, data: Vec<u8>) -> Self {
+        self.attachments.push((name.into(), data));
+        self
+    }
+
+    /// Build the message
+    ///
+    /// Fails with [`AdapterError::MessageFormat`] if the source and
+    /// destination chains are the same or the payload is empty.
+    pub fn build(self) -> Result<FrostMessage, AdapterError> {
+        if self.from_chain == self.to_chain {
+            return Err(AdapterError::MessageFormat(format!(
+                "source and destination chain are both {}",
+                self.from_chain
+            )));
+        }
+        if self.payload.is_empty() {
+            return Err(AdapterError::MessageFormat("empty payload".to_string()));
+        }
+
+        let timestamp = self.timestamp.unwrap_or_else(skew::unix_now);
+        let mut message = FrostMessage::new(self.from_chain, self.to_chain, self.payload, self.nonce, timestamp);
+        message.proof = self.proof;
+        message.signature = self.signature;
+        message.fee = self.fee;
+        message.metadata.get_or_insert_with(Default::default).extend(self.metadata);
+        message.attachments.extend(self.attachments);
+        Ok(message)
+    }
+}
+
+impl FrostMessage {
+    /// Start building a message from `from_chain` to `to_chain`
+    pub fn builder(from_chain: ChainId, to_chain: ChainId) -> FrostMessageBuilder {
+        FrostMessageBuilder::new(from_chain, to_chain)
+    }
+
+    /// Create a message carrying only a payload, timestamped now
+    ///
+    /// Pass a nonce from a [`NonceAllocator`](super::NonceAllocator) to
+    /// allocate one per corridor.
+    pub fn payload_only(from_chain: ChainId, to_chain: ChainId, payload: Vec<u8>, nonce: u64) -> Self {
+        Self::new(from_chain, to_chain, payload, nonce, skew::unix_now())
+    }
+
+    /// Create a message carrying a proof, timestamped now
+    pub fn with_proof(from_chain: ChainId, to_chain: ChainId, payload: Vec<u8>, proof: Proof, nonce: u64) -> Self {
+        let mut message = Self::payload_only(from_chain, to_chain, payload, nonce);
+        message.proof = Some(proof);
+        message
+    }
+
+    /// Create a message signed with a local ed25519 key, timestamped now
+    #[cfg(feature = "signing")]
+    pub fn signed(
+        from_chain: ChainId,
+        to_chain: ChainId,
+        payload: Vec<u8>,
+        signer: &super::LocalSigner,
+        nonce: u64,
+    ) -> Self {
+        let mut message = Self::payload_only(from_chain, to_chain, payload, nonce);
+        signer.sign_message(&mut message);
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{PROTOCOL_VERSION, SENDER_KEY};
+
+    #[test]
+    fn test_builder_sets_fields() {
+        let message = FrostMessage::builder(ChainId::Ethereum, ChainId::Solana)
+            .payload(b"test".to_vec())
+            .nonce(7)
+            .timestamp(1_725_000_000)
+            .signature(vec![0xAB; 64])
+            .fee(10)
+            .metadata_entry(SENDER_KEY, "0xbridge")
+            .attachment("receipt", vec![1, 2])
+            .build()
+            .unwrap();
+
+        assert_eq!(message.payload, b"test");
+        assert_eq!((message.nonce, message.timestamp, message.fee), (7, 1_725_000_000, Some(10)));
+        assert_eq!(message.signature, Some(vec![0xAB; 64]));
+        assert_eq!(message.sender(), Some("0xbridge"));
+        assert_eq!(message.protocol_version(), Some(PROTOCOL_VERSION));
+        assert_eq!(message.attachment("receipt"), Some([1u8, 2].as_slice()));
+        assert!(message.validate_id().is_ok());
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let before = skew::unix_now();
+        let first = FrostMessage::builder(ChainId::Ethereum, ChainId::Solana).payload(b"a".to_vec()).build().unwrap();
+        let second = FrostMessage::builder(ChainId::Ethereum, ChainId::Solana).payload(b"a".to_vec()).build().unwrap();
+
+        assert_ne!(first.id, second.id);
+        assert!(first.timestamp >= before && first.timestamp <= skew::unix_now());
+        assert!(first.proof.is_none() && first.signature.is_none() && first.fee.is_none());
+    }
+
+    #[test]
+    fn test_convenience_constructors() {
+        let before = skew::unix_now();
+        let allocator = crate::messages::NonceAllocator::new();
+
+        let plain = FrostMessage::payload_only(
+            ChainId::Ethereum,
+            ChainId::Solana,
+            b"test".to_vec(),
+            allocator.next(ChainId::Ethereum, ChainId::Solana),
+        );
+        assert_eq!((plain.payload.as_slice(), plain.nonce), (b"test".as_slice(), 0));
+        assert!(plain.timestamp >= before && plain.timestamp <= skew::unix_now());
+        assert!(plain.proof.is_none() && plain.signature.is_none());
+
+        let proof = Proof {
+            data: vec![1, 2, 3],
+            metadata: frostgate_zkip::types::ProofMetadata {
+                generation_time: std::time::Duration::from_secs(1),
+                proof_size: 3,
+                program_hash: "dummy".to_string(),
+                timestamp: std::time::SystemTime::now(),
+            },
+        };
+        let proven = FrostMessage::with_proof(
+            ChainId::Ethereum,
+            ChainId::Solana,
+            b"test".to_vec(),
+            proof,
+            allocator.next(ChainId::Ethereum, ChainId::Solana),
+        );
+        assert_eq!(proven.nonce, 1);
+        assert_eq!(proven.proof.unwrap().data, vec![1, 2, 3]);
+        assert!(proven.signature.is_none());
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_signed_constructor() {
+        let signer = crate::messages::LocalSigner::from_bytes(&[7u8; 32]);
+        let message = FrostMessage::signed(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), &signer, 3);
+
+        assert_eq!(message.nonce, 3);
+        assert!(message.proof.is_none());
+        assert!(message.verify_signature(&signer.public_key()).unwrap());
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        let same_chain = FrostMessage::builder(ChainId::Ethereum, ChainId::Ethereum).payload(b"a".to_vec()).build();
+        assert!(matches!(same_chain, Err(AdapterError::MessageFormat(_))));
+
+        let empty = FrostMessage::builder(ChainId::Ethereum, ChainId::Solana).build();
+        assert!(matches!(empty, Err(AdapterError::MessageFormat(_))));
+    }
+}
@@ -0,0 +1,78 @@
+//! Size of messages as submitted to their destination chain
+
+use super::{ChainId, FrostMessage};
+use crate::types::ChainCapabilities;
+
+impl FrostMessage {
+    /// Size in bytes of the message as encoded for its destination chain
+    ///
+    /// This is the length of the encoding hashed by
+    /// [`onchain_digest`](Self::onchain_digest) for `to_chain`, which the
+    /// built-in [`MessageSerializer`](crate::traits::MessageSerializer)s
+    /// submit. The proof is not included.
+    pub fn calldata_size(&self) -> usize {
+        match self.to_chain {
+            ChainId::Ethereum => self.abi_encoding().len(),
+            ChainId::Polkadot => self.scale_encoding().len(),
+            ChainId::Solana => self.borsh_encoding().len(),
+            ChainId::Unknown => self.canonical_bytes().len(),
+        }
+    }
+
+    /// Check that the message fits the destination chain's message size limit
+    ///
+    /// Returns `Err` with the number of bytes over
+    /// [`ChainCapabilities::max_message_size`] if it does not, i.e. how much
+    /// the payload must be trimmed by. On Ethereum the payload is padded to
+    /// 32-byte words, so trimming less than a word may not reduce the size.
+    /// Proofs are limited separately by
+    /// [`ChainCapabilities::max_proof_size`].
+    pub fn fits_calldata(&self, caps: &ChainCapabilities) -> Result<(), usize> {
+        match self.calldata_size().checked_sub(caps.max_message_size) {
+            Some(excess) if excess > 0 => Err(excess),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::types::FinalityType;
+
+    fn caps(max_message_size: usize) -> ChainCapabilities {
+        ChainCapabilities {
+            supports_smart_contracts: true,
+            supports_native_tokens: true,
+            supports_onchain_verification: true,
+            max_message_size,
+            proof_types: Vec::new(),
+            finality_type: FinalityType::Deterministic,
+            max_proof_size: None,
+            supports_parallel_execution: false,
+            features: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn message_within_limit_fits() {
+        // Solana: two chain IDs, length prefix, payload, nonce and timestamp
+        let message = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![0u8; 100], 1, 1_725_000_000);
+        assert_eq!(message.calldata_size(), 8 + 8 + 4 + 100 + 8 + 8);
+        assert_eq!(message.fits_calldata(&caps(136)), Ok(()));
+        assert_eq!(message.fits_calldata(&caps(1024)), Ok(()));
+    }
+
+    #[test]
+    fn oversized_message_reports_excess() {
+        let message = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![0u8; 100], 1, 1_725_000_000);
+        assert_eq!(message.fits_calldata(&caps(100)), Err(36));
+
+        // Ethereum: six head words and the payload padded to 32 bytes
+        let message = FrostMessage::new(ChainId::Solana, ChainId::Ethereum, vec![0u8; 100], 1, 1_725_000_000);
+        assert_eq!(message.calldata_size(), 6 * 32 + 128);
+        assert_eq!(message.fits_calldata(&caps(300)), Err(20));
+    }
+}
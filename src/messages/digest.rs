@@ -0,0 +1,199 @@
+//! Message digests matching on-chain message identification
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Blake2b512, Digest};
+use sha2::Sha256;
+use sha3::Keccak256;
+
+use super::{ChainId, FrostMessage};
+
+impl FrostMessage {
+    /// Compute the digest a destination chain's contracts use to identify
+    /// this message.
+    ///
+    /// The preimage covers the same fields as
+    /// [`canonical_bytes`](Self::canonical_bytes), encoded the way each
+    /// chain's runtime does:
+    ///
+    /// - **Ethereum**: `keccak256(abi.encode(uint256 from_chain,
+    ///   uint256 to_chain, uint256 nonce, uint256 timestamp, bytes payload))`
+    /// - **Polkadot**: `blake2_256` over the SCALE encoding of
+    ///   `(u64 from_chain, u64 to_chain, Vec<u8> payload, u64 nonce,
+    ///   u64 timestamp)`, i.e. little-endian integers and a compact length
+    ///   prefix on the payload
+    /// - **Solana**: `sha256` over the Borsh encoding of the same tuple,
+    ///   i.e. little-endian integers and a `u32` length prefix on the payload
+    /// - **Unknown**: `sha256` over [`canonical_bytes`](Self::canonical_bytes)
+    ///
+    /// Chain IDs are encoded with [`ChainId::to_u64`]. Attachments are not
+    /// part of the on-chain digest.
+    pub fn onchain_digest(&self, chain: ChainId) -> [u8; 32] {
+        match chain {
+            ChainId::Ethereum => Keccak256::digest(self.abi_encoding()).into(),
+            ChainId::Polkadot => Blake2b::<U32>::digest(self.scale_encoding()).into(),
+            ChainId::Solana => Sha256::digest(self.borsh_encoding()).into(),
+            ChainId::Unknown => Sha256::digest(self.canonical_bytes()).into(),
+        }
+    }
+
+    /// Content hash of the message for deduplication and signing.
+    ///
+    /// Blake2b-512 truncated to 32 bytes, the verifier's default program
+    /// hash, over [`canonical_bytes`](Self::canonical_bytes): `from_chain`,
+    /// `to_chain`, `payload`, `nonce` and `timestamp` in that order, followed
+    /// by any attachments. The `id`, `proof`, `signature`, `fee` and
+    /// `metadata` fields are excluded, so the hash is stable across relay
+    /// hops. The encoding is recomputed on every call, so the hash always
+    /// reflects the current field values, even after direct field writes.
+    pub fn canonical_hash(&self) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&Blake2b512::digest(self.canonical_bytes())[..32]);
+        hash
+    }
+
+    /// Solidity `abi.encode` of the message fields
+    pub(crate) fn abi_encoding(&self) -> Vec<u8> {
+        fn word(value: u64) -> [u8; 32] {
+            let mut word = [0u8; 32];
+            word[24..].copy_from_slice(&value.to_be_bytes());
+            word
+        }
+
+        let padded_len = self.payload.len().div_ceil(32) * 32;
+        let mut bytes = Vec::with_capacity(6 * 32 + padded_len);
+        bytes.extend_from_slice(&word(self.from_chain.to_u64()));
+        bytes.extend_from_slice(&word(self.to_chain.to_u64()));
+        bytes.extend_from_slice(&word(self.nonce));
+        bytes.extend_from_slice(&word(self.timestamp));
+        // Offset of the dynamic `bytes` tail, after the five head words
+        bytes.extend_from_slice(&word(5 * 32));
+        bytes.extend_from_slice(&word(self.payload.len() as u64));
+        bytes.extend_from_slice(&self.payload);
+        bytes.resize(6 * 32 + padded_len, 0);
+        bytes
+    }
+
+    /// SCALE encoding of the message fields
+    pub(crate) fn scale_encoding(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(37 + self.payload.len());
+        bytes.extend_from_slice(&self.from_chain.to_u64().to_le_bytes());
+        bytes.extend_from_slice(&self.to_chain.to_u64().to_le_bytes());
+        scale_compact(self.payload.len() as u64, &mut bytes);
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&self.nonce.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        bytes
+    }
+
+    /// Borsh encoding of the message fields
+    pub(crate) fn borsh_encoding(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36 + self.payload.len());
+        bytes.extend_from_slice(&self.from_chain.to_u64().to_le_bytes());
+        bytes.extend_from_slice(&self.to_chain.to_u64().to_le_bytes());
+        bytes.extend_from_slice(&(self.payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&self.nonce.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        bytes
+    }
+}
+
+/// Append a SCALE compact-encoded integer
+fn scale_compact(value: u64, out: &mut Vec<u8>) {
+    match value {
+        0..=0x3f => out.push((value as u8) << 2),
+        0x40..=0x3fff => out.extend_from_slice(&(((value as u16) << 2) | 0b01).to_le_bytes()),
+        0x4000..=0x3fff_ffff => out.extend_from_slice(&(((value as u32) << 2) | 0b10).to_le_bytes()),
+        _ => {
+            let len = 8 - value.leading_zeros() as usize / 8;
+            out.push((((len - 4) as u8) << 2) | 0b11);
+            out.extend_from_slice(&value.to_le_bytes()[..len]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Polkadot, b"hello".to_vec(), 7, 1_725_000_000)
+    }
+
+    #[test]
+    fn test_evm_digest() {
+        assert_eq!(
+            hex::encode(message().onchain_digest(ChainId::Ethereum)),
+            "8dcbf838b1d41eddb08791ef9a09e9ed05caf470613470e492725dc8356dc301"
+        );
+    }
+
+    #[test]
+    fn test_substrate_digest() {
+        assert_eq!(
+            hex::encode(message().onchain_digest(ChainId::Polkadot)),
+            "8ee5034a68e9685221395f292936d261d4189fb5a2270b1a2c2313e1f40e67f9"
+        );
+    }
+
+    #[test]
+    fn test_solana_digest() {
+        assert_eq!(
+            hex::encode(message().onchain_digest(ChainId::Solana)),
+            "c6eaf796cb0e8d1d3502eee440ffaf023308c9dc6dcacf31738ad07c887d246b"
+        );
+    }
+
+    #[test]
+    fn test_unknown_chain_digest() {
+        let msg = message();
+        assert_eq!(msg.onchain_digest(ChainId::Unknown), <[u8; 32]>::from(Sha256::digest(msg.canonical_bytes())));
+    }
+
+    #[test]
+    fn test_canonical_hash_ignores_relay_fields() {
+        let msg = message();
+        let mut relayed = msg.clone();
+        relayed.id = uuid::Uuid::new_v4();
+        relayed.fee = Some(100);
+        relayed.signature = Some(vec![1; 64]);
+        relayed.metadata.get_or_insert_with(Default::default).insert("hop".to_string(), "2".to_string());
+        assert_eq!(relayed.canonical_hash(), msg.canonical_hash());
+
+        let mut other = msg.clone();
+        other.set_nonce(8);
+        assert_ne!(other.canonical_hash(), msg.canonical_hash());
+    }
+
+    #[test]
+    fn test_digest_ignores_relay_fields() {
+        let msg = message();
+        let mut relayed = msg.clone();
+        relayed.fee = Some(100);
+        relayed.signature = Some(vec![1; 64]);
+        assert_eq!(msg.onchain_digest(ChainId::Ethereum), relayed.onchain_digest(ChainId::Ethereum));
+    }
+
+    #[test]
+    fn test_scale_compact() {
+        let encode = |value| {
+            let mut out = Vec::new();
+            scale_compact(value, &mut out);
+            out
+        };
+        assert_eq!(encode(1), vec![0x04]);
+        assert_eq!(encode(64), vec![0x01, 0x01]);
+        assert_eq!(encode(16_384), vec![0x02, 0x00, 0x01, 0x00]);
+        assert_eq!(encode(1 << 30), vec![0x03, 0x00, 0x00, 0x00, 0x40]);
+    }
+
+    #[test]
+    fn test_canonical_hash_tracks_direct_writes() {
+        let mut msg = message();
+        let before = msg.canonical_hash();
+        msg.nonce += 1;
+        assert_ne!(msg.canonical_hash(), before);
+        msg.nonce -= 1;
+        assert_eq!(msg.canonical_hash(), before);
+    }
+}
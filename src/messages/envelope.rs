@@ -0,0 +1,114 @@
+//! Self-describing envelope for proof bytes
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::AdapterError;
+
+/// Magic prefix identifying an enveloped proof
+const MAGIC: &[u8; 4] = b"FGPE";
+
+/// Borrowed proof system, version and inner bytes of an enveloped proof
+pub type EnvelopeParts<'a> = (&'a str, u32, &'a [u8]);
+
+/// Proof bytes tagged with the proof system and version that produced them
+///
+/// On the wire the envelope is the magic `FGPE`, the system name length
+/// (u16, big-endian), the system name (UTF-8), the version (u32,
+/// big-endian), then the backend's proof bytes. Proofs without the magic
+/// prefix are untagged raw backend proofs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SerializedProof {
+    /// Proof system name, e.g. `sp1` or `groth16`
+    pub system: String,
+    /// Proof system version
+    pub version: u32,
+    /// Proof bytes in the backend's own format
+    pub bytes: Vec<u8>,
+}
+
+impl SerializedProof {
+    /// Tag proof bytes with their proof system
+    pub fn new(system: impl Into<String>, version: u32, bytes: Vec<u8>) -> Self {
+        Self {
+            system: system.into(),
+            version,
+            bytes,
+        }
+    }
+
+    /// Encode the envelope for use as `Proof::data`
+    ///
+    /// Fails if the system name is longer than `u16::MAX` bytes.
+    pub fn encode(&self) -> Result<Vec<u8>, AdapterError> {
+        let system_len = u16::try_from(self.system.len()).map_err(|_| {
+            AdapterError::MessageFormat(format!("proof system name of {} bytes is too long", self.system.len()))
+        })?;
+        let mut out = Vec::with_capacity(MAGIC.len() + 2 + self.system.len() + 4 + self.bytes.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&system_len.to_be_bytes());
+        out.extend_from_slice(self.system.as_bytes());
+        out.extend_from_slice(&self.version.to_be_bytes());
+        out.extend_from_slice(&self.bytes);
+        Ok(out)
+    }
+
+    /// Decode an envelope, failing on untagged or malformed data
+    pub fn decode(data: &[u8]) -> Result<Self, AdapterError> {
+        let (system, version, bytes) = Self::split(data)?
+            .ok_or_else(|| AdapterError::MessageFormat("proof is not enveloped".to_string()))?;
+        Ok(Self::new(system, version, bytes.to_vec()))
+    }
+
+    /// Read the header of possibly enveloped proof data without copying
+    ///
+    /// Returns `None` for untagged data, or the system, version and inner
+    /// proof bytes.
+    pub fn split(data: &[u8]) -> Result<Option<EnvelopeParts<'_>>, AdapterError> {
+        let Some(rest) = data.strip_prefix(MAGIC) else {
+            return Ok(None);
+        };
+        let truncated = || AdapterError::MessageFormat("truncated proof envelope".to_string());
+        let (len, rest) = rest.split_first_chunk::<2>().ok_or_else(truncated)?;
+        let len = u16::from_be_bytes(*len) as usize;
+        if rest.len() < len {
+            return Err(truncated());
+        }
+        let (system, rest) = rest.split_at(len);
+        let system = std::str::from_utf8(system)
+            .map_err(|_| AdapterError::MessageFormat("proof system name is not UTF-8".to_string()))?;
+        let (version, bytes) = rest.split_first_chunk::<4>().ok_or_else(truncated)?;
+        Ok(Some((system, u32::from_be_bytes(*version), bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_round_trip() {
+        let proof = SerializedProof::new("groth16", 2, vec![1, 2, 3, 4]);
+        let encoded = proof.encode().unwrap();
+        assert_eq!(SerializedProof::decode(&encoded).unwrap(), proof);
+        assert_eq!(
+            SerializedProof::split(&encoded).unwrap(),
+            Some(("groth16", 2, [1u8, 2, 3, 4].as_slice()))
+        );
+
+        let empty = SerializedProof::new("", 0, vec![]);
+        assert_eq!(SerializedProof::decode(&empty.encode().unwrap()).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_untagged_and_malformed_envelopes() {
+        assert_eq!(SerializedProof::split(&[1, 2, 3, 4]).unwrap(), None);
+        assert!(SerializedProof::decode(&[1, 2, 3, 4]).is_err());
+
+        let encoded = SerializedProof::new("sp1", 1, vec![9; 8]).encode().unwrap();
+        assert!(SerializedProof::split(&encoded[..8]).is_err());
+        assert!(SerializedProof::split(&encoded[..6]).is_err());
+
+        let oversized = SerializedProof::new("x".repeat(u16::MAX as usize + 1), 1, vec![]);
+        assert!(oversized.encode().is_err());
+    }
+}
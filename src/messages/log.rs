@@ -0,0 +1,130 @@
+//! Raw on-chain logs behind message events
+
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use crate::types::AdapterError;
+
+/// An EVM-style log as emitted on-chain
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct RawLog {
+    /// Address of the emitting contract
+    pub address: Vec<u8>,
+    /// Indexed topics; the first is the event signature hash
+    pub topics: Vec<[u8; 32]>,
+    /// ABI-encoded non-indexed event data
+    pub data: Vec<u8>,
+}
+
+/// A typed event that can be decoded from a [`RawLog`]
+pub trait LogEvent: Sized {
+    /// Solidity event signature, e.g. `MessageSent(bytes32,uint64)`
+    const SIGNATURE: &'static str;
+
+    /// Topic identifying the event: `keccak256(SIGNATURE)`
+    fn topic() -> [u8; 32] {
+        Keccak256::digest(Self::SIGNATURE.as_bytes()).into()
+    }
+
+    /// Decode the event from a log whose first topic matches [`topic`](Self::topic)
+    fn decode_log(log: &RawLog) -> Result<Self, AdapterError>;
+}
+
+impl RawLog {
+    /// Decode the log as `E`, or `None` if it is a different event
+    pub fn decode<E: LogEvent>(&self) -> Result<Option<E>, AdapterError> {
+        if self.topics.first() != Some(&E::topic()) {
+            return Ok(None);
+        }
+        E::decode_log(self).map(Some)
+    }
+
+    /// Get an indexed topic, failing if the log has too few
+    pub fn topic(&self, index: usize) -> Result<[u8; 32], AdapterError> {
+        self.topics.get(index).copied().ok_or_else(|| {
+            AdapterError::MessageFormat(format!("log has no topic {}", index))
+        })
+    }
+
+    /// Get the `index`th 32-byte word of the data
+    pub fn data_word(&self, index: usize) -> Result<[u8; 32], AdapterError> {
+        self.data
+            .get(index * 32..(index + 1) * 32)
+            .and_then(|word| word.try_into().ok())
+            .ok_or_else(|| AdapterError::MessageFormat(format!("log data has no word {}", index)))
+    }
+}
+
+/// Interpret a 32-byte ABI word as a `uint64`, rejecting larger values
+pub fn word_to_u64(word: &[u8; 32]) -> Result<u64, AdapterError> {
+    if word[..24].iter().any(|byte| *byte != 0) {
+        return Err(AdapterError::MessageFormat("ABI word does not fit in u64".to_string()));
+    }
+    Ok(u64::from_be_bytes(word[24..].try_into().expect("8-byte slice")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sample event: MessageSent(bytes32 indexed messageHash, uint64 nonce, uint64 toChain)
+    #[derive(Debug, PartialEq)]
+    struct MessageSent {
+        message_hash: [u8; 32],
+        nonce: u64,
+        to_chain: u64,
+    }
+
+    impl LogEvent for MessageSent {
+        const SIGNATURE: &'static str = "MessageSent(bytes32,uint64,uint64)";
+
+        fn decode_log(log: &RawLog) -> Result<Self, AdapterError> {
+            Ok(Self {
+                message_hash: log.topic(1)?,
+                nonce: word_to_u64(&log.data_word(0)?)?,
+                to_chain: word_to_u64(&log.data_word(1)?)?,
+            })
+        }
+    }
+
+    fn word(value: u64) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn test_decode_raw_log() {
+        let log = RawLog {
+            address: vec![0xAA; 20],
+            topics: vec![MessageSent::topic(), [7u8; 32]],
+            data: [word(42), word(2)].concat(),
+        };
+
+        assert_eq!(
+            log.decode::<MessageSent>().unwrap(),
+            Some(MessageSent {
+                message_hash: [7u8; 32],
+                nonce: 42,
+                to_chain: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_other_or_malformed_log() {
+        let other = RawLog {
+            topics: vec![[1u8; 32]],
+            ..Default::default()
+        };
+        assert_eq!(other.decode::<MessageSent>().unwrap(), None);
+
+        let truncated = RawLog {
+            topics: vec![MessageSent::topic(), [7u8; 32]],
+            data: word(42).to_vec(),
+            ..Default::default()
+        };
+        assert!(matches!(truncated.decode::<MessageSent>(), Err(AdapterError::MessageFormat(_))));
+        assert!(word_to_u64(&[0xFF; 32]).is_err());
+    }
+}
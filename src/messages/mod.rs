@@ -4,9 +4,35 @@
 #![allow(unused_imports)]
 
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use frostgate_zkip::types::ProofMetadata;
+use crate::types::AdapterError;
+
+mod ack;
+mod builder;
+mod calldata;
+mod digest;
+mod envelope;
+mod log;
+mod nonce;
+mod reader;
+mod signing;
+mod skew;
+mod transaction;
+
+pub use ack::{AckStatus, Acknowledgement, ACK_FOR_KEY};
+pub use builder::FrostMessageBuilder;
+pub use envelope::{EnvelopeParts, SerializedProof};
+pub use log::{word_to_u64, LogEvent, RawLog};
+pub use nonce::NonceAllocator;
+#[cfg(feature = "signing")]
+pub use signing::LocalSigner;
+pub use signing::{RemoteSigner, Signer};
+pub use skew::SkewTolerance;
+pub use transaction::VERIFICATION_KEY_ATTACHMENT;
 
 /// Supported chain identifiers. Extend as needed for more chains.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Copy)]
@@ -24,6 +50,9 @@ pub enum ChainId {
 
 impl ChainId {
     /// Convert chain ID to u64 for serialization
+    ///
+    /// This is the inverse of the `TryFrom<u64>` conversion. `Unknown` is `u64::MAX`, and every value without a chain
+    /// of its own converts back to `Unknown`.
     pub fn to_u64(&self) -> u64 {
         match self {
             ChainId::Ethereum => 0,
@@ -32,6 +61,31 @@ impl ChainId {
             ChainId::Unknown => u64::MAX,
         }
     }
+
+    /// Typical block time of the chain, if known
+    pub fn block_time(&self) -> Option<Duration> {
+        match self {
+            ChainId::Ethereum => Some(Duration::from_secs(12)),
+            ChainId::Polkadot => Some(Duration::from_secs(6)),
+            ChainId::Solana => Some(Duration::from_millis(400)),
+            ChainId::Unknown => None,
+        }
+    }
+}
+
+/// Chain IDs are ordered by their numeric value (see [`ChainId::to_u64`]),
+/// so `Unknown` sorts last. This ordering is stable and safe to rely on for
+/// canonical encodings and `BTreeMap` keys.
+impl PartialOrd for ChainId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ChainId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_u64().cmp(&other.to_u64())
+    }
 }
 
 impl std::fmt::Display for ChainId {
@@ -46,13 +100,20 @@ impl std::fmt::Display for ChainId {
     }
 }
 
+impl From<ChainId> for u64 {
+    fn from(chain_id: ChainId) -> u64 {
+        chain_id.to_u64()
+    }
+}
+
 impl std::convert::TryFrom<u64> for ChainId {
     type Error = ();
 
     /// Attempts to convert a u64 into a ChainId.
     /// 
     /// # Errors
-    /// Returns `Ok(ChainId::Unknown)` for unrecognized chain IDs.
+    /// Returns `Ok(ChainId::Unknown)` for unrecognized chain IDs, including
+    /// `u64::MAX`, the value of [`ChainId::Unknown`] itself.
     fn try_from(value: u64) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(ChainId::Ethereum),
@@ -72,10 +133,78 @@ pub struct Proof {
     pub metadata: ProofMetadata,
 }
 
+/// Metadata key set when a signed field is mutated after signing.
+pub const SIGNATURE_STALE_KEY: &str = "signature_stale";
+
+/// Metadata key declaring the input encoding version the proof was generated
+/// against (see `verification::InputEncoding`).
+pub const INPUT_ENCODING_VERSION_KEY: &str = "input_encoding_version";
+
+/// Metadata key declaring the account that originated the message.
+pub const SENDER_KEY: &str = "sender";
+
+/// Metadata key declaring the source chain block height the proof attests to.
+pub const PROOF_BLOCK_HEIGHT_KEY: &str = "proof_block_height";
+
+/// Metadata key declaring the hex-encoded source block hash the proof
+/// commits to.
+pub const PROOF_BLOCK_HASH_KEY: &str = "proof_block_hash";
+
+/// Metadata key set while a message waits for an asynchronously generated proof.
+pub const PROOF_PENDING_KEY: &str = "proof_pending";
+
+/// Version of the message format implemented by this SDK, as `MAJOR.MINOR`.
+pub const PROTOCOL_VERSION: &str = "1.0";
+
+/// Metadata key recording the protocol version a message was created with.
+pub const PROTOCOL_VERSION_KEY: &str = "protocol_version";
+
+/// Metadata keys the SDK interprets, which untrusted sources such as
+/// fetched transactions must not set.
+pub const RESERVED_METADATA_KEYS: &[&str] = &[
+    SIGNATURE_STALE_KEY,
+    INPUT_ENCODING_VERSION_KEY,
+    SENDER_KEY,
+    PROOF_BLOCK_HEIGHT_KEY,
+    PROOF_BLOCK_HASH_KEY,
+    PROOF_PENDING_KEY,
+    PROTOCOL_VERSION_KEY,
+    ACK_FOR_KEY,
+    crate::traits::PAYLOAD_ENCODING_KEY,
+];
+
+/// Version assumed for messages created before versioning was introduced.
+const LEGACY_PROTOCOL_VERSION: &str = "1.0";
+
+/// Where a message stands with respect to its proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofState {
+    /// No proof is attached or expected
+    Absent,
+    /// The message was queued before its proof was ready
+    Pending,
+    /// A proof is attached
+    Attached,
+}
+
+/// Memoized canonical encoding of a [`FrostMessage`], empty by default.
+#[derive(Debug, Clone, Default)]
+pub struct CanonicalCache(OnceLock<Vec<u8>>);
+
 /// The canonical cross-chain message structure for Frostgate.
 ///
 /// Includes all data necessary for verification and replay protection.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// [`canonical_bytes_cached`](Self::canonical_bytes_cached) memoizes the
+/// canonical encoding. The setters keep it up to date, but code that assigns
+/// `payload`, `nonce`, `timestamp`, the chain IDs or `attachments` directly
+/// must call [`invalidate`](Self::invalidate) afterwards. Struct literals
+/// initialize the cache with `canonical_cache: Default::default()`.
+///
+/// Human-readable formats such as JSON omit the optional fields when they
+/// are absent. Binary formats such as bincode are not self-describing and
+/// always carry every field.
+#[derive(Deserialize, Debug, Clone)]
 pub struct FrostMessage {
     /// Unique message ID (UUID v4 for global uniqueness).
     pub id: Uuid,
@@ -86,17 +215,61 @@ pub struct FrostMessage {
     /// Arbitrary user/application payload (should be encoded as required).
     pub payload: Vec<u8>,
     /// Zero-knowledge proof attached to the message (optional for some flows).
+    #[serde(default)]
     pub proof: Option<Proof>,
     /// Unix timestamp (seconds) for message creation.
     pub timestamp: u64,
     /// Per-sender nonce for replay protection.
     pub nonce: u64,
     /// Optional cryptographic signature (by relayer/operator, not always required).
+    #[serde(default)]
     pub signature: Option<Vec<u8>>,
     /// Optional relayer or protocol fee (in smallest unit of source chain).
+    #[serde(default)]
     pub fee: Option<u128>,
     /// Extensible metadata for debugging, audit, or protocol extensions.
+    #[serde(default)]
     pub metadata: Option<HashMap<String, String>>,
+    /// Named binary attachments (receipts, logs, ...), covered by the signature.
+    #[serde(default)]
+    pub attachments: HashMap<String, Vec<u8>>,
+    /// Memoized canonical encoding, see [`canonical_bytes_cached`](Self::canonical_bytes_cached).
+    #[serde(skip)]
+    pub canonical_cache: CanonicalCache,
+}
+
+impl Serialize for FrostMessage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        fn optional<S: SerializeStruct, T: Serialize>(
+            state: &mut S,
+            compact: bool,
+            name: &'static str,
+            value: &Option<T>,
+        ) -> Result<(), S::Error> {
+            if compact && value.is_none() {
+                state.skip_field(name)
+            } else {
+                state.serialize_field(name, value)
+            }
+        }
+
+        let compact = serializer.is_human_readable();
+        let mut state = serializer.serialize_struct("FrostMessage", 11)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("from_chain", &self.from_chain)?;
+        state.serialize_field("to_chain", &self.to_chain)?;
+        state.serialize_field("payload", &self.payload)?;
+        optional(&mut state, compact, "proof", &self.proof)?;
+        state.serialize_field("timestamp", &self.timestamp)?;
+        state.serialize_field("nonce", &self.nonce)?;
+        optional(&mut state, compact, "signature", &self.signature)?;
+        optional(&mut state, compact, "fee", &self.fee)?;
+        optional(&mut state, compact, "metadata", &self.metadata)?;
+        state.serialize_field("attachments", &self.attachments)?;
+        state.end()
+    }
 }
 
 impl FrostMessage {
@@ -125,11 +298,425 @@ impl FrostMessage {
             nonce,
             signature: None,
             fee: None,
-            metadata: None,
+            metadata: Some(HashMap::from([(
+                PROTOCOL_VERSION_KEY.to_string(),
+                PROTOCOL_VERSION.to_string(),
+            )])),
+            attachments: HashMap::new(),
+            canonical_cache: CanonicalCache::default(),
+        }
+    }
+
+    /// Deterministic encoding of the message content.
+    ///
+    /// Fields are encoded in this order, integers as big-endian:
+    /// `from_chain` (u64), `to_chain` (u64), payload length (u64), `payload`,
+    /// `nonce` (u64), `timestamp` (u64). If the message has attachments,
+    /// their count (u64) follows, then each attachment sorted by name as
+    /// name length (u64), name, data length (u64), data. The `id`, `proof`,
+    /// `signature`, `fee` and `metadata` fields are excluded so the encoding
+    /// is stable across relay hops.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(40 + self.payload.len());
+        bytes.extend_from_slice(&self.from_chain.to_u64().to_be_bytes());
+        bytes.extend_from_slice(&self.to_chain.to_u64().to_be_bytes());
+        bytes.extend_from_slice(&(self.payload.len() as u64).to_be_bytes());
+        bytes.extend_from_slice(&self.payload);
+        bytes.extend_from_slice(&self.nonce.to_be_bytes());
+        bytes.extend_from_slice(&self.timestamp.to_be_bytes());
+        if !self.attachments.is_empty() {
+            let mut attachments: Vec<_> = self.attachments.iter().collect();
+            attachments.sort_by_key(|(name, _)| *name);
+            bytes.extend_from_slice(&(attachments.len() as u64).to_be_bytes());
+            for (name, data) in attachments {
+                bytes.extend_from_slice(&(name.len() as u64).to_be_bytes());
+                bytes.extend_from_slice(name.as_bytes());
+                bytes.extend_from_slice(&(data.len() as u64).to_be_bytes());
+                bytes.extend_from_slice(data);
+            }
+        }
+        bytes
+    }
+
+    /// Canonical encoding, computed on first use and then cached.
+    ///
+    /// Returns the same bytes as [`canonical_bytes`](Self::canonical_bytes)
+    /// as long as direct field mutations are followed by
+    /// [`invalidate`](Self::invalidate).
+    pub fn canonical_bytes_cached(&self) -> &[u8] {
+        self.canonical_cache.0.get_or_init(|| self.canonical_bytes())
+    }
+
+    /// Drop the cached canonical encoding after mutating fields directly.
+    pub fn invalidate(&mut self) {
+        self.canonical_cache.0.take();
+    }
+
+    /// Replace the payload, invalidating any existing signature.
+    pub fn set_payload(&mut self, payload: Vec<u8>) {
+        self.payload = payload;
+        self.invalidate();
+        self.invalidate_signature();
+    }
+
+    /// Replace the nonce, invalidating any existing signature.
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.nonce = nonce;
+        self.invalidate();
+        self.invalidate_signature();
+    }
+
+    /// Replace the timestamp, invalidating any existing signature.
+    pub fn set_timestamp(&mut self, timestamp: u64) {
+        self.timestamp = timestamp;
+        self.invalidate();
+        self.invalidate_signature();
+    }
+
+    /// Get an attachment by name.
+    pub fn attachment(&self, name: &str) -> Option<&[u8]> {
+        self.attachments.get(name).map(Vec::as_slice)
+    }
+
+    /// Add or replace an attachment, invalidating any existing signature.
+    pub fn set_attachment(&mut self, name: impl Into<String>, data: Vec<u8>) {
+        self.attachments.insert(name.into(), data);
+        self.invalidate();
+        self.invalidate_signature();
+    }
+
+    /// Remove an attachment, invalidating any existing signature if it was
+    /// present.
+    pub fn remove_attachment(&mut self, name: &str) -> Option<Vec<u8>> {
+        let removed = self.attachments.remove(name);
+        if removed.is_some() {
+            self.invalidate();
+            self.invalidate_signature();
+        }
+        removed
+    }
+
+    /// Set the relayer fee.
+    ///
+    /// The fee is not part of [`canonical_bytes`](Self::canonical_bytes), so
+    /// an existing signature remains valid.
+    pub fn set_fee(&mut self, fee: u128) {
+        self.fee = Some(fee);
+    }
+
+    /// Key of the total order on messages.
+    ///
+    /// Messages are ordered by `timestamp`, then `nonce`, then the bytes of
+    /// `id`. Messages from different senders can share a timestamp and
+    /// nonce, so the ID is the final tie-breaker that makes the order
+    /// deterministic across nodes. Sort with
+    /// [`cmp_order`](Self::cmp_order).
+    pub fn ordering_key(&self) -> (u64, u64, [u8; 16]) {
+        (self.timestamp, self.nonce, *self.id.as_bytes())
+    }
+
+    /// Compare two messages under the total order of
+    /// [`ordering_key`](Self::ordering_key).
+    ///
+    /// Two messages compare equal only if they share an ID.
+    pub fn cmp_order(&self, other: &Self) -> std::cmp::Ordering {
+        self.ordering_key().cmp(&other.ordering_key())
+    }
+
+    /// Whether a signed field was mutated after the message was signed.
+    ///
+    /// Mutating a signed field through the setters above clears the
+    /// signature and records [`SIGNATURE_STALE_KEY`] in the metadata until
+    /// the message is signed again.
+    pub fn is_signature_stale(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.contains_key(SIGNATURE_STALE_KEY))
+    }
+
+    fn invalidate_signature(&mut self) {
+        if self.signature.take().is_some() {
+            self.metadata
+                .get_or_insert_with(HashMap::new)
+                .insert(SIGNATURE_STALE_KEY.to_string(), "true".to_string());
+        }
+    }
+
+    fn clear_signature_stale(&mut self) {
+        if let Some(metadata) = self.metadata.as_mut() {
+            metadata.remove(SIGNATURE_STALE_KEY);
+        }
+    }
+
+    /// Sender declared in the message metadata under [`SENDER_KEY`], if any.
+    pub fn sender(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(SENDER_KEY))
+            .map(String::as_str)
+    }
+
+    /// Block height the proof attests to, declared in the metadata under
+    /// [`PROOF_BLOCK_HEIGHT_KEY`].
+    ///
+    /// Returns `None` if the height is missing or not a decimal integer.
+    pub fn proof_block_height(&self) -> Option<u64> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(PROOF_BLOCK_HEIGHT_KEY))
+            .and_then(|height| height.parse().ok())
+    }
+
+    /// Source block hash the proof commits to, declared in the metadata
+    /// under [`PROOF_BLOCK_HASH_KEY`].
+    ///
+    /// Returns `None` if the hash is missing or not 32 hex-encoded bytes,
+    /// with or without a `0x` prefix.
+    pub fn proof_block_hash(&self) -> Option<[u8; 32]> {
+        let hash = self.metadata.as_ref()?.get(PROOF_BLOCK_HASH_KEY)?;
+        hex::decode(hash.strip_prefix("0x").unwrap_or(hash)).ok()?.try_into().ok()
+    }
+
+    /// Mark the message as waiting for a proof that is still being generated.
+    ///
+    /// Has no effect if a proof is already attached.
+    pub fn mark_proof_pending(&mut self) {
+        if self.proof.is_none() {
+            self.metadata
+                .get_or_insert_with(HashMap::new)
+                .insert(PROOF_PENDING_KEY.to_string(), "true".to_string());
+        }
+    }
+
+    /// Get the proof state of the message.
+    pub fn proof_state(&self) -> ProofState {
+        if self.proof.is_some() {
+            ProofState::Attached
+        } else if self.metadata.as_ref().is_some_and(|m| m.contains_key(PROOF_PENDING_KEY)) {
+            ProofState::Pending
+        } else {
+            ProofState::Absent
+        }
+    }
+
+    /// Whether the message was marked as waiting for its proof.
+    pub fn awaiting_proof(&self) -> bool {
+        self.proof_state() == ProofState::Pending
+    }
+
+    /// Attach a proof generated after the message was created.
+    ///
+    /// Fails without modifying the message if a proof is already attached.
+    /// The proof is not part of the signed
+    /// [`canonical_bytes`](Self::canonical_bytes), so attaching it leaves a
+    /// signature valid.
+    pub fn attach_proof(&mut self, proof: Proof) -> Result<(), AdapterError> {
+        if self.proof.is_some() {
+            return Err(AdapterError::MessageFormat(format!("message {} already has a proof", self.id)));
+        }
+        self.proof = Some(proof);
+        if let Some(metadata) = self.metadata.as_mut() {
+            metadata.remove(PROOF_PENDING_KEY);
+        }
+        Ok(())
+    }
+
+    /// Check that the message ID is usable for deduplication.
+    ///
+    /// Accepts random (v4) IDs and name-based (v5) IDs for messages with
+    /// deterministic identities. The nil UUID and other versions are
+    /// rejected, since they are likely to collide across senders.
+    pub fn validate_id(&self) -> Result<(), AdapterError> {
+        validate_message_id(&self.id)
+    }
+
+    /// Protocol version declared in the message metadata, if any.
+    pub fn protocol_version(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(PROTOCOL_VERSION_KEY))
+            .map(String::as_str)
+    }
+
+    /// Whether the message can be processed by a peer speaking `our_version`.
+    ///
+    /// Versions are `MAJOR.MINOR`. Minor versions only add backward-compatible
+    /// fields, so two versions are compatible exactly when their major
+    /// versions match. Messages without a declared version predate versioning
+    /// and are treated as version 1.0. Malformed versions are never
+    /// compatible.
+    pub fn is_compatible_with(&self, our_version: &str) -> bool {
+        let theirs = self.protocol_version().unwrap_or(LEGACY_PROTOCOL_VERSION);
+        match (protocol_major(theirs), protocol_major(our_version)) {
+            (Some(theirs), Some(ours)) => theirs == ours,
+            _ => false,
+        }
+    }
+
+    /// Check whether the message is older than `max_age`, allowing the
+    /// [default](SkewTolerance::default) clock skew.
+    ///
+    /// Age is measured from the message `timestamp` to the current system
+    /// time. Messages timestamped in the future are never expired.
+    pub fn is_expired(&self, max_age: Duration) -> bool {
+        self.is_expired_with(max_age, SkewTolerance::default())
+    }
+
+    /// Check whether the message is older than `max_age` beyond the given
+    /// clock skew.
+    pub fn is_expired_with(&self, max_age: Duration, skew: SkewTolerance) -> bool {
+        skew.is_expired(self.timestamp, skew::unix_now(), max_age)
+    }
+
+    /// Check whether the message is timestamped further ahead of the current
+    /// system time than the given clock skew allows.
+    pub fn is_from_future(&self, skew: SkewTolerance) -> bool {
+        skew.is_from_future(self.timestamp, skew::unix_now())
+    }
+}
+
+/// Parse a message ID received from an external system.
+///
+/// Accepts the hyphenated, simple, braced and URN forms. Fails if the string
+/// is not a UUID or the UUID is rejected by [`FrostMessage::validate_id`].
+pub fn parse_message_id(id: &str) -> Result<Uuid, AdapterError> {
+    let id = Uuid::parse_str(id.trim())
+        .map_err(|e| AdapterError::MessageFormat(format!("invalid message id {:?}: {}", id, e)))?;
+    validate_message_id(&id)?;
+    Ok(id)
+}
+
+fn validate_message_id(id: &Uuid) -> Result<(), AdapterError> {
+    if id.is_nil() {
+        return Err(AdapterError::MessageFormat("nil message id".to_string()));
+    }
+    match id.get_version_num() {
+        4 | 5 => Ok(()),
+        version => Err(AdapterError::MessageFormat(format!(
+            "message id {} is UUID v{}, expected v4 or v5",
+            id, version
+        ))),
+    }
+}
+
+/// Major component of a `MAJOR.MINOR` protocol version.
+fn protocol_major(version: &str) -> Option<u32> {
+    let (major, minor) = version.split_once('.')?;
+    minor.parse::<u32>().ok()?;
+    major.parse().ok()
+}
+
+/// Direction of a message relative to a local chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Sent from another chain to the local chain
+    Inbound,
+    /// Sent from the local chain to another chain
+    Outbound,
+    /// Sent from the local chain to itself
+    Loopback,
+    /// Neither sent from nor to the local chain
+    Unrelated,
+}
+
+impl FrostMessage {
+    /// Infer the direction of this message relative to `local`.
+    pub fn direction(&self, local: ChainId) -> Direction {
+        match (self.from_chain == local, self.to_chain == local) {
+            (false, true) => Direction::Inbound,
+            (true, false) => Direction::Outbound,
+            (true, true) => Direction::Loopback,
+            (false, false) => Direction::Unrelated,
         }
     }
 }
 
+/// A message received by the local chain.
+#[derive(Debug, Clone)]
+pub struct Inbound(FrostMessage);
+
+impl Inbound {
+    /// Wrap a message if it is inbound relative to `local`.
+    pub fn new(message: FrostMessage, local: ChainId) -> Option<Self> {
+        (message.direction(local) == Direction::Inbound).then_some(Self(message))
+    }
+
+    /// Unwrap the message.
+    pub fn into_inner(self) -> FrostMessage {
+        self.0
+    }
+}
+
+impl std::ops::Deref for Inbound {
+    type Target = FrostMessage;
+
+    fn deref(&self) -> &FrostMessage {
+        &self.0
+    }
+}
+
+/// A message originated by the local chain.
+#[derive(Debug, Clone)]
+pub struct Outbound(FrostMessage);
+
+impl Outbound {
+    /// Wrap a message if it is outbound relative to `local`.
+    pub fn new(message: FrostMessage, local: ChainId) -> Option<Self> {
+        (message.direction(local) == Direction::Outbound).then_some(Self(message))
+    }
+
+    /// Unwrap the message.
+    pub fn into_inner(self) -> FrostMessage {
+        self.0
+    }
+}
+
+impl std::ops::Deref for Outbound {
+    type Target = FrostMessage;
+
+    fn deref(&self) -> &FrostMessage {
+        &self.0
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl FrostMessage {
+    /// Serialize the message to CBOR.
+    ///
+    /// CBOR is a compact, self-describing binary encoding suited to
+    /// constrained environments. All fields, including `metadata`, round-trip.
+    pub fn to_cbor(&self) -> Result<Vec<u8>, AdapterError> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes)
+            .map_err(|e| AdapterError::MessageFormat(format!("CBOR encoding failed: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// Deserialize a message from CBOR bytes.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, AdapterError> {
+        ciborium::from_reader(bytes)
+            .map_err(|e| AdapterError::MessageFormat(format!("CBOR decoding failed: {}", e)))
+    }
+}
+
+/// Group messages by destination chain, preserving their relative order.
+pub fn group_by_destination(messages: Vec<FrostMessage>) -> HashMap<ChainId, Vec<FrostMessage>> {
+    let mut groups: HashMap<ChainId, Vec<FrostMessage>> = HashMap::new();
+    for message in messages {
+        groups.entry(message.to_chain).or_default().push(message);
+    }
+    groups
+}
+
+/// Whether two proofs are cryptographically identical, ignoring generation
+/// metadata.
+///
+/// Only the proof bytes and program hash are compared; timestamps,
+/// generation time and the reported proof size are not.
+pub fn proof_eq_ignoring_metadata(a: &Proof, b: &Proof) -> bool {
+    a.data == b.data && a.metadata.program_hash == b.metadata.program_hash
+}
+
 /// A trait for messages that can be sent across chains.
 pub trait CrossChainMessage {
     /// Get the unique identifier of the message.
@@ -183,12 +770,25 @@ pub struct MessageEvent {
     pub tx_hash: Option<TxHash>,
     /// Optional block number where the event was emitted
     pub block_number: Option<u64>,
+    /// Optional raw on-chain log the event was decoded from
+    #[serde(default)]
+    pub raw_log: Option<RawLog>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn chain_id_numeric_round_trip() {
+        for chain in [ChainId::Ethereum, ChainId::Polkadot, ChainId::Solana, ChainId::Unknown] {
+            assert_eq!(ChainId::try_from(chain.to_u64()), Ok(chain));
+            assert_eq!(u64::from(chain), chain.to_u64());
+        }
+        assert_eq!(ChainId::Unknown.to_u64(), u64::MAX);
+        assert_eq!(ChainId::try_from(3), Ok(ChainId::Unknown));
+    }
+
     #[test]
     fn frost_message_basic() {
         let msg = FrostMessage::new(
@@ -203,4 +803,286 @@ mod tests {
         assert_eq!(msg.from_chain, de.from_chain);
         assert_eq!(msg.payload, de.payload);
     }
+
+    #[test]
+    fn mutation_invalidates_signature() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+
+        // Unsigned messages are never stale
+        msg.set_nonce(2);
+        assert!(!msg.is_signature_stale());
+
+        msg.signature = Some(vec![9; 64]);
+        msg.set_fee(100);
+        assert!(msg.signature.is_some());
+        assert!(!msg.is_signature_stale());
+
+        msg.set_payload(vec![2]);
+        assert!(msg.signature.is_none());
+        assert!(msg.is_signature_stale());
+    }
+
+    #[test]
+    fn protocol_version_compatibility() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        assert_eq!(msg.protocol_version(), Some(PROTOCOL_VERSION));
+        assert!(msg.is_compatible_with(PROTOCOL_VERSION));
+        assert!(msg.is_compatible_with("1.7"));
+        assert!(!msg.is_compatible_with("2.0"));
+        assert!(!msg.is_compatible_with("garbage"));
+
+        let metadata = msg.metadata.as_mut().unwrap();
+        metadata.insert(PROTOCOL_VERSION_KEY.to_string(), "2.3".to_string());
+        assert!(!msg.is_compatible_with("1.0"));
+        assert!(msg.is_compatible_with("2.0"));
+
+        msg.metadata.as_mut().unwrap().insert(PROTOCOL_VERSION_KEY.to_string(), "2".to_string());
+        assert!(!msg.is_compatible_with("2.0"));
+
+        // Unversioned messages are treated as 1.0
+        msg.metadata = None;
+        assert_eq!(msg.protocol_version(), None);
+        assert!(msg.is_compatible_with("1.2"));
+        assert!(!msg.is_compatible_with("2.0"));
+    }
+
+    fn proof(data: Vec<u8>) -> Proof {
+        Proof {
+            data,
+            metadata: ProofMetadata {
+                generation_time: Duration::from_secs(1),
+                proof_size: 4,
+                program_hash: "dummy".to_string(),
+                timestamp: SystemTime::now(),
+            },
+        }
+    }
+
+    #[test]
+    fn proof_equality_ignores_metadata() {
+        let a = proof(vec![1, 2, 3, 4]);
+        let mut b = proof(vec![1, 2, 3, 4]);
+        b.metadata.timestamp = a.metadata.timestamp + Duration::from_secs(60);
+        b.metadata.generation_time = Duration::from_secs(5);
+        assert!(proof_eq_ignoring_metadata(&a, &b));
+
+        assert!(!proof_eq_ignoring_metadata(&a, &proof(vec![1, 2, 3, 5])));
+        b.metadata.program_hash = "other".to_string();
+        assert!(!proof_eq_ignoring_metadata(&a, &b));
+    }
+
+    #[test]
+    fn attach_pending_proof() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        assert_eq!(msg.proof_state(), ProofState::Absent);
+        msg.mark_proof_pending();
+        assert!(msg.awaiting_proof());
+
+        msg.attach_proof(proof(vec![1, 2, 3, 4])).unwrap();
+        assert_eq!(msg.proof_state(), ProofState::Attached);
+        assert!(!msg.awaiting_proof());
+
+        // A second proof is rejected and the first one kept
+        assert!(matches!(msg.attach_proof(proof(vec![9])), Err(AdapterError::MessageFormat(_))));
+        assert_eq!(msg.proof.as_ref().unwrap().data, vec![1, 2, 3, 4]);
+        msg.mark_proof_pending();
+        assert_eq!(msg.proof_state(), ProofState::Attached);
+    }
+
+    #[test]
+    fn message_id_validation() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        assert!(msg.validate_id().is_ok());
+
+        // uuid5(NAMESPACE_DNS, "python.org")
+        let v5 = "886313e1-3b8a-5372-9b90-0c9aee199e5d";
+        msg.id = parse_message_id(v5).unwrap();
+        assert!(msg.validate_id().is_ok());
+        assert_eq!(parse_message_id(&format!(" urn:uuid:{} ", v5)).unwrap(), msg.id);
+
+        msg.id = Uuid::nil();
+        assert!(matches!(msg.validate_id(), Err(AdapterError::MessageFormat(_))));
+        assert!(parse_message_id("00000000-0000-0000-0000-000000000000").is_err());
+
+        // v1 (time-based) and malformed ids
+        assert!(parse_message_id("c232ab00-9414-11ec-b3c8-9f6bdeced846").is_err());
+        assert!(parse_message_id("not-a-uuid").is_err());
+    }
+
+    #[test]
+    fn json_omits_absent_optional_fields() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), 1, 1_725_000_000);
+        msg.metadata = None;
+        let json = serde_json::to_value(&msg).unwrap();
+        let object = json.as_object().unwrap();
+        for field in ["proof", "signature", "fee", "metadata"] {
+            assert!(!object.contains_key(field), "{} serialized", field);
+        }
+        assert!(!json.to_string().contains("null"));
+
+        let decoded: FrostMessage = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.id, msg.id);
+        assert!(decoded.proof.is_none() && decoded.signature.is_none());
+        assert!(decoded.fee.is_none() && decoded.metadata.is_none());
+        assert_eq!(decoded.canonical_bytes(), msg.canonical_bytes());
+
+        msg.set_fee(5);
+        let decoded: FrostMessage = serde_json::from_str(&serde_json::to_string(&msg).unwrap()).unwrap();
+        assert_eq!(decoded.fee, Some(5));
+    }
+
+    #[test]
+    fn canonical_bytes_cache() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        let cached = msg.canonical_bytes_cached().as_ptr();
+        assert_eq!(msg.canonical_bytes_cached(), msg.canonical_bytes());
+        assert_eq!(msg.canonical_bytes_cached().as_ptr(), cached);
+
+        msg.set_payload(vec![2, 3]);
+        assert_eq!(msg.canonical_bytes_cached(), msg.canonical_bytes());
+        msg.set_attachment("receipt", vec![4]);
+        assert_eq!(msg.canonical_bytes_cached(), msg.canonical_bytes());
+
+        // Direct field writes need an explicit invalidate
+        msg.nonce = 9;
+        assert_ne!(msg.canonical_bytes_cached(), msg.canonical_bytes());
+        msg.invalidate();
+        assert_eq!(msg.canonical_bytes_cached(), msg.canonical_bytes());
+
+        // The cache is not serialized
+        let de: FrostMessage = serde_json::from_str(&serde_json::to_string(&msg).unwrap()).unwrap();
+        assert!(de.canonical_cache.0.get().is_none());
+        assert_eq!(de.canonical_bytes_cached(), msg.canonical_bytes());
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), 1, 1_725_000_000);
+        let decoded: FrostMessage = bincode::deserialize(&bincode::serialize(&msg).unwrap()).unwrap();
+        assert_eq!(decoded.id, msg.id);
+        assert!(decoded.proof.is_none() && decoded.signature.is_none() && decoded.fee.is_none());
+        assert_eq!(decoded.metadata, msg.metadata);
+
+        msg.metadata = None;
+        msg.set_fee(5);
+        msg.set_attachment("receipt", vec![7]);
+        let decoded: FrostMessage = bincode::deserialize(&bincode::serialize(&msg).unwrap()).unwrap();
+        assert_eq!(decoded.fee, Some(5));
+        assert!(decoded.metadata.is_none());
+        assert_eq!(decoded.canonical_bytes(), msg.canonical_bytes());
+    }
+
+    #[test]
+    fn message_attachments() {
+        let mut msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        let unattached = msg.canonical_bytes();
+        assert!(msg.attachment("receipt").is_none());
+
+        msg.signature = Some(vec![9; 64]);
+        msg.set_attachment("receipt", vec![1, 2, 3]);
+        assert_eq!(msg.attachment("receipt"), Some(&[1u8, 2, 3][..]));
+        assert!(msg.is_signature_stale());
+
+        // Attachments are part of the content encoding, independent of
+        // insertion order
+        let attached = msg.canonical_bytes();
+        assert_ne!(attached, unattached);
+        msg.set_attachment("log", vec![4]);
+        let mut reordered = msg.clone();
+        reordered.attachments = HashMap::from([
+            ("log".to_string(), vec![4]),
+            ("receipt".to_string(), vec![1, 2, 3]),
+        ]);
+        assert_eq!(reordered.canonical_bytes(), msg.canonical_bytes());
+        msg.set_attachment("receipt", vec![1, 2, 4]);
+        assert_ne!(msg.canonical_bytes(), reordered.canonical_bytes());
+
+        assert_eq!(msg.remove_attachment("log"), Some(vec![4]));
+        msg.remove_attachment("receipt");
+        assert_eq!(msg.canonical_bytes(), unattached);
+    }
+
+    #[test]
+    fn chain_id_ordering() {
+        let mut chains = vec![ChainId::Unknown, ChainId::Solana, ChainId::Ethereum, ChainId::Polkadot];
+        chains.sort();
+        assert_eq!(chains, vec![ChainId::Ethereum, ChainId::Polkadot, ChainId::Solana, ChainId::Unknown]);
+    }
+
+    #[test]
+    fn message_direction() {
+        let msg = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        assert_eq!(msg.direction(ChainId::Solana), Direction::Inbound);
+        assert_eq!(msg.direction(ChainId::Ethereum), Direction::Outbound);
+        assert_eq!(msg.direction(ChainId::Polkadot), Direction::Unrelated);
+
+        let loopback = FrostMessage::new(ChainId::Solana, ChainId::Solana, vec![1], 1, 1_725_000_000);
+        assert_eq!(loopback.direction(ChainId::Solana), Direction::Loopback);
+
+        assert!(Inbound::new(msg.clone(), ChainId::Solana).is_some());
+        assert!(Inbound::new(msg.clone(), ChainId::Ethereum).is_none());
+        let outbound = Outbound::new(msg.clone(), ChainId::Ethereum).unwrap();
+        assert_eq!(outbound.id, msg.id);
+    }
+
+    #[test]
+    fn group_messages_by_destination() {
+        let messages = vec![
+            FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![1], 1, 1_725_000_000),
+            FrostMessage::new(ChainId::Ethereum, ChainId::Polkadot, vec![2], 2, 1_725_000_000),
+            FrostMessage::new(ChainId::Polkadot, ChainId::Solana, vec![3], 3, 1_725_000_000),
+        ];
+
+        let groups = group_by_destination(messages);
+        assert_eq!(groups.len(), 2);
+        let nonces: Vec<u64> = groups[&ChainId::Solana].iter().map(|m| m.nonce).collect();
+        assert_eq!(nonces, vec![1, 3]);
+        assert_eq!(groups[&ChainId::Polkadot].len(), 1);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn frost_message_cbor_roundtrip() {
+        let mut msg = FrostMessage::new(
+            ChainId::Ethereum,
+            ChainId::Solana,
+            b"test-payload".to_vec(),
+            1,
+            1_725_000_000,
+        );
+        msg.signature = Some(vec![7u8; 64]);
+        msg.fee = Some(42);
+        msg.metadata = Some(HashMap::from([("relayer".to_string(), "r1".to_string())]));
+
+        let cbor = msg.to_cbor().unwrap();
+        let json = serde_json::to_vec(&msg).unwrap();
+        assert!(cbor.len() < json.len());
+
+        let de = FrostMessage::from_cbor(&cbor).unwrap();
+        assert_eq!(serde_json::to_vec(&de).unwrap(), json);
+    }
+
+    #[test]
+    fn ordering_breaks_ties_by_id() {
+        let earlier = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![9], 7, 1_725_000_000);
+        let mut messages: Vec<_> = (0..4u8)
+            .map(|i| FrostMessage::new(ChainId::Polkadot, ChainId::Solana, vec![i], 5, 1_725_000_001))
+            .collect();
+        messages.push(earlier.clone());
+
+        let mut sorted = messages.clone();
+        sorted.sort_by(FrostMessage::cmp_order);
+        assert_eq!(sorted[0].id, earlier.id);
+        let ids: Vec<_> = sorted[1..].iter().map(|m| *m.id.as_bytes()).collect();
+        let mut expected = ids.clone();
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        // The order does not depend on the input order
+        messages.reverse();
+        messages.sort_by(FrostMessage::cmp_order);
+        let resorted: Vec<_> = messages.iter().map(|m| m.id).collect();
+        assert_eq!(resorted, sorted.iter().map(|m| m.id).collect::<Vec<_>>());
+        assert_eq!(earlier.cmp_order(&earlier.clone()), std::cmp::Ordering::Equal);
+    }
 }
\ No newline at end of file
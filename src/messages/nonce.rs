@@ -0,0 +1,76 @@
+//! Per-corridor nonce allocation
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+
+use super::ChainId;
+
+/// Allocates monotonically increasing nonces per `(from_chain, to_chain)`
+/// corridor.
+///
+/// Each corridor has its own nonce namespace starting at 0. Use
+/// [`observe`](NonceAllocator::observe) to sync with nonces already used
+/// on-chain, e.g. after a restart.
+#[derive(Debug, Default)]
+pub struct NonceAllocator {
+    next: Mutex<HashMap<(ChainId, ChainId), u64>>,
+}
+
+impl NonceAllocator {
+    /// Create an allocator with every corridor starting at nonce 0
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocate the next nonce for a corridor
+    pub fn next(&self, from: ChainId, to: ChainId) -> u64 {
+        let mut next = self.next.lock();
+        let counter = next.entry((from, to)).or_insert(0);
+        let nonce = *counter;
+        *counter += 1;
+        nonce
+    }
+
+    /// Get the nonce the next allocation for a corridor would return
+    pub fn peek(&self, from: ChainId, to: ChainId) -> u64 {
+        self.next.lock().get(&(from, to)).copied().unwrap_or(0)
+    }
+
+    /// Record a nonce seen on-chain so it is never allocated again
+    ///
+    /// Nonces lower than the next one to allocate are ignored, so the
+    /// counter never moves backwards.
+    pub fn observe(&self, from: ChainId, to: ChainId, nonce: u64) {
+        let mut next = self.next.lock();
+        let counter = next.entry((from, to)).or_insert(0);
+        *counter = (*counter).max(nonce.saturating_add(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocation_per_corridor() {
+        let allocator = NonceAllocator::new();
+        assert_eq!(allocator.next(ChainId::Ethereum, ChainId::Solana), 0);
+        assert_eq!(allocator.next(ChainId::Ethereum, ChainId::Solana), 1);
+        assert_eq!(allocator.next(ChainId::Solana, ChainId::Ethereum), 0);
+        assert_eq!(allocator.next(ChainId::Ethereum, ChainId::Polkadot), 0);
+        assert_eq!(allocator.peek(ChainId::Ethereum, ChainId::Solana), 2);
+    }
+
+    #[test]
+    fn test_observe_advances_counter() {
+        let allocator = NonceAllocator::new();
+        allocator.observe(ChainId::Ethereum, ChainId::Solana, 41);
+        assert_eq!(allocator.next(ChainId::Ethereum, ChainId::Solana), 42);
+
+        // Older nonces never move the counter backwards
+        allocator.observe(ChainId::Ethereum, ChainId::Solana, 10);
+        assert_eq!(allocator.next(ChainId::Ethereum, ChainId::Solana), 43);
+        assert_eq!(allocator.peek(ChainId::Solana, ChainId::Ethereum), 0);
+    }
+}
@@ -0,0 +1,101 @@
+//! Payloads streamed from readers
+
+use std::io::{ErrorKind, Read};
+
+use super::FrostMessage;
+use crate::types::AdapterError;
+use crate::verification::HashAlgorithm;
+
+/// Size of the chunks payloads are read in
+const CHUNK_SIZE: usize = 64 * 1024;
+
+impl FrostMessage {
+    /// Replace the payload with the contents of a reader.
+    ///
+    /// The reader is consumed in chunks straight into the payload, so no
+    /// second copy of a large payload is held. Reading stops as soon as more
+    /// than `max_size` bytes are seen, failing with
+    /// [`AdapterError::MessageFormat`]. Like
+    /// [`set_payload`](Self::set_payload), this invalidates any existing
+    /// signature.
+    pub fn with_payload_from_reader(mut self, reader: impl Read, max_size: usize) -> Result<Self, AdapterError> {
+        self.set_payload(read_payload(reader, max_size)?);
+        Ok(self)
+    }
+
+    /// Replace the payload with the contents of a reader, hashing it.
+    ///
+    /// Same as [`with_payload_from_reader`](Self::with_payload_from_reader),
+    /// also returning the payload's digest under `algorithm`. Pass the
+    /// verifier's
+    /// [`hash_algorithm`](crate::verification::VerifierConfig::hash_algorithm)
+    /// to compare the payload with digests the verifier computes.
+    pub fn with_payload_from_reader_hashed(
+        mut self,
+        reader: impl Read,
+        max_size: usize,
+        algorithm: HashAlgorithm,
+    ) -> Result<(Self, [u8; 32]), AdapterError> {
+        let payload = read_payload(reader, max_size)?;
+        let hash = algorithm.digest(&payload);
+        self.set_payload(payload);
+        Ok((self, hash))
+    }
+}
+
+/// Read a reader to the end in chunks, failing once more than `max_size`
+/// bytes are seen
+fn read_payload(mut reader: impl Read, max_size: usize) -> Result<Vec<u8>, AdapterError> {
+    let mut payload = Vec::new();
+    let mut chunk = vec![0u8; CHUNK_SIZE];
+    loop {
+        let read = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(AdapterError::Other(e.into())),
+        };
+        if payload.len() + read > max_size {
+            return Err(AdapterError::MessageFormat(format!(
+                "payload exceeds {} bytes",
+                max_size
+            )));
+        }
+        payload.extend_from_slice(&chunk[..read]);
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ChainId;
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, Vec::new(), 1, 1_725_000_000)
+    }
+
+    #[test]
+    fn reader_under_cap_fills_payload() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| i as u8).collect();
+
+        let (message, hash) = message()
+            .with_payload_from_reader_hashed(data.as_slice(), data.len(), HashAlgorithm::Sha256)
+            .unwrap();
+        assert_eq!(message.payload, data);
+        assert_eq!(message.canonical_bytes_cached(), message.canonical_bytes().as_slice());
+
+        let (_, hash) = message
+            .with_payload_from_reader_hashed(data.as_slice(), data.len(), HashAlgorithm::default())
+            .unwrap();
+        assert_eq!(hash, HashAlgorithm::Blake2b.digest(&data));
+    }
+
+    #[test]
+    fn reader_over_cap_rejected() {
+        let data = vec![7u8; 200_000];
+
+        let result = message().with_payload_from_reader(data.as_slice(), data.len() - 1);
+        assert!(matches!(result, Err(AdapterError::MessageFormat(_))));
+    }
+}
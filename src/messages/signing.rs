@@ -0,0 +1,307 @@
+//! Message signing with external signers
+
+use async_trait::async_trait;
+
+use super::FrostMessage;
+#[cfg(feature = "signing")]
+use crate::types::AdapterError;
+use crate::types::SignError;
+
+/// A signer producing raw signatures in process
+pub trait Signer {
+    /// Sign the given bytes, returning the raw signature
+    fn sign(&self, msg: &[u8]) -> Vec<u8>;
+}
+
+/// A signer whose key material lives outside the process (HSM, KMS, ...)
+#[async_trait]
+pub trait RemoteSigner: Send + Sync {
+    /// Sign the given bytes, returning the raw signature
+    async fn sign(&self, bytes: &[u8]) -> Result<Vec<u8>, SignError>;
+}
+
+impl FrostMessage {
+    /// Sign the message's canonical hash.
+    ///
+    /// The signature replaces any existing one and the message is no longer
+    /// considered stale. The signed preimage is
+    /// [`FrostMessage::canonical_hash`], which excludes the proof, so
+    /// attaching a proof after signing keeps the signature valid.
+    pub fn sign<S: Signer + ?Sized>(&mut self, signer: &S) {
+        self.signature = Some(signer.sign(&self.canonical_hash()));
+        self.clear_signature_stale();
+    }
+
+    /// Sign the message's canonical hash with a remote signer.
+    ///
+    /// On success the signature replaces any existing one and the message is
+    /// no longer considered stale. The preimage is the same as for
+    /// [`sign`](Self::sign).
+    pub async fn sign_with<S: RemoteSigner + ?Sized>(&mut self, signer: &S) -> Result<(), SignError> {
+        let signature = signer.sign(&self.canonical_hash()).await?;
+        self.signature = Some(signature);
+        self.clear_signature_stale();
+        Ok(())
+    }
+}
+
+/// In-memory ed25519 signer implementing [`Signer`] and [`RemoteSigner`]
+///
+/// The secret key is wiped from memory when the signer is dropped.
+#[cfg(feature = "signing")]
+pub struct LocalSigner {
+    key: ed25519_dalek::SigningKey,
+}
+
+#[cfg(feature = "signing")]
+impl LocalSigner {
+    /// Create a signer from a 32-byte ed25519 secret key
+    ///
+    /// The signer keeps its own copy of the key; callers should hold the
+    /// secret in a [`zeroize::Zeroizing`] buffer so their copy is wiped too.
+    pub fn from_bytes(secret: &[u8; 32]) -> Self {
+        Self {
+            key: ed25519_dalek::SigningKey::from_bytes(secret),
+        }
+    }
+
+    /// Export the 32-byte secret key, wiped when the returned buffer is dropped
+    pub fn secret_bytes(&self) -> zeroize::Zeroizing<[u8; 32]> {
+        zeroize::Zeroizing::new(self.key.to_bytes())
+    }
+
+    /// Get the 32-byte ed25519 public key
+    pub fn public_key(&self) -> [u8; 32] {
+        self.key.verifying_key().to_bytes()
+    }
+
+    /// Sign a message in place; the synchronous counterpart of
+    /// [`FrostMessage::sign_with`]
+    pub fn sign_message(&self, message: &mut FrostMessage) {
+        message.sign(self);
+    }
+}
+
+#[cfg(feature = "signing")]
+impl FrostMessage {
+    /// Check the message's ed25519 signature against a public key.
+    ///
+    /// Fails with [`AdapterError::MessageFormat`] if the key is not a valid
+    /// 32-byte ed25519 public key. Returns `Ok(false)` if the message is
+    /// unsigned, the signature is malformed, or it does not cover the
+    /// current [`canonical_hash`](FrostMessage::canonical_hash).
+    pub fn verify_signature(&self, public_key: &[u8]) -> Result<bool, AdapterError> {
+        let public_key: [u8; 32] = public_key.try_into().map_err(|_| {
+            AdapterError::MessageFormat(format!("ed25519 public key must be 32 bytes, got {}", public_key.len()))
+        })?;
+        ed25519_dalek::VerifyingKey::from_bytes(&public_key)
+            .map_err(|e| AdapterError::MessageFormat(format!("invalid ed25519 public key: {}", e)))?;
+        Ok(self.verify_signature_multi(&[public_key]).is_some())
+    }
+
+    /// Find which of several authorized ed25519 keys signed the message.
+    ///
+    /// Returns the index of the first key the signature validates against,
+    /// or `None` under the same conditions as
+    /// [`verify_signature`](FrostMessage::verify_signature). Malformed keys
+    /// are skipped, so a key set can be rotated by appending the new key.
+    pub fn verify_signature_multi(&self, public_keys: &[[u8; 32]]) -> Option<usize> {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let signature = Signature::from_slice(self.signature.as_deref()?).ok()?;
+        // Recomputed from the current fields, so direct writes after signing
+        // are caught
+        let preimage = self.canonical_hash();
+        public_keys.iter().position(|public_key| {
+            VerifyingKey::from_bytes(public_key)
+                .is_ok_and(|key| key.verify(&preimage, &signature).is_ok())
+        })
+    }
+}
+
+// The signing key zeroizes itself on drop
+#[cfg(feature = "signing")]
+impl zeroize::ZeroizeOnDrop for LocalSigner {}
+
+#[cfg(feature = "signing")]
+impl Signer for LocalSigner {
+    fn sign(&self, msg: &[u8]) -> Vec<u8> {
+        ed25519_dalek::Signer::sign(&self.key, msg).to_bytes().to_vec()
+    }
+}
+
+#[cfg(feature = "signing")]
+#[async_trait]
+impl RemoteSigner for LocalSigner {
+    async fn sign(&self, bytes: &[u8]) -> Result<Vec<u8>, SignError> {
+        Ok(Signer::sign(self, bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ChainId;
+    use parking_lot::Mutex;
+
+    // Mock remote signer recording what it was asked to sign
+    #[derive(Default)]
+    struct MockRemoteSigner {
+        signed: Mutex<Vec<Vec<u8>>>,
+        unavailable: bool,
+    }
+
+    #[async_trait]
+    impl RemoteSigner for MockRemoteSigner {
+        async fn sign(&self, bytes: &[u8]) -> Result<Vec<u8>, SignError> {
+            if self.unavailable {
+                return Err(SignError::Unavailable("KMS timeout".to_string()));
+            }
+            self.signed.lock().push(bytes.to_vec());
+            Ok(vec![0xAB; 64])
+        }
+    }
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), 1, 1_725_000_000)
+    }
+
+    #[tokio::test]
+    async fn test_sign_with_remote_signer() {
+        let signer = MockRemoteSigner::default();
+        let mut msg = message();
+
+        msg.sign_with(&signer).await.unwrap();
+        assert_eq!(msg.signature, Some(vec![0xAB; 64]));
+        assert_eq!(*signer.signed.lock(), vec![msg.canonical_hash().to_vec()]);
+    }
+
+    #[tokio::test]
+    async fn test_resigning_clears_stale_flag() {
+        let signer = MockRemoteSigner::default();
+        let mut msg = message();
+        msg.sign_with(&signer).await.unwrap();
+
+        msg.set_timestamp(1_725_000_100);
+        assert!(msg.is_signature_stale());
+        msg.sign_with(&signer).await.unwrap();
+        assert!(!msg.is_signature_stale());
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_failure_leaves_message_unsigned() {
+        let signer = MockRemoteSigner {
+            unavailable: true,
+            ..Default::default()
+        };
+        let mut msg = message();
+
+        assert!(matches!(msg.sign_with(&signer).await, Err(SignError::Unavailable(_))));
+        assert!(msg.signature.is_none());
+    }
+
+    #[cfg(feature = "signing")]
+    #[tokio::test]
+    async fn test_local_signer() {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let signer = LocalSigner::from_bytes(&[7u8; 32]);
+        let mut msg = message();
+        msg.sign_with(&signer).await.unwrap();
+
+        let key = VerifyingKey::from_bytes(&signer.public_key()).unwrap();
+        let signature = Signature::from_slice(msg.signature.as_ref().unwrap()).unwrap();
+        assert!(key.verify(&msg.canonical_hash(), &signature).is_ok());
+    }
+
+    #[cfg(feature = "signing")]
+    #[tokio::test]
+    async fn test_verify_signature_multi() {
+        let operators: Vec<LocalSigner> = (1..=3).map(|i| LocalSigner::from_bytes(&[i; 32])).collect();
+        let keys: Vec<[u8; 32]> = operators.iter().map(LocalSigner::public_key).collect();
+        let mut msg = message();
+        assert_eq!(msg.verify_signature_multi(&keys), None);
+
+        msg.sign_with(&operators[1]).await.unwrap();
+        assert_eq!(msg.verify_signature_multi(&keys), Some(1));
+        assert_eq!(msg.verify_signature_multi(&keys[2..]), None);
+        assert_eq!(msg.verify_signature_multi(&[]), None);
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_local_signer_secret_is_zeroized() {
+        fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<ed25519_dalek::SigningKey>();
+        assert_zeroize_on_drop::<LocalSigner>();
+
+        let secret = zeroize::Zeroizing::new([7u8; 32]);
+        let signer = LocalSigner::from_bytes(&secret);
+        let exported: zeroize::Zeroizing<[u8; 32]> = signer.secret_bytes();
+        assert_eq!(*exported, *secret);
+    }
+
+    #[cfg(feature = "signing")]
+    #[tokio::test]
+    async fn test_verify_signature() {
+        let signer = LocalSigner::from_bytes(&[7u8; 32]);
+        let other = LocalSigner::from_bytes(&[8u8; 32]);
+        let mut msg = message();
+        assert!(!msg.verify_signature(&signer.public_key()).unwrap());
+
+        msg.sign_with(&signer).await.unwrap();
+        assert!(msg.verify_signature(&signer.public_key()).unwrap());
+        assert!(!msg.verify_signature(&other.public_key()).unwrap());
+
+        msg.set_fee(10);
+        assert!(msg.verify_signature(&signer.public_key()).unwrap());
+        assert!(matches!(msg.verify_signature(&[1u8; 31]), Err(AdapterError::MessageFormat(_))));
+    }
+
+    // Signer recording what it was asked to sign
+    #[derive(Default)]
+    struct RecordingSigner {
+        signed: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl Signer for RecordingSigner {
+        fn sign(&self, msg: &[u8]) -> Vec<u8> {
+            self.signed.lock().push(msg.to_vec());
+            vec![0xCD; 64]
+        }
+    }
+
+    #[test]
+    fn test_sign_over_canonical_hash() {
+        let signer = RecordingSigner::default();
+        let mut msg = message();
+        msg.sign(&signer);
+
+        assert_eq!(msg.signature, Some(vec![0xCD; 64]));
+        assert_eq!(*signer.signed.lock(), vec![msg.canonical_hash().to_vec()]);
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_tampered_payload_rejected() {
+        let signer = LocalSigner::from_bytes(&[7u8; 32]);
+        let mut msg = message();
+        msg.sign(&signer);
+
+        // Attaching a proof keeps the signature valid
+        msg.proof = Some(crate::messages::Proof {
+            data: vec![1, 2, 3],
+            metadata: frostgate_zkip::types::ProofMetadata {
+                generation_time: std::time::Duration::from_secs(1),
+                proof_size: 3,
+                program_hash: "dummy".to_string(),
+                timestamp: std::time::SystemTime::now(),
+            },
+        });
+        assert!(msg.verify_signature(&signer.public_key()).unwrap());
+
+        // Direct writes are detected without any cache invalidation
+        msg.payload[0] ^= 1;
+        assert!(!msg.verify_signature(&signer.public_key()).unwrap());
+    }
+}
@@ -0,0 +1,86 @@
+//! Clock skew tolerance for comparing message timestamps to the local clock
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Tolerated difference between a message timestamp and the local clock
+///
+/// Relayers and verifiers do not share a clock, so every comparison of a
+/// message `timestamp` against wall-clock time goes through a
+/// `SkewTolerance`: a message may be up to the tolerance ahead of the local
+/// clock, and expiry is only declared once the maximum age is exceeded by
+/// more than the tolerance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SkewTolerance(Duration);
+
+impl SkewTolerance {
+    /// No tolerance; timestamps are compared exactly
+    pub const NONE: SkewTolerance = SkewTolerance(Duration::ZERO);
+
+    /// Create a symmetric tolerance
+    pub const fn new(tolerance: Duration) -> Self {
+        Self(tolerance)
+    }
+
+    /// The tolerated skew in either direction
+    pub fn tolerance(&self) -> Duration {
+        self.0
+    }
+
+    /// Whether `timestamp` is further ahead of `now` than tolerated
+    ///
+    /// Both are Unix timestamps in seconds.
+    pub fn is_from_future(&self, timestamp: u64, now: u64) -> bool {
+        timestamp > now.saturating_add(self.0.as_secs())
+    }
+
+    /// Whether `timestamp` is older than `max_age` at `now`, beyond the
+    /// tolerance
+    ///
+    /// Both are Unix timestamps in seconds. Timestamps ahead of `now` are
+    /// never expired.
+    pub fn is_expired(&self, timestamp: u64, now: u64, max_age: Duration) -> bool {
+        now.saturating_sub(timestamp) > max_age.as_secs().saturating_add(self.0.as_secs())
+    }
+}
+
+impl Default for SkewTolerance {
+    /// ±30 seconds
+    fn default() -> Self {
+        Self(Duration::from_secs(30))
+    }
+}
+
+/// Current Unix time in seconds, or 0 if the clock is before the epoch
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_725_000_000;
+
+    #[test]
+    fn test_future_timestamp_boundary() {
+        let skew = SkewTolerance::default();
+        assert!(!skew.is_from_future(NOW + 30, NOW));
+        assert!(skew.is_from_future(NOW + 31, NOW));
+        assert!(!SkewTolerance::NONE.is_from_future(NOW, NOW));
+        assert!(SkewTolerance::NONE.is_from_future(NOW + 1, NOW));
+    }
+
+    #[test]
+    fn test_expiry_boundary() {
+        let skew = SkewTolerance::new(Duration::from_secs(30));
+        let max_age = Duration::from_secs(60);
+        assert!(!skew.is_expired(NOW - 90, NOW, max_age));
+        assert!(skew.is_expired(NOW - 91, NOW, max_age));
+        assert!(!SkewTolerance::NONE.is_expired(NOW - 60, NOW, max_age));
+        assert!(SkewTolerance::NONE.is_expired(NOW - 61, NOW, max_age));
+        assert!(!skew.is_expired(NOW + 100, NOW, max_age));
+    }
+}
@@ -0,0 +1,168 @@
+//! Lifting fetched chain transactions into relayable messages
+
+use std::time::{Duration, SystemTime};
+
+use frostgate_zkip::types::ProofMetadata;
+
+use super::{skew, ChainId, FrostMessage, Proof, RESERVED_METADATA_KEYS, SENDER_KEY};
+use crate::types::{TransactionDetails, TransactionStatus};
+
+/// Attachment holding the verification key of a lifted proof transaction
+pub const VERIFICATION_KEY_ATTACHMENT: &str = "verification_key";
+
+impl FrostMessage {
+    /// Lift a fetched transaction into a message for relaying.
+    ///
+    /// - `Raw` bytes become the payload.
+    /// - `Parsed` transactions contribute their call data as payload, their
+    ///   `from` address (hex, `0x`-prefixed) as [sender](FrostMessage::sender),
+    ///   and their metadata, except [`RESERVED_METADATA_KEYS`]; a `nonce`
+    ///   metadata entry sets the nonce.
+    ///   Transactions that are not confirmed are skipped.
+    /// - `Proof` data becomes the message proof. The payload holds each public
+    ///   input prefixed with its length (u64, big-endian), so input boundaries
+    ///   survive, and the verification key, if any, is attached under
+    ///   [`VERIFICATION_KEY_ATTACHMENT`]. The proof's program hash is left
+    ///   empty.
+    ///
+    /// Returns `None` for transactions with nothing to relay (empty bytes or
+    /// proof). The message is timestamped with the current time.
+    pub fn from_transaction_details(details: &TransactionDetails, from: ChainId, to: ChainId) -> Option<Self> {
+        let timestamp = skew::unix_now();
+        match details {
+            TransactionDetails::Raw(bytes) => {
+                if bytes.is_empty() {
+                    return None;
+                }
+                Some(Self::new(from, to, bytes.clone(), 0, timestamp))
+            }
+            TransactionDetails::Parsed(tx) => {
+                if tx.status != TransactionStatus::Confirmed || tx.data.is_empty() {
+                    return None;
+                }
+                let nonce = tx.metadata.get("nonce").and_then(|nonce| nonce.parse().ok()).unwrap_or(0);
+                let mut message = Self::new(from, to, tx.data.clone(), nonce, timestamp);
+                let metadata = message.metadata.get_or_insert_with(Default::default);
+                for (key, value) in &tx.metadata {
+                    if !RESERVED_METADATA_KEYS.contains(&key.as_str()) {
+                        metadata.insert(key.clone(), value.clone());
+                    }
+                }
+                if let Some(sender) = &tx.from {
+                    metadata.insert(SENDER_KEY.to_string(), format!("0x{}", hex::encode(sender)));
+                }
+                Some(message)
+            }
+            TransactionDetails::Proof(data) => {
+                if data.proof.is_empty() {
+                    return None;
+                }
+                let mut payload = Vec::new();
+                for input in &data.public_inputs {
+                    payload.extend_from_slice(&(input.len() as u64).to_be_bytes());
+                    payload.extend_from_slice(input);
+                }
+                let mut message = Self::new(from, to, payload, 0, timestamp);
+                if let Some(verification_key) = &data.verification_key {
+                    message.set_attachment(VERIFICATION_KEY_ATTACHMENT, verification_key.clone());
+                }
+                message.proof = Some(Proof {
+                    data: data.proof.clone(),
+                    metadata: ProofMetadata {
+                        generation_time: Duration::ZERO,
+                        proof_size: data.proof.len(),
+                        program_hash: String::new(),
+                        timestamp: SystemTime::now(),
+                    },
+                });
+                Some(message)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ParsedTransaction, ProofData};
+    use std::collections::HashMap;
+
+    fn parsed(status: TransactionStatus) -> TransactionDetails {
+        TransactionDetails::Parsed(ParsedTransaction {
+            hash: vec![0xaa; 32],
+            from: Some(vec![0x12, 0x34]),
+            to: None,
+            value: 0,
+            data: b"call".to_vec(),
+            status,
+            metadata: HashMap::from([("nonce".to_string(), "7".to_string())]),
+        })
+    }
+
+    #[test]
+    fn test_from_raw_transaction() {
+        let message = FrostMessage::from_transaction_details(
+            &TransactionDetails::Raw(b"raw".to_vec()),
+            ChainId::Ethereum,
+            ChainId::Solana,
+        )
+        .unwrap();
+        assert_eq!(message.payload, b"raw");
+        assert_eq!((message.from_chain, message.to_chain), (ChainId::Ethereum, ChainId::Solana));
+        assert!(message.proof.is_none());
+
+        let empty = TransactionDetails::Raw(Vec::new());
+        assert!(FrostMessage::from_transaction_details(&empty, ChainId::Ethereum, ChainId::Solana).is_none());
+    }
+
+    #[test]
+    fn test_from_parsed_transaction() {
+        let details = parsed(TransactionStatus::Confirmed);
+        let message = FrostMessage::from_transaction_details(&details, ChainId::Ethereum, ChainId::Solana).unwrap();
+        assert_eq!(message.payload, b"call");
+        assert_eq!(message.nonce, 7);
+        assert_eq!(message.sender(), Some("0x1234"));
+        assert!(message.is_compatible_with(crate::messages::PROTOCOL_VERSION));
+
+        let failed = parsed(TransactionStatus::Failed("reverted".to_string()));
+        assert!(FrostMessage::from_transaction_details(&failed, ChainId::Ethereum, ChainId::Solana).is_none());
+    }
+
+    #[test]
+    fn test_parsed_transaction_cannot_set_reserved_keys() {
+        let TransactionDetails::Parsed(mut tx) = parsed(TransactionStatus::Confirmed) else {
+            unreachable!()
+        };
+        tx.from = None;
+        tx.metadata.insert(SENDER_KEY.to_string(), "0xbridge".to_string());
+        tx.metadata.insert(crate::traits::PAYLOAD_ENCODING_KEY.to_string(), "lz4".to_string());
+        tx.metadata.insert(crate::messages::PROOF_PENDING_KEY.to_string(), "true".to_string());
+        tx.metadata.insert("app".to_string(), "swap".to_string());
+
+        let details = TransactionDetails::Parsed(tx);
+        let message = FrostMessage::from_transaction_details(&details, ChainId::Ethereum, ChainId::Solana).unwrap();
+        let metadata = message.metadata.as_ref().unwrap();
+        assert_eq!(message.sender(), None);
+        assert!(!metadata.contains_key(crate::traits::PAYLOAD_ENCODING_KEY));
+        assert!(!metadata.contains_key(crate::messages::PROOF_PENDING_KEY));
+        assert_eq!(metadata.get("app").map(String::as_str), Some("swap"));
+        assert_eq!(message.protocol_version(), Some(crate::messages::PROTOCOL_VERSION));
+    }
+
+    #[test]
+    fn test_from_proof_transaction() {
+        let details = TransactionDetails::Proof(ProofData {
+            proof_type: "groth16".to_string(),
+            proof: vec![1, 2, 3],
+            public_inputs: vec![vec![4], vec![5, 6]],
+            verification_key: Some(vec![0xab]),
+        });
+        let message = FrostMessage::from_transaction_details(&details, ChainId::Ethereum, ChainId::Solana).unwrap();
+        assert_eq!(message.payload, [&1u64.to_be_bytes()[..], &[4], &2u64.to_be_bytes(), &[5, 6]].concat());
+        assert_eq!(message.attachment(VERIFICATION_KEY_ATTACHMENT), Some(&[0xab][..]));
+        let proof = message.proof.unwrap();
+        assert_eq!(proof.data, vec![1, 2, 3]);
+        assert_eq!(proof.metadata.proof_size, 3);
+        assert!(proof.metadata.program_hash.is_empty());
+    }
+}
@@ -0,0 +1,330 @@
+//! Adapters composed from several endpoints for the same chain
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::{
+    CapabilityProvider, ChainAdapter, EventListener, EventSubscription, FinalityProvider,
+    MessageProver, MessageSubmitter,
+};
+use crate::messages::{ChainId, FrostMessage, MessageEvent, MessageStatus};
+use crate::types::{
+    AdapterError, ChainCapabilities, ConnectionStatus, FeeParameters, FinalizedBlock, HealthMetrics,
+    ParsedTransaction, SubmissionOptions, TransactionDetails,
+};
+
+/// Try each adapter in order, moving on only after a retryable error
+macro_rules! failover {
+    ($self:ident, $adapter:ident => $call:expr) => {{
+        let mut last_error = None;
+        for $adapter in &$self.adapters {
+            match $call.await {
+                Ok(value) => return Ok(value),
+                Err(e) if e.is_retryable() => last_error = Some(e),
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(no_adapters))
+    }};
+}
+
+/// Dispatch to the next adapter in round-robin order
+macro_rules! balanced {
+    ($self:ident, $adapter:ident => $call:expr) => {{
+        match $self.next() {
+            Some($adapter) => $call.await,
+            None => Err(no_adapters()),
+        }
+    }};
+}
+
+fn no_adapters() -> AdapterError {
+    AdapterError::Configuration("no adapters configured".to_string())
+}
+
+fn composite_id(kind: &str, ids: impl Iterator<Item = String>) -> String {
+    format!("{}({})", kind, ids.collect::<Vec<_>>().join(","))
+}
+
+/// Adapter that tries an ordered list of adapters for the same chain.
+///
+/// Each operation is sent to the first adapter; if it fails with a retryable
+/// error (see [`AdapterError::is_retryable`]) the next adapter is tried, and
+/// so on. Non-retryable errors are returned immediately. If every adapter
+/// fails, the last retryable error is returned.
+///
+/// Note that a submission failing with a network error may still have
+/// reached the chain, so failing over `submit_message` can resubmit a
+/// message; nonces protect against it being executed twice.
+pub struct FailoverAdapter<A> {
+    adapters: Vec<A>,
+}
+
+impl<A> FailoverAdapter<A> {
+    /// Create a failover adapter trying `adapters` in order
+    pub fn new(adapters: Vec<A>) -> Self {
+        Self { adapters }
+    }
+
+    /// Get the underlying adapters, in failover order
+    pub fn adapters(&self) -> &[A] {
+        &self.adapters
+    }
+}
+
+/// Adapter that spreads operations across adapters for the same chain.
+///
+/// Each operation is dispatched to the next adapter in round-robin order.
+/// Errors are returned as-is; combine with [`FailoverAdapter`] for retries.
+pub struct LoadBalancedAdapter<A> {
+    adapters: Vec<A>,
+    next: AtomicUsize,
+}
+
+impl<A> LoadBalancedAdapter<A> {
+    /// Create a load-balanced adapter over `adapters`
+    pub fn new(adapters: Vec<A>) -> Self {
+        Self {
+            adapters,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Get the underlying adapters
+    pub fn adapters(&self) -> &[A] {
+        &self.adapters
+    }
+
+    fn next(&self) -> Option<&A> {
+        if self.adapters.is_empty() {
+            return None;
+        }
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.adapters.len();
+        self.adapters.get(index)
+    }
+}
+
+macro_rules! impl_composite_adapter {
+    ($adapter:ident, $dispatch:ident, $kind:literal) => {
+        #[async_trait]
+        impl<A: FinalityProvider> FinalityProvider for $adapter<A> {
+            type BlockId = A::BlockId;
+
+            async fn latest_finalized_block(&self) -> Result<FinalizedBlock<Self::BlockId>, AdapterError> {
+                $dispatch!(self, a => a.latest_finalized_block())
+            }
+
+            async fn wait_for_finality(
+                &self,
+                block: &Self::BlockId,
+                timeout: Option<Duration>,
+            ) -> Result<FinalizedBlock<Self::BlockId>, AdapterError> {
+                $dispatch!(self, a => a.wait_for_finality(block, timeout))
+            }
+
+            async fn is_finalized(&self, block: &Self::BlockId) -> Result<bool, AdapterError> {
+                $dispatch!(self, a => a.is_finalized(block))
+            }
+
+            async fn finalized_height(&self) -> Result<u64, AdapterError> {
+                $dispatch!(self, a => a.finalized_height())
+            }
+        }
+
+        #[async_trait]
+        impl<A: MessageProver> MessageProver for $adapter<A> {
+            async fn generate_proof(&self, message: &FrostMessage) -> Result<Vec<u8>, AdapterError> {
+                $dispatch!(self, a => a.generate_proof(message))
+            }
+
+            async fn verify_proof(&self, message: &FrostMessage) -> Result<bool, AdapterError> {
+                $dispatch!(self, a => a.verify_proof(message))
+            }
+        }
+
+        #[async_trait]
+        impl<A: MessageSubmitter> MessageSubmitter for $adapter<A> {
+            type TxId = A::TxId;
+
+            async fn submit_message(
+                &self,
+                message: &FrostMessage,
+                options: Option<SubmissionOptions>,
+            ) -> Result<Self::TxId, AdapterError> {
+                $dispatch!(self, a => a.submit_message(message, options.clone()))
+            }
+
+            async fn get_transaction(
+                &self,
+                tx_id: &Self::TxId,
+            ) -> Result<Option<TransactionDetails>, AdapterError> {
+                $dispatch!(self, a => a.get_transaction(tx_id))
+            }
+
+            async fn wait_for_confirmation(
+                &self,
+                tx_id: &Self::TxId,
+                timeout: Option<Duration>,
+            ) -> Result<TransactionDetails, AdapterError> {
+                $dispatch!(self, a => a.wait_for_confirmation(tx_id, timeout))
+            }
+
+            async fn estimate_fee(&self, message: &FrostMessage) -> Result<u128, AdapterError> {
+                $dispatch!(self, a => a.estimate_fee(message))
+            }
+
+            async fn get_receipt(
+                &self,
+                tx_id: &Self::TxId,
+            ) -> Result<Option<ParsedTransaction>, AdapterError> {
+                $dispatch!(self, a => a.get_receipt(tx_id))
+            }
+        }
+
+        #[async_trait]
+        impl<A: EventListener> EventListener for $adapter<A> {
+            async fn listen_for_events(&self) -> Result<Vec<MessageEvent>, AdapterError> {
+                $dispatch!(self, a => a.listen_for_events())
+            }
+
+            async fn filter_events(
+                &self,
+                from_block: Option<u64>,
+                to_block: Option<u64>,
+                event_types: Option<Vec<String>>,
+            ) -> Result<Vec<MessageEvent>, AdapterError> {
+                $dispatch!(self, a => a.filter_events(from_block, to_block, event_types.clone()))
+            }
+
+            async fn subscribe(&self) -> Result<EventSubscription, AdapterError> {
+                $dispatch!(self, a => a.subscribe())
+            }
+        }
+
+        #[async_trait]
+        impl<A: CapabilityProvider> CapabilityProvider for $adapter<A> {
+            async fn capabilities(&self) -> Result<ChainCapabilities, AdapterError> {
+                $dispatch!(self, a => a.capabilities())
+            }
+
+            async fn supports_capability(&self, capability: &str) -> Result<bool, AdapterError> {
+                $dispatch!(self, a => a.supports_capability(capability))
+            }
+
+            async fn connection_status(&self) -> Result<ConnectionStatus, AdapterError> {
+                $dispatch!(self, a => a.connection_status())
+            }
+
+            async fn health_metrics(&self) -> Result<HealthMetrics, AdapterError> {
+                $dispatch!(self, a => a.health_metrics())
+            }
+        }
+
+        #[async_trait]
+        impl<A: ChainAdapter> ChainAdapter for $adapter<A> {
+            fn chain_id(&self) -> ChainId {
+                self.adapters
+                    .first()
+                    .map(|a| a.chain_id())
+                    .unwrap_or(ChainId::Unknown)
+            }
+
+            fn adapter_id(&self) -> String {
+                composite_id($kind, self.adapters.iter().map(|a| a.adapter_id()))
+            }
+
+            async fn estimate_finality_time(&self, block: &Self::BlockId) -> Result<Duration, AdapterError> {
+                $dispatch!(self, a => a.estimate_finality_time(block))
+            }
+
+            async fn wait_for_finality_batch(
+                &self,
+                blocks: &[Self::BlockId],
+                timeout: Option<Duration>,
+            ) -> Result<Vec<FinalizedBlock<Self::BlockId>>, AdapterError> {
+                $dispatch!(self, a => a.wait_for_finality_batch(blocks, timeout))
+            }
+
+            async fn fee_parameters(&self) -> Result<FeeParameters, AdapterError> {
+                $dispatch!(self, a => a.fee_parameters())
+            }
+
+            async fn message_status(&self, id: Uuid) -> Result<Option<MessageStatus>, AdapterError> {
+                $dispatch!(self, a => a.message_status(id))
+            }
+
+            async fn message_status_batch(
+                &self,
+                ids: &[Uuid],
+            ) -> Result<HashMap<Uuid, Option<MessageStatus>>, AdapterError> {
+                $dispatch!(self, a => a.message_status_batch(ids))
+            }
+        }
+    };
+}
+
+impl_composite_adapter!(FailoverAdapter, failover, "failover");
+impl_composite_adapter!(LoadBalancedAdapter, balanced, "balanced");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::mock::{MockAdapter, MockFailure};
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), 1, 1_725_000_000)
+    }
+
+    #[tokio::test]
+    async fn test_failover_to_second_adapter() {
+        let adapter = FailoverAdapter::new(vec![
+            MockAdapter::failing("primary", MockFailure::Retryable),
+            MockAdapter::new("secondary"),
+        ]);
+
+        assert!(adapter.submit_message(&message(), None).await.is_ok());
+        assert_eq!(adapter.adapters()[0].calls(), 1);
+        assert_eq!(adapter.adapters()[1].submitted.lock().len(), 1);
+        assert_eq!(adapter.adapter_id(), "failover(primary,secondary)");
+    }
+
+    #[tokio::test]
+    async fn test_failover_stops_on_fatal_error() {
+        let adapter = FailoverAdapter::new(vec![
+            MockAdapter::failing("primary", MockFailure::Fatal),
+            MockAdapter::new("secondary"),
+        ]);
+
+        let result = adapter.submit_message(&message(), None).await;
+        assert!(matches!(result, Err(AdapterError::MessageFormat(_))));
+        assert_eq!(adapter.adapters()[1].calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_failover_exhausted() {
+        let adapter = FailoverAdapter::new(vec![
+            MockAdapter::failing("primary", MockFailure::Retryable),
+            MockAdapter::failing("secondary", MockFailure::Retryable),
+        ]);
+
+        let result = adapter.finalized_height().await;
+        assert!(matches!(result, Err(AdapterError::Connection(ref e)) if e.contains("secondary")));
+    }
+
+    #[tokio::test]
+    async fn test_load_balanced_round_robin() {
+        let adapter = LoadBalancedAdapter::new(vec![MockAdapter::new("a"), MockAdapter::new("b")]);
+
+        for _ in 0..4 {
+            adapter.estimate_fee(&message()).await.unwrap();
+        }
+        assert_eq!(adapter.adapters()[0].calls(), 2);
+        assert_eq!(adapter.adapters()[1].calls(), 2);
+
+        let empty = LoadBalancedAdapter::<MockAdapter>::new(Vec::new());
+        assert!(matches!(empty.estimate_fee(&message()).await, Err(AdapterError::Configuration(_))));
+    }
+}
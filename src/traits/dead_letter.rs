@@ -0,0 +1,297 @@
+//! Dead-letter queues for messages that permanently failed to relay
+
+use async_trait::async_trait;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use super::MessageSubmitter;
+use crate::messages::FrostMessage;
+use crate::types::{AdapterError, ParsedTransaction, SubmissionOptions, TransactionDetails};
+
+/// A message set aside after it permanently failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// The failed message
+    pub message: FrostMessage,
+    /// Why the message failed
+    pub reason: String,
+    /// When the message was dead-lettered
+    pub failed_at: SystemTime,
+}
+
+/// Storage for messages awaiting manual inspection
+pub trait DeadLetterQueue: Send + Sync {
+    /// Record a failed message with the reason it failed
+    fn push(&self, message: FrostMessage, reason: String) -> Result<(), AdapterError>;
+
+    /// Remove and return every queued message, oldest first
+    fn drain(&self) -> Result<Vec<DeadLetter>, AdapterError>;
+}
+
+/// Dead-letter queue held in memory
+#[derive(Debug, Default)]
+pub struct InMemoryDeadLetterQueue {
+    letters: parking_lot::Mutex<Vec<DeadLetter>>,
+}
+
+impl InMemoryDeadLetterQueue {
+    /// Create an empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of queued messages
+    pub fn len(&self) -> usize {
+        self.letters.lock().len()
+    }
+
+    /// Whether the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.letters.lock().is_empty()
+    }
+}
+
+impl DeadLetterQueue for InMemoryDeadLetterQueue {
+    fn push(&self, message: FrostMessage, reason: String) -> Result<(), AdapterError> {
+        self.letters.lock().push(DeadLetter {
+            message,
+            reason,
+            failed_at: SystemTime::now(),
+        });
+        Ok(())
+    }
+
+    fn drain(&self) -> Result<Vec<DeadLetter>, AdapterError> {
+        Ok(std::mem::take(&mut *self.letters.lock()))
+    }
+}
+
+/// Dead-letter queue persisted as newline-delimited JSON
+///
+/// Each pushed message is appended to the file as one line, so queued
+/// messages survive restarts. Draining reads every line and truncates the
+/// file.
+#[derive(Debug)]
+pub struct FileDeadLetterQueue {
+    path: PathBuf,
+    lock: parking_lot::Mutex<()>,
+}
+
+impl FileDeadLetterQueue {
+    /// Use the file at `path`, created on first push
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: parking_lot::Mutex::new(()),
+        }
+    }
+
+    /// Path of the backing file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+fn io_error(context: &'static str, error: impl std::error::Error + Send + Sync + 'static) -> AdapterError {
+    AdapterError::Other(anyhow::Error::new(error).context(context))
+}
+
+impl DeadLetterQueue for FileDeadLetterQueue {
+    fn push(&self, message: FrostMessage, reason: String) -> Result<(), AdapterError> {
+        let letter = DeadLetter {
+            message,
+            reason,
+            failed_at: SystemTime::now(),
+        };
+        let mut line = serde_json::to_vec(&letter).map_err(|e| io_error("Failed to encode dead letter", e))?;
+        line.push(b'\n');
+
+        let _guard = self.lock.lock();
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(&line))
+            .map_err(|e| io_error("Failed to write dead letter", e))
+    }
+
+    fn drain(&self) -> Result<Vec<DeadLetter>, AdapterError> {
+        let _guard = self.lock.lock();
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error("Failed to read dead letters", e)),
+        };
+        let letters = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(|e| io_error("Malformed dead letter", e)))
+            .collect::<Result<Vec<DeadLetter>, _>>()?;
+        fs::write(&self.path, b"").map_err(|e| io_error("Failed to truncate dead letters", e))?;
+        Ok(letters)
+    }
+}
+
+/// Adapter wrapper sending messages that fail to submit to a dead-letter queue.
+///
+/// Every `submit_message` error is recorded with the error as reason before
+/// being returned, so the wrapper belongs outside any retrying layer (e.g.
+/// around a [`FailoverAdapter`](super::FailoverAdapter)) where an error
+/// means retries are exhausted. All other operations are passed through
+/// unchanged.
+pub struct DeadLetterAdapter<A> {
+    inner: A,
+    queue: Arc<dyn DeadLetterQueue>,
+}
+
+impl<A> DeadLetterAdapter<A> {
+    /// Wrap an adapter, dead-lettering failed submissions to `queue`
+    pub fn new(inner: A, queue: Arc<dyn DeadLetterQueue>) -> Self {
+        Self { inner, queue }
+    }
+
+    /// Get the dead-letter queue
+    pub fn queue(&self) -> &Arc<dyn DeadLetterQueue> {
+        &self.queue
+    }
+
+    /// Get a reference to the wrapped adapter
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Unwrap the inner adapter
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<A: MessageSubmitter> MessageSubmitter for DeadLetterAdapter<A> {
+    type TxId = A::TxId;
+
+    async fn submit_message(
+        &self,
+        message: &FrostMessage,
+        options: Option<SubmissionOptions>,
+    ) -> Result<Self::TxId, AdapterError> {
+        match self.inner.submit_message(message, options).await {
+            Ok(tx_id) => Ok(tx_id),
+            Err(e) => {
+                if let Err(queue_error) = self.queue.push(message.clone(), e.to_string()) {
+                    return Err(AdapterError::Other(anyhow::anyhow!(
+                        "{}; dead-lettering message {} failed: {}",
+                        e,
+                        message.id,
+                        queue_error
+                    )));
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_transaction(
+        &self,
+        tx_id: &Self::TxId,
+    ) -> Result<Option<TransactionDetails>, AdapterError> {
+        self.inner.get_transaction(tx_id).await
+    }
+
+    async fn wait_for_confirmation(
+        &self,
+        tx_id: &Self::TxId,
+        timeout: Option<Duration>,
+    ) -> Result<TransactionDetails, AdapterError> {
+        self.inner.wait_for_confirmation(tx_id, timeout).await
+    }
+
+    async fn estimate_fee(&self, message: &FrostMessage) -> Result<u128, AdapterError> {
+        self.inner.estimate_fee(message).await
+    }
+
+    async fn get_receipt(
+        &self,
+        tx_id: &Self::TxId,
+    ) -> Result<Option<ParsedTransaction>, AdapterError> {
+        self.inner.get_receipt(tx_id).await
+    }
+}
+
+forward_to_inner_adapter!(DeadLetterAdapter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ChainId;
+    use crate::traits::mock::{MockAdapter, MockFailure};
+    use crate::traits::FailoverAdapter;
+    use uuid::Uuid;
+
+    fn message(nonce: u64) -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), nonce, 1_725_000_000)
+    }
+
+    #[test]
+    fn test_in_memory_queue() {
+        let queue = InMemoryDeadLetterQueue::new();
+        let (first, second) = (message(1), message(2));
+        queue.push(first.clone(), "reverted".to_string()).unwrap();
+        queue.push(second.clone(), "nonce too low".to_string()).unwrap();
+        assert_eq!(queue.len(), 2);
+
+        let letters = queue.drain().unwrap();
+        let drained: Vec<(Uuid, &str)> = letters.iter().map(|l| (l.message.id, l.reason.as_str())).collect();
+        assert_eq!(drained, vec![(first.id, "reverted"), (second.id, "nonce too low")]);
+        assert!(letters[0].failed_at <= letters[1].failed_at);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_file_queue() {
+        let path = std::env::temp_dir().join(format!("frostgate-dead-letters-{}.jsonl", Uuid::new_v4()));
+        let queue = FileDeadLetterQueue::new(&path);
+        assert!(queue.drain().unwrap().is_empty());
+
+        let (first, second) = (message(1), message(2));
+        queue.push(first.clone(), "reverted".to_string()).unwrap();
+        queue.push(second.clone(), "out of gas".to_string()).unwrap();
+
+        // A fresh handle on the same file sees the queued messages
+        let letters = FileDeadLetterQueue::new(&path).drain().unwrap();
+        assert_eq!(letters.len(), 2);
+        assert_eq!(letters[0].message.id, first.id);
+        assert_eq!(letters[1].reason, "out of gas");
+        assert!(queue.drain().unwrap().is_empty());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failed_submissions_dead_lettered() {
+        let queue = Arc::new(InMemoryDeadLetterQueue::new());
+        let adapter = DeadLetterAdapter::new(
+            FailoverAdapter::new(vec![
+                MockAdapter::failing("primary", MockFailure::Retryable),
+                MockAdapter::failing("secondary", MockFailure::Retryable),
+            ]),
+            queue.clone(),
+        );
+
+        let failed = message(1);
+        assert!(adapter.submit_message(&failed, None).await.is_err());
+        let letters = queue.drain().unwrap();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].message.id, failed.id);
+        assert!(letters[0].reason.contains("secondary unreachable"));
+
+        let healthy = DeadLetterAdapter::new(MockAdapter::new("mock"), queue.clone());
+        assert!(healthy.submit_message(&message(2), None).await.is_ok());
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! Message expiry enforcement at the submission boundary
+
+use async_trait::async_trait;
+use std::time::Duration;
+
+use super::MessageSubmitter;
+use crate::messages::{FrostMessage, SkewTolerance};
+use crate::types::{
+    AdapterError, ParsedTransaction, SubmissionError, SubmissionOptions, TransactionDetails,
+};
+
+/// Adapter wrapper that refuses to submit expired messages.
+///
+/// Before delegating `submit_message` to the inner adapter, the message is
+/// checked against the configured maximum age, allowing the configured
+/// [`SkewTolerance`]. Stale messages, and messages timestamped further in
+/// the future than the tolerance, are rejected with
+/// [`SubmissionError::InvalidMessage`]. All other operations are passed
+/// through unchanged.
+pub struct ExpiryGuardAdapter<A> {
+    inner: A,
+    max_age: Duration,
+    skew: SkewTolerance,
+}
+
+impl<A> ExpiryGuardAdapter<A> {
+    /// Wrap an adapter, rejecting messages older than `max_age`
+    pub fn new(inner: A, max_age: Duration) -> Self {
+        Self {
+            inner,
+            max_age,
+            skew: SkewTolerance::default(),
+        }
+    }
+
+    /// Set the tolerated clock skew
+    pub fn with_skew_tolerance(mut self, skew: SkewTolerance) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// Get the maximum accepted message age
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Get the tolerated clock skew
+    pub fn skew_tolerance(&self) -> SkewTolerance {
+        self.skew
+    }
+
+    /// Get a reference to the wrapped adapter
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Unwrap the inner adapter
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<A: MessageSubmitter> MessageSubmitter for ExpiryGuardAdapter<A> {
+    type TxId = A::TxId;
+
+    async fn submit_message(
+        &self,
+        message: &FrostMessage,
+        options: Option<SubmissionOptions>,
+    ) -> Result<Self::TxId, AdapterError> {
+        if message.is_expired_with(self.max_age, self.skew) {
+            return Err(SubmissionError::InvalidMessage(format!(
+                "message {} expired (max age {}s)",
+                message.id,
+                self.max_age.as_secs()
+            ))
+            .into());
+        }
+        if message.is_from_future(self.skew) {
+            return Err(SubmissionError::InvalidMessage(format!(
+                "message {} timestamped in the future (tolerance {}s)",
+                message.id,
+                self.skew.tolerance().as_secs()
+            ))
+            .into());
+        }
+        self.inner.submit_message(message, options).await
+    }
+
+    async fn get_transaction(
+        &self,
+        tx_id: &Self::TxId,
+    ) -> Result<Option<TransactionDetails>, AdapterError> {
+        self.inner.get_transaction(tx_id).await
+    }
+
+    async fn wait_for_confirmation(
+        &self,
+        tx_id: &Self::TxId,
+        timeout: Option<Duration>,
+    ) -> Result<TransactionDetails, AdapterError> {
+        self.inner.wait_for_confirmation(tx_id, timeout).await
+    }
+
+    async fn estimate_fee(&self, message: &FrostMessage) -> Result<u128, AdapterError> {
+        self.inner.estimate_fee(message).await
+    }
+
+    async fn get_receipt(
+        &self,
+        tx_id: &Self::TxId,
+    ) -> Result<Option<ParsedTransaction>, AdapterError> {
+        self.inner.get_receipt(tx_id).await
+    }
+}
+
+forward_to_inner_adapter!(ExpiryGuardAdapter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ChainId;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // Mock submitter counting delegated submissions
+    #[derive(Default)]
+    struct MockSubmitter {
+        submitted: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MessageSubmitter for MockSubmitter {
+        type TxId = u64;
+
+        async fn submit_message(
+            &self,
+            _message: &FrostMessage,
+            _options: Option<SubmissionOptions>,
+        ) -> Result<u64, AdapterError> {
+            Ok(self.submitted.fetch_add(1, Ordering::SeqCst) as u64)
+        }
+
+        async fn get_transaction(&self, _tx_id: &u64) -> Result<Option<TransactionDetails>, AdapterError> {
+            Ok(None)
+        }
+
+        async fn wait_for_confirmation(
+            &self,
+            _tx_id: &u64,
+            _timeout: Option<Duration>,
+        ) -> Result<TransactionDetails, AdapterError> {
+            Ok(TransactionDetails::Raw(vec![]))
+        }
+
+        async fn estimate_fee(&self, _message: &FrostMessage) -> Result<u128, AdapterError> {
+            Ok(0)
+        }
+    }
+
+    fn message_aged(age: Duration) -> FrostMessage {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), 1, now - age.as_secs())
+    }
+
+    fn message_ahead(ahead: Duration) -> FrostMessage {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, b"test".to_vec(), 1, now + ahead.as_secs())
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_tolerated() {
+        let adapter = ExpiryGuardAdapter::new(MockSubmitter::default(), Duration::from_secs(60))
+            .with_skew_tolerance(SkewTolerance::new(Duration::from_secs(30)));
+
+        // Slightly ahead of our clock, or slightly past the maximum age
+        assert!(adapter.submit_message(&message_ahead(Duration::from_secs(10)), None).await.is_ok());
+        assert!(adapter.submit_message(&message_aged(Duration::from_secs(75)), None).await.is_ok());
+
+        assert!(adapter.submit_message(&message_ahead(Duration::from_secs(120)), None).await.is_err());
+        assert!(adapter.submit_message(&message_aged(Duration::from_secs(120)), None).await.is_err());
+        assert_eq!(adapter.inner().submitted.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fresh_message_submitted() {
+        let adapter = ExpiryGuardAdapter::new(MockSubmitter::default(), Duration::from_secs(60));
+
+        let result = adapter.submit_message(&message_aged(Duration::from_secs(10)), None).await;
+        assert!(result.is_ok());
+        assert_eq!(adapter.inner().submitted.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_message_rejected() {
+        let adapter = ExpiryGuardAdapter::new(MockSubmitter::default(), Duration::from_secs(60));
+
+        let result = adapter.submit_message(&message_aged(Duration::from_secs(120)), None).await;
+        assert!(matches!(
+            result,
+            Err(AdapterError::Transaction(SubmissionError::InvalidMessage(_)))
+        ));
+        assert_eq!(adapter.inner().submitted.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,176 @@
+//! Absolute per-chain fee ceilings enforced at the submission boundary
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::{ChainAdapter, MessageSubmitter};
+use crate::messages::{ChainId, FrostMessage};
+use crate::types::{
+    AdapterError, ParsedTransaction, SubmissionError, SubmissionOptions, TransactionDetails,
+};
+
+/// Maximum fee the relayer will pay per submission, by chain
+///
+/// Chains without a ceiling are not limited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeeCeilings {
+    ceilings: HashMap<ChainId, u128>,
+}
+
+impl FeeCeilings {
+    /// Create an empty set of ceilings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the ceiling for a chain, in the chain's smallest fee unit
+    pub fn with_ceiling(mut self, chain: ChainId, max_fee: u128) -> Self {
+        self.ceilings.insert(chain, max_fee);
+        self
+    }
+
+    /// Get the ceiling for a chain
+    pub fn ceiling(&self, chain: ChainId) -> Option<u128> {
+        self.ceilings.get(&chain).copied()
+    }
+
+    /// Check a fee estimate against the chain's ceiling
+    pub fn check(&self, chain: ChainId, estimate: u128) -> Result<(), AdapterError> {
+        match self.ceiling(chain) {
+            Some(ceiling) if estimate > ceiling => Err(SubmissionError::Failed(format!(
+                "estimated fee {} exceeds {:?} ceiling {}",
+                estimate, chain, ceiling
+            ))
+            .into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Adapter wrapper that refuses submissions whose fee exceeds a ceiling.
+///
+/// Before delegating `submit_message`, the fee is estimated with the inner
+/// adapter and checked against the ceiling for the adapter's chain,
+/// independently of [`SubmissionOptions::max_gas_price`]. Submissions over
+/// the ceiling fail with [`SubmissionError::Failed`]. All other operations
+/// are passed through unchanged.
+pub struct FeeCeilingAdapter<A> {
+    inner: A,
+    ceilings: FeeCeilings,
+}
+
+impl<A> FeeCeilingAdapter<A> {
+    /// Wrap an adapter, enforcing the given ceilings
+    pub fn new(inner: A, ceilings: FeeCeilings) -> Self {
+        Self { inner, ceilings }
+    }
+
+    /// Get the enforced ceilings
+    pub fn ceilings(&self) -> &FeeCeilings {
+        &self.ceilings
+    }
+
+    /// Get a reference to the wrapped adapter
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Unwrap the inner adapter
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<A: ChainAdapter> MessageSubmitter for FeeCeilingAdapter<A> {
+    type TxId = A::TxId;
+
+    async fn submit_message(
+        &self,
+        message: &FrostMessage,
+        options: Option<SubmissionOptions>,
+    ) -> Result<Self::TxId, AdapterError> {
+        let estimate = self.inner.estimate_fee(message).await?;
+        self.ceilings.check(self.inner.chain_id(), estimate)?;
+        self.inner.submit_message(message, options).await
+    }
+
+    async fn get_transaction(
+        &self,
+        tx_id: &Self::TxId,
+    ) -> Result<Option<TransactionDetails>, AdapterError> {
+        self.inner.get_transaction(tx_id).await
+    }
+
+    async fn wait_for_confirmation(
+        &self,
+        tx_id: &Self::TxId,
+        timeout: Option<Duration>,
+    ) -> Result<TransactionDetails, AdapterError> {
+        self.inner.wait_for_confirmation(tx_id, timeout).await
+    }
+
+    async fn estimate_fee(&self, message: &FrostMessage) -> Result<u128, AdapterError> {
+        self.inner.estimate_fee(message).await
+    }
+
+    async fn get_receipt(
+        &self,
+        tx_id: &Self::TxId,
+    ) -> Result<Option<ParsedTransaction>, AdapterError> {
+        self.inner.get_receipt(tx_id).await
+    }
+}
+
+forward_to_inner_adapter!(FeeCeilingAdapter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::mock::MockAdapter;
+
+    fn adapter(chain_id: ChainId, fee: u128) -> FeeCeilingAdapter<MockAdapter> {
+        let ceilings = FeeCeilings::new()
+            .with_ceiling(ChainId::Ethereum, 2_000)
+            .with_ceiling(ChainId::Solana, 4_000);
+        FeeCeilingAdapter::new(
+            MockAdapter {
+                chain_id,
+                fee,
+                ..MockAdapter::new("mock")
+            },
+            ceilings,
+        )
+    }
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Polkadot, ChainId::Ethereum, b"test".to_vec(), 1, 1_725_000_000)
+    }
+
+    #[tokio::test]
+    async fn test_fee_under_ceiling_submitted() {
+        for (chain_id, fee) in [(ChainId::Ethereum, 2_000), (ChainId::Solana, 3_999)] {
+            let adapter = adapter(chain_id, fee);
+            assert!(adapter.submit_message(&message(), None).await.is_ok());
+            assert_eq!(adapter.inner().submitted.lock().len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fee_over_ceiling_rejected() {
+        for (chain_id, fee) in [(ChainId::Ethereum, 2_001), (ChainId::Solana, 10_000)] {
+            let adapter = adapter(chain_id, fee);
+            let result = adapter.submit_message(&message(), None).await;
+            assert!(matches!(result, Err(AdapterError::Transaction(SubmissionError::Failed(_)))));
+            assert!(adapter.inner().submitted.lock().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_without_ceiling_unlimited() {
+        let adapter = adapter(ChainId::Polkadot, u128::MAX);
+        assert!(adapter.submit_message(&message(), None).await.is_ok());
+        assert_eq!(adapter.ceilings().ceiling(ChainId::Polkadot), None);
+    }
+}
@@ -0,0 +1,144 @@
+//! Pass-through trait implementations for single-adapter wrappers
+
+/// Implement every adapter trait except [`MessageSubmitter`] for a wrapper
+/// by delegating to its `inner` adapter.
+///
+/// Wrappers that guard the submission boundary implement
+/// `MessageSubmitter` themselves and use this for the rest.
+///
+/// [`MessageSubmitter`]: crate::traits::MessageSubmitter
+macro_rules! forward_to_inner_adapter {
+    ($adapter:ident) => {
+        #[::async_trait::async_trait]
+        impl<A: $crate::traits::FinalityProvider> $crate::traits::FinalityProvider for $adapter<A> {
+            type BlockId = A::BlockId;
+
+            async fn latest_finalized_block(
+                &self,
+            ) -> Result<$crate::types::FinalizedBlock<Self::BlockId>, $crate::types::AdapterError> {
+                self.inner.latest_finalized_block().await
+            }
+
+            async fn wait_for_finality(
+                &self,
+                block: &Self::BlockId,
+                timeout: Option<::std::time::Duration>,
+            ) -> Result<$crate::types::FinalizedBlock<Self::BlockId>, $crate::types::AdapterError> {
+                self.inner.wait_for_finality(block, timeout).await
+            }
+
+            async fn is_finalized(&self, block: &Self::BlockId) -> Result<bool, $crate::types::AdapterError> {
+                self.inner.is_finalized(block).await
+            }
+
+            async fn finalized_height(&self) -> Result<u64, $crate::types::AdapterError> {
+                self.inner.finalized_height().await
+            }
+        }
+
+        #[::async_trait::async_trait]
+        impl<A: $crate::traits::MessageProver> $crate::traits::MessageProver for $adapter<A> {
+            async fn generate_proof(
+                &self,
+                message: &$crate::messages::FrostMessage,
+            ) -> Result<Vec<u8>, $crate::types::AdapterError> {
+                self.inner.generate_proof(message).await
+            }
+
+            async fn verify_proof(
+                &self,
+                message: &$crate::messages::FrostMessage,
+            ) -> Result<bool, $crate::types::AdapterError> {
+                self.inner.verify_proof(message).await
+            }
+        }
+
+        #[::async_trait::async_trait]
+        impl<A: $crate::traits::EventListener> $crate::traits::EventListener for $adapter<A> {
+            async fn listen_for_events(
+                &self,
+            ) -> Result<Vec<$crate::messages::MessageEvent>, $crate::types::AdapterError> {
+                self.inner.listen_for_events().await
+            }
+
+            async fn filter_events(
+                &self,
+                from_block: Option<u64>,
+                to_block: Option<u64>,
+                event_types: Option<Vec<String>>,
+            ) -> Result<Vec<$crate::messages::MessageEvent>, $crate::types::AdapterError> {
+                self.inner.filter_events(from_block, to_block, event_types).await
+            }
+
+            async fn subscribe(&self) -> Result<$crate::traits::EventSubscription, $crate::types::AdapterError> {
+                self.inner.subscribe().await
+            }
+        }
+
+        #[::async_trait::async_trait]
+        impl<A: $crate::traits::CapabilityProvider> $crate::traits::CapabilityProvider for $adapter<A> {
+            async fn capabilities(&self) -> Result<$crate::types::ChainCapabilities, $crate::types::AdapterError> {
+                self.inner.capabilities().await
+            }
+
+            async fn supports_capability(&self, capability: &str) -> Result<bool, $crate::types::AdapterError> {
+                self.inner.supports_capability(capability).await
+            }
+
+            async fn connection_status(&self) -> Result<$crate::types::ConnectionStatus, $crate::types::AdapterError> {
+                self.inner.connection_status().await
+            }
+
+            async fn health_metrics(&self) -> Result<$crate::types::HealthMetrics, $crate::types::AdapterError> {
+                self.inner.health_metrics().await
+            }
+        }
+
+        #[::async_trait::async_trait]
+        impl<A: $crate::traits::ChainAdapter> $crate::traits::ChainAdapter for $adapter<A> {
+            fn chain_id(&self) -> $crate::messages::ChainId {
+                self.inner.chain_id()
+            }
+
+            fn adapter_id(&self) -> String {
+                self.inner.adapter_id()
+            }
+
+            async fn estimate_finality_time(
+                &self,
+                block: &Self::BlockId,
+            ) -> Result<::std::time::Duration, $crate::types::AdapterError> {
+                self.inner.estimate_finality_time(block).await
+            }
+
+            async fn wait_for_finality_batch(
+                &self,
+                blocks: &[Self::BlockId],
+                timeout: Option<::std::time::Duration>,
+            ) -> Result<Vec<$crate::types::FinalizedBlock<Self::BlockId>>, $crate::types::AdapterError> {
+                self.inner.wait_for_finality_batch(blocks, timeout).await
+            }
+
+            async fn fee_parameters(&self) -> Result<$crate::types::FeeParameters, $crate::types::AdapterError> {
+                self.inner.fee_parameters().await
+            }
+
+            async fn message_status(
+                &self,
+                id: ::uuid::Uuid,
+            ) -> Result<Option<$crate::messages::MessageStatus>, $crate::types::AdapterError> {
+                self.inner.message_status(id).await
+            }
+
+            async fn message_status_batch(
+                &self,
+                ids: &[::uuid::Uuid],
+            ) -> Result<
+                ::std::collections::HashMap<::uuid::Uuid, Option<$crate::messages::MessageStatus>>,
+                $crate::types::AdapterError,
+            > {
+                self.inner.message_status_batch(ids).await
+            }
+        }
+    };
+}
@@ -0,0 +1,251 @@
+//! Mock chain adapter shared by the adapter tests
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+
+use super::*;
+use crate::messages::ChainId;
+use crate::types::{FinalityType, SubmissionError, TransactionStatus};
+
+/// How a mock adapter should fail its operations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MockFailure {
+    /// Fail with a retryable connection error
+    Retryable,
+    /// Fail with a non-retryable message format error
+    Fatal,
+}
+
+/// Configurable in-memory chain adapter
+pub struct MockAdapter {
+    pub id: String,
+    pub chain_id: ChainId,
+    pub height: u64,
+    pub fee: u128,
+    pub finality_type: FinalityType,
+    pub failure: Option<MockFailure>,
+    pub calls: AtomicUsize,
+    pub submitted: parking_lot::Mutex<Vec<FrostMessage>>,
+    pub statuses: parking_lot::Mutex<HashMap<Uuid, MessageStatus>>,
+}
+
+impl MockAdapter {
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            chain_id: ChainId::Ethereum,
+            height: 100,
+            fee: 1_000,
+            finality_type: FinalityType::Deterministic,
+            failure: None,
+            calls: AtomicUsize::new(0),
+            submitted: parking_lot::Mutex::new(Vec::new()),
+            statuses: parking_lot::Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn failing(id: &str, failure: MockFailure) -> Self {
+        Self {
+            failure: Some(failure),
+            ..Self::new(id)
+        }
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    fn call(&self) -> Result<(), AdapterError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        match self.failure {
+            None => Ok(()),
+            Some(MockFailure::Retryable) => Err(AdapterError::Connection(format!("{} unreachable", self.id))),
+            Some(MockFailure::Fatal) => Err(AdapterError::MessageFormat(format!("{} rejected message", self.id))),
+        }
+    }
+
+    fn block(&self, block: u64) -> FinalizedBlock<u64> {
+        FinalizedBlock {
+            block,
+            finality_proof: None,
+            finalized_at: SystemTime::now(),
+            confirmations: None,
+        }
+    }
+}
+
+#[async_trait]
+impl FinalityProvider for MockAdapter {
+    type BlockId = u64;
+
+    async fn latest_finalized_block(&self) -> Result<FinalizedBlock<u64>, AdapterError> {
+        self.call()?;
+        Ok(self.block(self.height))
+    }
+
+    async fn wait_for_finality(
+        &self,
+        block: &u64,
+        _timeout: Option<Duration>,
+    ) -> Result<FinalizedBlock<u64>, AdapterError> {
+        self.call()?;
+        Ok(self.block(*block))
+    }
+
+    async fn is_finalized(&self, block: &u64) -> Result<bool, AdapterError> {
+        self.call()?;
+        Ok(*block <= self.height)
+    }
+}
+
+#[async_trait]
+impl MessageProver for MockAdapter {
+    async fn generate_proof(&self, _message: &FrostMessage) -> Result<Vec<u8>, AdapterError> {
+        self.call()?;
+        Ok(vec![1, 2, 3, 4])
+    }
+
+    async fn verify_proof(&self, message: &FrostMessage) -> Result<bool, AdapterError> {
+        self.call()?;
+        Ok(message.proof.is_some())
+    }
+}
+
+#[async_trait]
+impl MessageSubmitter for MockAdapter {
+    type TxId = u64;
+
+    async fn submit_message(
+        &self,
+        message: &FrostMessage,
+        _options: Option<SubmissionOptions>,
+    ) -> Result<u64, AdapterError> {
+        self.call()?;
+        let mut submitted = self.submitted.lock();
+        submitted.push(message.clone());
+        Ok(submitted.len() as u64 - 1)
+    }
+
+    async fn get_transaction(&self, tx_id: &u64) -> Result<Option<TransactionDetails>, AdapterError> {
+        self.call()?;
+        Ok(self
+            .submitted
+            .lock()
+            .get(*tx_id as usize)
+            .map(|message| TransactionDetails::Raw(message.payload.clone())))
+    }
+
+    async fn wait_for_confirmation(
+        &self,
+        tx_id: &u64,
+        _timeout: Option<Duration>,
+    ) -> Result<TransactionDetails, AdapterError> {
+        self.get_transaction(tx_id)
+            .await?
+            .ok_or_else(|| AdapterError::Transaction(SubmissionError::Failed("unknown transaction".into())))
+    }
+
+    async fn estimate_fee(&self, _message: &FrostMessage) -> Result<u128, AdapterError> {
+        self.call()?;
+        Ok(self.fee)
+    }
+
+    async fn get_receipt(&self, tx_id: &u64) -> Result<Option<ParsedTransaction>, AdapterError> {
+        self.call()?;
+        Ok(self.submitted.lock().get(*tx_id as usize).map(|message| ParsedTransaction {
+            hash: tx_id.to_be_bytes().to_vec(),
+            from: None,
+            to: None,
+            value: 0,
+            data: message.payload.clone(),
+            status: TransactionStatus::Confirmed,
+            metadata: HashMap::from([("gas_used".to_string(), "21000".to_string())]),
+        }))
+    }
+}
+
+#[async_trait]
+impl EventListener for MockAdapter {
+    async fn listen_for_events(&self) -> Result<Vec<MessageEvent>, AdapterError> {
+        self.call()?;
+        Ok(Vec::new())
+    }
+
+    async fn filter_events(
+        &self,
+        _from_block: Option<u64>,
+        _to_block: Option<u64>,
+        _event_types: Option<Vec<String>>,
+    ) -> Result<Vec<MessageEvent>, AdapterError> {
+        self.call()?;
+        Ok(Vec::new())
+    }
+
+    async fn subscribe(&self) -> Result<EventSubscription, AdapterError> {
+        self.call()?;
+        Ok(EventSubscription {
+            id: self.id.clone(),
+            filter: None,
+        })
+    }
+}
+
+#[async_trait]
+impl CapabilityProvider for MockAdapter {
+    async fn capabilities(&self) -> Result<ChainCapabilities, AdapterError> {
+        self.call()?;
+        Ok(ChainCapabilities {
+            supports_smart_contracts: true,
+            supports_native_tokens: true,
+            supports_onchain_verification: true,
+            max_message_size: 1024,
+            proof_types: vec!["sp1".to_string()],
+            finality_type: self.finality_type.clone(),
+            max_proof_size: None,
+            supports_parallel_execution: false,
+            features: HashMap::new(),
+        })
+    }
+
+    async fn supports_capability(&self, capability: &str) -> Result<bool, AdapterError> {
+        self.call()?;
+        Ok(capability == "smart_contracts")
+    }
+
+    async fn connection_status(&self) -> Result<ConnectionStatus, AdapterError> {
+        self.call()?;
+        Ok(ConnectionStatus::Healthy)
+    }
+
+    async fn health_metrics(&self) -> Result<HealthMetrics, AdapterError> {
+        self.call()?;
+        Ok(HealthMetrics {
+            last_successful: Some(SystemTime::now()),
+            consecutive_failures: 0,
+            total_operations: self.calls() as u64,
+            failed_operations: 0,
+            avg_response_time: Duration::from_millis(1),
+            connection_status: ConnectionStatus::Healthy,
+            latest_block: Some(self.height),
+            custom_metrics: HashMap::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl ChainAdapter for MockAdapter {
+    fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    fn adapter_id(&self) -> String {
+        self.id.clone()
+    }
+
+    async fn message_status(&self, id: Uuid) -> Result<Option<MessageStatus>, AdapterError> {
+        self.call()?;
+        Ok(self.statuses.lock().get(&id).cloned())
+    }
+}
@@ -2,17 +2,67 @@
 
 use async_trait::async_trait;
 use crate::types::{
-    AdapterError, ChainCapabilities, ConnectionStatus, FinalizedBlock,
-    HealthMetrics, SubmissionOptions, TransactionDetails,
+    AdapterError, ChainCapabilities, ConnectionStatus, FeeParameters, FinalityType,
+    FinalizedBlock, HealthMetrics, ParsedTransaction, SubmissionOptions, TransactionDetails,
 };
-use crate::messages::{FrostMessage, MessageEvent};
+use crate::messages::{FrostMessage, MessageEvent, MessageStatus};
+use std::collections::HashMap;
 use std::time::Duration;
+use uuid::Uuid;
+
+#[macro_use]
+mod forward;
+
+mod composite;
+mod dead_letter;
+mod expiry;
+mod fee;
+#[cfg(test)]
+pub(crate) mod mock;
+mod payload;
+mod pipeline;
+mod router;
+mod serializer;
+mod watchdog;
+
+pub use composite::{FailoverAdapter, LoadBalancedAdapter};
+pub use dead_letter::{
+    DeadLetter, DeadLetterAdapter, DeadLetterQueue, FileDeadLetterQueue, InMemoryDeadLetterQueue,
+};
+pub use expiry::ExpiryGuardAdapter;
+pub use fee::{FeeCeilingAdapter, FeeCeilings};
+pub use payload::{
+    EvmPayloadValidator, PayloadValidator, PayloadValidators, SolanaPayloadValidator,
+    SubstratePayloadValidator,
+};
+#[cfg(feature = "compression")]
+pub use pipeline::CompressPayload;
+#[cfg(feature = "signing")]
+pub use pipeline::SignMessage;
+pub use pipeline::{MessagePipeline, MessageTransform, SetFee, PAYLOAD_ENCODING_KEY};
+pub use router::{CapabilityRequirements, CapabilityRouter};
+pub use serializer::{
+    BorshSerializer, EvmAbiSerializer, MessageSerializer, MessageSerializers, ScaleSerializer,
+};
+pub use watchdog::HealthWatchdog;
+
+/// Maps a chain's block identifier to its numeric height
+pub trait BlockHeight {
+    /// Get the height of the identified block
+    fn block_height(&self) -> u64;
+}
+
+impl BlockHeight for u64 {
+    fn block_height(&self) -> u64 {
+        *self
+    }
+}
 
 /// Provides finality-related functionality for a blockchain
 #[async_trait]
 pub trait FinalityProvider: Send + Sync {
     /// The type used to identify blocks in this chain
-    type BlockId: Clone + std::fmt::Debug + Send + Sync + 'static;
+    type BlockId: BlockHeight + Clone + std::fmt::Debug + Send + Sync + 'static;
 
     /// Get the latest finalized block
     async fn latest_finalized_block(&self) -> Result<FinalizedBlock<Self::BlockId>, AdapterError>;
@@ -26,6 +76,15 @@ pub trait FinalityProvider: Send + Sync {
 
     /// Check if a block is finalized
     async fn is_finalized(&self, block: &Self::BlockId) -> Result<bool, AdapterError>;
+
+    /// Get the height of the latest finalized block
+    ///
+    /// The default implementation maps the result of `latest_finalized_block`
+    /// through [`BlockHeight`]. Adapters that can query the height directly
+    /// should override this to avoid fetching the full block.
+    async fn finalized_height(&self) -> Result<u64, AdapterError> {
+        Ok(self.latest_finalized_block().await?.block.block_height())
+    }
 }
 
 /// Handles proof generation and verification
@@ -66,6 +125,32 @@ pub trait MessageSubmitter: Send + Sync {
 
     /// Estimate fee for submitting a message
     async fn estimate_fee(&self, message: &FrostMessage) -> Result<u128, AdapterError>;
+
+    /// Get the execution receipt of a transaction
+    ///
+    /// The receipt carries the execution `status` and chain-specific details
+    /// (gas used, emitted events, ...) in `metadata`. Returns `None` if the
+    /// transaction is unknown or not yet executed. The default implementation
+    /// returns the transaction details if the adapter reports them parsed.
+    async fn get_receipt(&self, tx_id: &Self::TxId) -> Result<Option<ParsedTransaction>, AdapterError> {
+        Ok(match self.get_transaction(tx_id).await? {
+            Some(TransactionDetails::Parsed(receipt)) => Some(receipt),
+            _ => None,
+        })
+    }
+
+    /// Submit several messages, returning their transaction IDs in input order
+    ///
+    /// The default implementation submits sequentially with default options
+    /// and stops at the first failure. Chains that can pack several messages
+    /// into one transaction should override this.
+    async fn submit_batch(&self, messages: &[FrostMessage]) -> Result<Vec<Self::TxId>, AdapterError> {
+        let mut tx_ids = Vec::with_capacity(messages.len());
+        for message in messages {
+            tx_ids.push(self.submit_message(message, None).await?);
+        }
+        Ok(tx_ids)
+    }
 }
 
 /// Handles event listening and filtering
@@ -109,6 +194,7 @@ pub trait CapabilityProvider: Send + Sync {
 }
 
 /// Combined trait for full chain adapter functionality
+#[async_trait]
 pub trait ChainAdapter:
     FinalityProvider + MessageProver + MessageSubmitter + EventListener + CapabilityProvider
 {
@@ -117,4 +203,264 @@ pub trait ChainAdapter:
 
     /// Get a unique identifier for this adapter instance
     fn adapter_id(&self) -> String;
-} 
\ No newline at end of file
+
+    /// Estimate how long until a block is finalized
+    ///
+    /// The default implementation returns zero for finalized blocks and
+    /// otherwise derives a rough estimate from the chain's
+    /// [`FinalityType`] and typical [`block_time`](crate::messages::ChainId::block_time):
+    /// confirmations × block time for probabilistic finality, one block time
+    /// per block above the finalized head for deterministic finality, and
+    /// zero for instant finality. Chains without a known block time yield a
+    /// `Configuration` error unless the adapter overrides this.
+    async fn estimate_finality_time(&self, block: &Self::BlockId) -> Result<Duration, AdapterError> {
+        if self.is_finalized(block).await? {
+            return Ok(Duration::ZERO);
+        }
+        let finality_type = self.capabilities().await?.finality_type;
+        if finality_type == FinalityType::Instant {
+            return Ok(Duration::ZERO);
+        }
+        let block_time = self.chain_id().block_time().ok_or_else(|| {
+            AdapterError::Configuration(format!("no block time known for {:?}", self.chain_id()))
+        })?;
+        let blocks = match finality_type {
+            FinalityType::Probabilistic { confirmations } => confirmations,
+            FinalityType::Deterministic => {
+                let pending = block.block_height().saturating_sub(self.finalized_height().await?);
+                u32::try_from(pending).unwrap_or(u32::MAX).max(1)
+            }
+            FinalityType::Instant => 0,
+        };
+        Ok(block_time.saturating_mul(blocks))
+    }
+
+    /// Wait for finality of several blocks, returning them in input order
+    ///
+    /// The default implementation waits for all blocks concurrently and
+    /// fails on the first error. Adapters whose finality is monotonic in
+    /// block height can override this to wait once for the highest block.
+    async fn wait_for_finality_batch(
+        &self,
+        blocks: &[Self::BlockId],
+        timeout: Option<Duration>,
+    ) -> Result<Vec<FinalizedBlock<Self::BlockId>>, AdapterError> {
+        futures::future::try_join_all(blocks.iter().map(|block| self.wait_for_finality(block, timeout))).await
+    }
+
+    /// Get the chain's current fee parameters
+    ///
+    /// The default implementation reports the
+    /// [`estimate_fee`](MessageSubmitter::estimate_fee) of an empty message
+    /// to this chain as a [flat](FeeParameters::flat) base fee. Chains with
+    /// a fee market should override this to report base fee, priority fee
+    /// and gas limit separately.
+    async fn fee_parameters(&self) -> Result<FeeParameters, AdapterError> {
+        let chain_id = self.chain_id();
+        let probe = FrostMessage::new(chain_id, chain_id, Vec::new(), 0, 0);
+        Ok(FeeParameters::flat(self.estimate_fee(&probe).await?))
+    }
+
+    /// Get the relay status of a message, or `None` if the chain does not
+    /// know the message
+    ///
+    /// The default implementation reports the query as unsupported.
+    async fn message_status(&self, id: Uuid) -> Result<Option<MessageStatus>, AdapterError> {
+        Err(AdapterError::Capability(format!(
+            "{} does not support message status queries (message {})",
+            self.adapter_id(),
+            id
+        )))
+    }
+
+    /// Get the relay status of several messages
+    ///
+    /// Every requested ID is a key of the result; IDs the chain does not know
+    /// map to `None`. The default implementation queries
+    /// [`message_status`](Self::message_status) sequentially; chains with a
+    /// multi-query endpoint can override this to use a single request.
+    async fn message_status_batch(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Option<MessageStatus>>, AdapterError> {
+        let mut statuses = HashMap::with_capacity(ids.len());
+        for id in ids {
+            statuses.insert(*id, self.message_status(*id).await?);
+        }
+        Ok(statuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    // Mock finality provider reporting a fixed finalized height
+    struct MockFinality {
+        height: u64,
+    }
+
+    #[async_trait]
+    impl FinalityProvider for MockFinality {
+        type BlockId = u64;
+
+        async fn latest_finalized_block(&self) -> Result<FinalizedBlock<u64>, AdapterError> {
+            Ok(FinalizedBlock {
+                block: self.height,
+                finality_proof: None,
+                finalized_at: SystemTime::now(),
+                confirmations: None,
+            })
+        }
+
+        async fn wait_for_finality(
+            &self,
+            block: &u64,
+            _timeout: Option<Duration>,
+        ) -> Result<FinalizedBlock<u64>, AdapterError> {
+            Ok(FinalizedBlock {
+                block: *block,
+                finality_proof: None,
+                finalized_at: SystemTime::now(),
+                confirmations: None,
+            })
+        }
+
+        async fn is_finalized(&self, block: &u64) -> Result<bool, AdapterError> {
+            Ok(*block <= self.height)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_finalized_height() {
+        let provider = MockFinality { height: 1_234 };
+        assert_eq!(provider.finalized_height().await.unwrap(), 1_234);
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_preserves_order() {
+        let adapter = mock::MockAdapter::new("mock");
+        let messages: Vec<FrostMessage> = (0..3)
+            .map(|nonce| FrostMessage::new(
+                crate::messages::ChainId::Ethereum,
+                crate::messages::ChainId::Solana,
+                vec![nonce as u8],
+                nonce,
+                1_725_000_000,
+            ))
+            .collect();
+
+        let tx_ids = adapter.submit_batch(&messages).await.unwrap();
+        assert_eq!(tx_ids, vec![0, 1, 2]);
+        let nonces: Vec<u64> = adapter.submitted.lock().iter().map(|m| m.nonce).collect();
+        assert_eq!(nonces, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_finality_time() {
+        let probabilistic = mock::MockAdapter {
+            finality_type: FinalityType::Probabilistic { confirmations: 12 },
+            ..mock::MockAdapter::new("pow")
+        };
+        assert_eq!(probabilistic.estimate_finality_time(&150).await.unwrap(), Duration::from_secs(144));
+        assert_eq!(probabilistic.estimate_finality_time(&100).await.unwrap(), Duration::ZERO);
+
+        // Deterministic finality waits for the finalized head to reach the block
+        let deterministic = mock::MockAdapter::new("bft");
+        assert_eq!(deterministic.estimate_finality_time(&103).await.unwrap(), Duration::from_secs(36));
+
+        let instant = mock::MockAdapter {
+            finality_type: FinalityType::Instant,
+            ..mock::MockAdapter::new("l2")
+        };
+        assert_eq!(instant.estimate_finality_time(&150).await.unwrap(), Duration::ZERO);
+
+        let unknown = mock::MockAdapter {
+            chain_id: crate::messages::ChainId::Unknown,
+            ..mock::MockAdapter::new("unknown")
+        };
+        assert!(matches!(
+            unknown.estimate_finality_time(&150).await,
+            Err(AdapterError::Configuration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_finality_batch() {
+        let adapter = mock::MockAdapter::new("mock");
+        let blocks = adapter.wait_for_finality_batch(&[103, 101, 102], None).await.unwrap();
+        let heights: Vec<u64> = blocks.iter().map(|b| b.block).collect();
+        assert_eq!(heights, vec![103, 101, 102]);
+        assert_eq!(adapter.calls(), 3);
+        assert!(adapter.wait_for_finality_batch(&[], None).await.unwrap().is_empty());
+
+        let failing = mock::MockAdapter::failing("down", mock::MockFailure::Retryable);
+        assert!(matches!(
+            failing.wait_for_finality_batch(&[101, 102], None).await,
+            Err(AdapterError::Connection(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_message_status_batch() {
+        let adapter = mock::MockAdapter::new("mock");
+        let (pending, failed, unknown) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        adapter.statuses.lock().extend([
+            (pending, MessageStatus::Pending),
+            (failed, MessageStatus::Failed("reverted".to_string())),
+        ]);
+
+        let statuses = adapter.message_status_batch(&[pending, failed, unknown]).await.unwrap();
+        assert_eq!(statuses.len(), 3);
+        assert_eq!(statuses[&pending], Some(MessageStatus::Pending));
+        assert_eq!(statuses[&failed], Some(MessageStatus::Failed("reverted".to_string())));
+        assert_eq!(statuses[&unknown], None);
+
+        let failing = mock::MockAdapter::failing("down", mock::MockFailure::Retryable);
+        assert!(failing.message_status_batch(&[pending]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fee_parameters() {
+        // The default reports the estimated fee as a flat base fee
+        let adapter = mock::MockAdapter::new("mock");
+        assert_eq!(adapter.fee_parameters().await.unwrap(), FeeParameters::flat(1_000));
+
+        let adapter = mock::MockAdapter {
+            chain_id: crate::messages::ChainId::Solana,
+            fee: 5_000,
+            ..mock::MockAdapter::new("solana")
+        };
+        assert_eq!(adapter.fee_parameters().await.unwrap(), FeeParameters::flat(5_000));
+
+        let params = FeeParameters {
+            base_fee: 30,
+            priority_fee: 2,
+            gas_limit: Some(21_000),
+        };
+        assert_eq!(params.total(), 672_000);
+
+        let failing = mock::MockAdapter::failing("down", mock::MockFailure::Retryable);
+        assert!(failing.fee_parameters().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_receipt() {
+        let adapter = mock::MockAdapter::new("mock");
+        let message = FrostMessage::new(
+            crate::messages::ChainId::Ethereum,
+            crate::messages::ChainId::Solana,
+            b"test".to_vec(),
+            1,
+            1_725_000_000,
+        );
+        let tx_id = adapter.submit_message(&message, None).await.unwrap();
+
+        let receipt = adapter.get_receipt(&tx_id).await.unwrap().unwrap();
+        assert_eq!(receipt.status, crate::types::TransactionStatus::Confirmed);
+        assert_eq!(receipt.data, b"test");
+        assert_eq!(receipt.metadata.get("gas_used").map(String::as_str), Some("21000"));
+        assert!(adapter.get_receipt(&(tx_id + 1)).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,160 @@
+//! Chain-specific payload format validation
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::messages::ChainId;
+use crate::types::AdapterError;
+
+/// Validates that a payload matches a chain's expected format
+pub trait PayloadValidator: Send + Sync {
+    /// Check a payload, returning a `MessageFormat` error if it is malformed
+    fn validate(&self, payload: &[u8]) -> Result<(), AdapterError>;
+}
+
+/// EVM calldata: a 4-byte function selector followed by 32-byte ABI words
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvmPayloadValidator;
+
+impl PayloadValidator for EvmPayloadValidator {
+    fn validate(&self, payload: &[u8]) -> Result<(), AdapterError> {
+        if payload.len() < 4 {
+            return Err(AdapterError::MessageFormat(format!(
+                "EVM calldata must include a 4-byte selector, got {} bytes",
+                payload.len()
+            )));
+        }
+        if !(payload.len() - 4).is_multiple_of(32) {
+            return Err(AdapterError::MessageFormat(format!(
+                "EVM calldata arguments must be a multiple of 32 bytes, got {}",
+                payload.len() - 4
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Substrate calls: SCALE-encoded with a pallet index and call index prefix
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubstratePayloadValidator;
+
+impl PayloadValidator for SubstratePayloadValidator {
+    fn validate(&self, payload: &[u8]) -> Result<(), AdapterError> {
+        if payload.len() < 2 {
+            return Err(AdapterError::MessageFormat(
+                "Substrate call must include pallet and call indices".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Solana instruction data: non-empty and within the transaction packet size
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolanaPayloadValidator;
+
+impl SolanaPayloadValidator {
+    /// Maximum serialized transaction size on Solana
+    pub const MAX_PAYLOAD_SIZE: usize = 1232;
+}
+
+impl PayloadValidator for SolanaPayloadValidator {
+    fn validate(&self, payload: &[u8]) -> Result<(), AdapterError> {
+        if payload.is_empty() {
+            return Err(AdapterError::MessageFormat(
+                "Solana instruction data must not be empty".to_string(),
+            ));
+        }
+        if payload.len() > Self::MAX_PAYLOAD_SIZE {
+            return Err(AdapterError::MessageFormat(format!(
+                "Solana instruction data exceeds {} bytes, got {}",
+                Self::MAX_PAYLOAD_SIZE,
+                payload.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Per-chain payload validators
+///
+/// Chains without a registered validator accept any payload.
+#[derive(Clone, Default)]
+pub struct PayloadValidators {
+    validators: HashMap<ChainId, Arc<dyn PayloadValidator>>,
+}
+
+impl PayloadValidators {
+    /// Create an empty set of validators
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create validators for the built-in chains
+    pub fn with_defaults() -> Self {
+        let mut validators = Self::new();
+        validators.register(ChainId::Ethereum, EvmPayloadValidator);
+        validators.register(ChainId::Polkadot, SubstratePayloadValidator);
+        validators.register(ChainId::Solana, SolanaPayloadValidator);
+        validators
+    }
+
+    /// Register the validator for a chain, replacing any existing one
+    pub fn register(&mut self, chain: ChainId, validator: impl PayloadValidator + 'static) {
+        self.validators.insert(chain, Arc::new(validator));
+    }
+
+    /// Validate a payload against the rules of the given chain
+    pub fn validate(&self, chain: ChainId, payload: &[u8]) -> Result<(), AdapterError> {
+        match self.validators.get(&chain) {
+            Some(validator) => validator.validate(payload),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for PayloadValidators {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PayloadValidators")
+            .field("chains", &self.validators.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evm_payloads() {
+        let validators = PayloadValidators::with_defaults();
+        assert!(validators.validate(ChainId::Ethereum, &[0u8; 4 + 64]).is_ok());
+        assert!(validators.validate(ChainId::Ethereum, &[0u8; 4 + 33]).is_err());
+        assert!(validators.validate(ChainId::Ethereum, &[0u8; 3]).is_err());
+    }
+
+    #[test]
+    fn test_substrate_payloads() {
+        let validators = PayloadValidators::with_defaults();
+        assert!(validators.validate(ChainId::Polkadot, &[5, 0, 1, 2]).is_ok());
+        assert!(matches!(
+            validators.validate(ChainId::Polkadot, &[5]),
+            Err(AdapterError::MessageFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_solana_payloads() {
+        let validators = PayloadValidators::with_defaults();
+        assert!(validators.validate(ChainId::Solana, &[1u8; 100]).is_ok());
+        assert!(validators.validate(ChainId::Solana, &[]).is_err());
+        assert!(validators
+            .validate(ChainId::Solana, &vec![1u8; SolanaPayloadValidator::MAX_PAYLOAD_SIZE + 1])
+            .is_err());
+    }
+
+    #[test]
+    fn test_unregistered_chain_accepts() {
+        assert!(PayloadValidators::with_defaults().validate(ChainId::Unknown, &[]).is_ok());
+    }
+}
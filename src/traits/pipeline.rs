@@ -0,0 +1,243 @@
+//! Ordered pre-submission transforms of outgoing messages
+
+use crate::messages::FrostMessage;
+use crate::types::AdapterError;
+
+#[cfg(feature = "signing")]
+use crate::messages::LocalSigner;
+
+/// Metadata key recording how the payload was encoded by a transform
+pub const PAYLOAD_ENCODING_KEY: &str = "payload_encoding";
+
+/// A single in-place modification of an outgoing message
+pub trait MessageTransform: Send + Sync {
+    /// Apply the transform to the message
+    fn apply(&self, message: &mut FrostMessage) -> Result<(), AdapterError>;
+}
+
+/// Ordered sequence of [`MessageTransform`]s applied before submission
+///
+/// Transforms run in the order they were added. If any transform fails, the
+/// message is restored to its state before the pipeline ran, so a failed
+/// pipeline never leaves a partially transformed message behind.
+#[derive(Default)]
+pub struct MessagePipeline {
+    transforms: Vec<Box<dyn MessageTransform>>,
+}
+
+impl MessagePipeline {
+    /// Create an empty pipeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a transform to the pipeline
+    pub fn with_transform(mut self, transform: impl MessageTransform + 'static) -> Self {
+        self.push(transform);
+        self
+    }
+
+    /// Append a transform to the pipeline
+    pub fn push(&mut self, transform: impl MessageTransform + 'static) {
+        self.transforms.push(Box::new(transform));
+    }
+
+    /// Number of transforms in the pipeline
+    pub fn len(&self) -> usize {
+        self.transforms.len()
+    }
+
+    /// Whether the pipeline has no transforms
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+
+    /// Run every transform in order, rolling the message back on failure
+    pub fn apply(&self, message: &mut FrostMessage) -> Result<(), AdapterError> {
+        let original = message.clone();
+        for transform in &self.transforms {
+            if let Err(e) = transform.apply(message) {
+                *message = original;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for MessagePipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessagePipeline")
+            .field("transforms", &self.transforms.len())
+            .finish()
+    }
+}
+
+/// Set the relayer fee
+#[derive(Debug, Clone, Copy)]
+pub struct SetFee(pub u128);
+
+impl MessageTransform for SetFee {
+    fn apply(&self, message: &mut FrostMessage) -> Result<(), AdapterError> {
+        message.set_fee(self.0);
+        Ok(())
+    }
+}
+
+/// LZ4-compress the payload, recording `lz4` under [`PAYLOAD_ENCODING_KEY`]
+///
+/// Compressing a payload twice is rejected. Since the payload is signed,
+/// this must run before [`SignMessage`].
+#[cfg(feature = "compression")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressPayload;
+
+#[cfg(feature = "compression")]
+impl CompressPayload {
+    /// Encoding name recorded in the message metadata
+    pub const ENCODING: &'static str = "lz4";
+
+    /// Recover the original payload of a message compressed by this transform
+    ///
+    /// The encoding flag lives in unsigned metadata and the size prefix is
+    /// chosen by the sender, so both are untrusted: payloads declaring more
+    /// than `max_decompressed_len` bytes are rejected before anything is
+    /// allocated.
+    pub fn decompress(message: &FrostMessage, max_decompressed_len: usize) -> Result<Vec<u8>, AdapterError> {
+        if !Self::is_compressed(message) {
+            return Ok(message.payload.clone());
+        }
+        let invalid = |reason: String| AdapterError::MessageFormat(format!("invalid compressed payload: {}", reason));
+        let (prefix, block) = message
+            .payload
+            .split_first_chunk::<4>()
+            .ok_or_else(|| invalid("missing size prefix".to_string()))?;
+        let len = u32::from_le_bytes(*prefix) as usize;
+        if len > max_decompressed_len {
+            return Err(invalid(format!(
+                "declared size {} exceeds {} bytes",
+                len, max_decompressed_len
+            )));
+        }
+        let mut payload = vec![0u8; len];
+        let written = lz4_flex::block::decompress_into(block, &mut payload).map_err(|e| invalid(e.to_string()))?;
+        if written != len {
+            return Err(invalid(format!("declared size {} but decoded {} bytes", len, written)));
+        }
+        Ok(payload)
+    }
+
+    fn is_compressed(message: &FrostMessage) -> bool {
+        message
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(PAYLOAD_ENCODING_KEY))
+            .is_some_and(|encoding| encoding == Self::ENCODING)
+    }
+}
+
+#[cfg(feature = "compression")]
+impl MessageTransform for CompressPayload {
+    fn apply(&self, message: &mut FrostMessage) -> Result<(), AdapterError> {
+        if Self::is_compressed(message) {
+            return Err(AdapterError::MessageFormat("payload is already compressed".to_string()));
+        }
+        message.set_payload(lz4_flex::compress_prepend_size(&message.payload));
+        message
+            .metadata
+            .get_or_insert_with(Default::default)
+            .insert(PAYLOAD_ENCODING_KEY.to_string(), Self::ENCODING.to_string());
+        Ok(())
+    }
+}
+
+/// Sign the message with a local ed25519 key
+///
+/// Signing covers the canonical hash, so it should be the last transform
+/// that touches signed fields.
+#[cfg(feature = "signing")]
+pub struct SignMessage(pub LocalSigner);
+
+#[cfg(feature = "signing")]
+impl MessageTransform for SignMessage {
+    fn apply(&self, message: &mut FrostMessage) -> Result<(), AdapterError> {
+        self.0.sign_message(message);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ChainId;
+
+    // Transform that always fails
+    struct Reject;
+
+    impl MessageTransform for Reject {
+        fn apply(&self, _message: &mut FrostMessage) -> Result<(), AdapterError> {
+            Err(AdapterError::MessageFormat("rejected".to_string()))
+        }
+    }
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![0x42; 256], 1, 1_725_000_000)
+    }
+
+    #[test]
+    fn test_pipeline_rolls_back_on_failure() {
+        let pipeline = MessagePipeline::new().with_transform(SetFee(500)).with_transform(Reject);
+        let mut message = message();
+
+        assert!(pipeline.apply(&mut message).is_err());
+        assert_eq!(message.fee, None);
+
+        let pipeline = MessagePipeline::new().with_transform(SetFee(500));
+        pipeline.apply(&mut message).unwrap();
+        assert_eq!(message.fee, Some(500));
+        assert_eq!(pipeline.len(), 1);
+    }
+
+    #[cfg(all(feature = "compression", feature = "signing"))]
+    #[test]
+    fn test_compress_then_sign() {
+        let signer = LocalSigner::from_bytes(&[7u8; 32]);
+        let public_key = signer.public_key();
+        let pipeline = MessagePipeline::new()
+            .with_transform(CompressPayload)
+            .with_transform(SignMessage(signer));
+        let mut message = message();
+
+        pipeline.apply(&mut message).unwrap();
+        assert!(message.payload.len() < 256);
+        assert_eq!(CompressPayload::decompress(&message, 1024).unwrap(), vec![0x42; 256]);
+        assert!(message.verify_signature(&public_key).unwrap());
+        assert!(!message.is_signature_stale());
+
+        // Re-running fails on the second compression and leaves the message intact
+        let before = message.clone();
+        assert!(pipeline.apply(&mut message).is_err());
+        assert_eq!(message.payload, before.payload);
+        assert_eq!(message.signature, before.signature);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_decompress_rejects_oversized_prefix() {
+        let mut message = message();
+        CompressPayload.apply(&mut message).unwrap();
+        assert!(CompressPayload::decompress(&message, 255).is_err());
+        assert_eq!(CompressPayload::decompress(&message, 256).unwrap(), vec![0x42; 256]);
+
+        // A forged prefix declaring ~4 GiB is rejected without allocating it
+        message.set_payload(vec![0xff, 0xff, 0xff, 0xff]);
+        let result = CompressPayload::decompress(&message, 16 * 1024 * 1024);
+        assert!(matches!(result, Err(AdapterError::MessageFormat(ref e)) if e.contains("exceeds")));
+
+        // A prefix larger than the block decodes to is rejected too
+        let mut forged = 1024u32.to_le_bytes().to_vec();
+        forged.extend_from_slice(&lz4_flex::block::compress(&[0x42; 16]));
+        message.set_payload(forged);
+        assert!(CompressPayload::decompress(&message, 4096).is_err());
+    }
+}
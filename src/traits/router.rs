@@ -0,0 +1,213 @@
+//! Selecting destination chains by capability and cost
+
+use std::collections::HashMap;
+
+use crate::messages::ChainId;
+use crate::types::ChainCapabilities;
+
+/// Capabilities a destination chain must offer to carry a message
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapabilityRequirements {
+    /// Require smart contract support
+    pub smart_contracts: bool,
+    /// Require on-chain proof verification
+    pub onchain_verification: bool,
+    /// Size in bytes of the message that must fit
+    pub message_size: usize,
+    /// Proof type the chain must accept
+    pub proof_type: Option<String>,
+    /// Size in bytes of the proof that must fit
+    pub proof_size: Option<usize>,
+    /// Chain-specific features that must be present
+    pub features: Vec<String>,
+}
+
+impl CapabilityRequirements {
+    /// Requirements met by every chain
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require smart contract support
+    pub fn with_smart_contracts(mut self) -> Self {
+        self.smart_contracts = true;
+        self
+    }
+
+    /// Require on-chain proof verification
+    pub fn with_onchain_verification(mut self) -> Self {
+        self.onchain_verification = true;
+        self
+    }
+
+    /// Require room for a message of the given size
+    pub fn with_message_size(mut self, size: usize) -> Self {
+        self.message_size = size;
+        self
+    }
+
+    /// Require support for a proof type
+    pub fn with_proof_type(mut self, proof_type: impl Into<String>) -> Self {
+        self.proof_type = Some(proof_type.into());
+        self
+    }
+
+    /// Require room for a proof of the given size
+    pub fn with_proof_size(mut self, size: usize) -> Self {
+        self.proof_size = Some(size);
+        self
+    }
+
+    /// Require a chain-specific feature
+    pub fn with_feature(mut self, feature: impl Into<String>) -> Self {
+        self.features.push(feature.into());
+        self
+    }
+
+    /// Whether a chain with the given capabilities meets the requirements
+    pub fn is_met_by(&self, capabilities: &ChainCapabilities) -> bool {
+        (!self.smart_contracts || capabilities.supports_smart_contracts)
+            && (!self.onchain_verification || capabilities.supports_onchain_verification)
+            && self.message_size <= capabilities.max_message_size
+            && self
+                .proof_type
+                .as_ref()
+                .is_none_or(|proof_type| capabilities.proof_types.contains(proof_type))
+            && self
+                .proof_size
+                .zip(capabilities.max_proof_size)
+                .is_none_or(|(size, max)| size <= max)
+            && self
+                .features
+                .iter()
+                .all(|feature| capabilities.features.contains_key(feature))
+    }
+}
+
+/// Routes messages to chains whose capabilities meet a set of requirements
+#[derive(Debug, Clone, Default)]
+pub struct CapabilityRouter {
+    capabilities: HashMap<ChainId, ChainCapabilities>,
+}
+
+impl CapabilityRouter {
+    /// Create a router with no known chains
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the capabilities of a chain
+    pub fn with_chain(mut self, chain_id: ChainId, capabilities: ChainCapabilities) -> Self {
+        self.register(chain_id, capabilities);
+        self
+    }
+
+    /// Register or replace the capabilities of a chain
+    pub fn register(&mut self, chain_id: ChainId, capabilities: ChainCapabilities) {
+        self.capabilities.insert(chain_id, capabilities);
+    }
+
+    /// Get the registered capabilities of a chain
+    pub fn capabilities(&self, chain_id: ChainId) -> Option<&ChainCapabilities> {
+        self.capabilities.get(&chain_id)
+    }
+
+    /// Whether a registered chain meets the requirements
+    pub fn is_capable(&self, chain_id: ChainId, requirements: &CapabilityRequirements) -> bool {
+        self.capabilities(chain_id)
+            .is_some_and(|capabilities| requirements.is_met_by(capabilities))
+    }
+
+    /// All registered chains meeting the requirements, in chain order
+    pub fn capable_chains(&self, requirements: &CapabilityRequirements) -> Vec<ChainId> {
+        let mut chains: Vec<ChainId> = self
+            .capabilities
+            .iter()
+            .filter(|(_, capabilities)| requirements.is_met_by(capabilities))
+            .map(|(chain_id, _)| *chain_id)
+            .collect();
+        chains.sort();
+        chains
+    }
+
+    /// Pick the cheapest candidate chain that meets the requirements
+    ///
+    /// Candidates are `(chain, estimated fee)` pairs. Unregistered chains are
+    /// never chosen. Equal fees are broken by chain order so the choice is
+    /// deterministic.
+    pub fn cheapest_route(
+        &self,
+        candidates: &[(ChainId, u128)],
+        requirements: &CapabilityRequirements,
+    ) -> Option<ChainId> {
+        candidates
+            .iter()
+            .filter(|(chain_id, _)| self.is_capable(*chain_id, requirements))
+            .min_by_key(|(chain_id, fee)| (*fee, *chain_id))
+            .map(|(chain_id, _)| *chain_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FinalityType;
+
+    fn capabilities(onchain_verification: bool, max_message_size: usize) -> ChainCapabilities {
+        ChainCapabilities {
+            supports_smart_contracts: true,
+            supports_native_tokens: true,
+            supports_onchain_verification: onchain_verification,
+            max_message_size,
+            proof_types: vec!["groth16".to_string()],
+            finality_type: FinalityType::Deterministic,
+            max_proof_size: Some(1024),
+            supports_parallel_execution: false,
+            features: HashMap::new(),
+        }
+    }
+
+    fn router() -> CapabilityRouter {
+        CapabilityRouter::new()
+            .with_chain(ChainId::Ethereum, capabilities(true, 4096))
+            .with_chain(ChainId::Polkadot, capabilities(true, 1024))
+            .with_chain(ChainId::Solana, capabilities(false, 1232))
+    }
+
+    #[test]
+    fn test_cheapest_capable_route() {
+        let router = router();
+        let candidates = [
+            (ChainId::Ethereum, 50_000),
+            (ChainId::Polkadot, 2_000),
+            (ChainId::Solana, 500),
+        ];
+
+        // Solana is cheapest but cannot verify on-chain
+        let requirements = CapabilityRequirements::new().with_onchain_verification();
+        assert_eq!(router.cheapest_route(&candidates, &requirements), Some(ChainId::Polkadot));
+
+        // Polkadot cannot carry a 2 KiB message
+        let requirements = requirements.with_message_size(2048);
+        assert_eq!(router.cheapest_route(&candidates, &requirements), Some(ChainId::Ethereum));
+
+        assert_eq!(router.cheapest_route(&candidates, &CapabilityRequirements::new()), Some(ChainId::Solana));
+
+        let requirements = CapabilityRequirements::new().with_proof_type("plonk");
+        assert_eq!(router.cheapest_route(&candidates, &requirements), None);
+        assert!(router.capable_chains(&requirements).is_empty());
+    }
+
+    #[test]
+    fn test_cheapest_route_ties_and_unknown_chains() {
+        let router = router();
+        let requirements = CapabilityRequirements::new().with_onchain_verification();
+
+        let candidates = [(ChainId::Polkadot, 100), (ChainId::Ethereum, 100), (ChainId::Unknown, 1)];
+        assert_eq!(router.cheapest_route(&candidates, &requirements), Some(ChainId::Ethereum));
+        assert_eq!(
+            router.capable_chains(&requirements),
+            vec![ChainId::Ethereum, ChainId::Polkadot]
+        );
+    }
+}
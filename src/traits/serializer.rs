@@ -0,0 +1,155 @@
+//! Chain-specific encoding of messages for submission
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::messages::{ChainId, FrostMessage};
+
+/// Encodes a message into the payload a chain's contracts expect
+///
+/// Adapters call this from `submit_message` to build the submitted payload.
+/// The built-in serializers encode the same fields, in the same layout, as
+/// the preimage of [`FrostMessage::onchain_digest`], so contracts can hash
+/// the submitted bytes directly.
+pub trait MessageSerializer: Send + Sync {
+    /// Encode a message for submission
+    fn serialize(&self, message: &FrostMessage) -> Vec<u8>;
+}
+
+/// Solidity `abi.encode(uint256 from_chain, uint256 to_chain, uint256 nonce,
+/// uint256 timestamp, bytes payload)`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvmAbiSerializer;
+
+impl MessageSerializer for EvmAbiSerializer {
+    fn serialize(&self, message: &FrostMessage) -> Vec<u8> {
+        message.abi_encoding()
+    }
+}
+
+/// SCALE encoding of `(u64 from_chain, u64 to_chain, Vec<u8> payload,
+/// u64 nonce, u64 timestamp)`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScaleSerializer;
+
+impl MessageSerializer for ScaleSerializer {
+    fn serialize(&self, message: &FrostMessage) -> Vec<u8> {
+        message.scale_encoding()
+    }
+}
+
+/// Borsh encoding of `(u64 from_chain, u64 to_chain, Vec<u8> payload,
+/// u64 nonce, u64 timestamp)`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BorshSerializer;
+
+impl MessageSerializer for BorshSerializer {
+    fn serialize(&self, message: &FrostMessage) -> Vec<u8> {
+        message.borsh_encoding()
+    }
+}
+
+/// Per-chain message serializers
+#[derive(Clone, Default)]
+pub struct MessageSerializers {
+    serializers: HashMap<ChainId, Arc<dyn MessageSerializer>>,
+}
+
+impl MessageSerializers {
+    /// Create an empty set of serializers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create serializers for the built-in chains
+    pub fn with_defaults() -> Self {
+        let mut serializers = Self::new();
+        serializers.register(ChainId::Ethereum, EvmAbiSerializer);
+        serializers.register(ChainId::Polkadot, ScaleSerializer);
+        serializers.register(ChainId::Solana, BorshSerializer);
+        serializers
+    }
+
+    /// Register the serializer for a chain, replacing any existing one
+    pub fn register(&mut self, chain: ChainId, serializer: impl MessageSerializer + 'static) {
+        self.serializers.insert(chain, Arc::new(serializer));
+    }
+
+    /// Get the serializer for a chain
+    pub fn get(&self, chain: ChainId) -> Option<&dyn MessageSerializer> {
+        self.serializers.get(&chain).map(|serializer| serializer.as_ref())
+    }
+
+    /// Encode a message for submission to the given chain
+    pub fn serialize(&self, chain: ChainId, message: &FrostMessage) -> Option<Vec<u8>> {
+        self.get(chain).map(|serializer| serializer.serialize(message))
+    }
+}
+
+impl std::fmt::Debug for MessageSerializers {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("MessageSerializers")
+            .field("chains", &self.serializers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> FrostMessage {
+        FrostMessage::new(ChainId::Ethereum, ChainId::Polkadot, b"hello".to_vec(), 7, 1_725_000_000)
+    }
+
+    fn word(value: u64) -> Vec<u8> {
+        let mut word = vec![0u8; 24];
+        word.extend_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn test_evm_abi_layout() {
+        let mut padded_payload = b"hello".to_vec();
+        padded_payload.resize(32, 0);
+        let expected = [word(0), word(1), word(7), word(1_725_000_000), word(160), word(5), padded_payload].concat();
+        assert_eq!(EvmAbiSerializer.serialize(&message()), expected);
+    }
+
+    #[test]
+    fn test_scale_layout() {
+        let expected = [
+            &0u64.to_le_bytes()[..],
+            &1u64.to_le_bytes(),
+            &[5 << 2],
+            b"hello",
+            &7u64.to_le_bytes(),
+            &1_725_000_000u64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(ScaleSerializer.serialize(&message()), expected);
+    }
+
+    #[test]
+    fn test_borsh_layout() {
+        let expected = [
+            &0u64.to_le_bytes()[..],
+            &1u64.to_le_bytes(),
+            &5u32.to_le_bytes(),
+            b"hello",
+            &7u64.to_le_bytes(),
+            &1_725_000_000u64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(BorshSerializer.serialize(&message()), expected);
+    }
+
+    #[test]
+    fn test_serializer_selection() {
+        let serializers = MessageSerializers::with_defaults();
+        let msg = message();
+        assert_eq!(serializers.serialize(ChainId::Solana, &msg), Some(BorshSerializer.serialize(&msg)));
+        assert_eq!(serializers.serialize(ChainId::Polkadot, &msg), Some(ScaleSerializer.serialize(&msg)));
+        assert!(serializers.serialize(ChainId::Unknown, &msg).is_none());
+    }
+}
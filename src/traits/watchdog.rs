@@ -0,0 +1,230 @@
+//! Background health monitoring of adapters
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use parking_lot::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+
+use super::CapabilityProvider;
+use crate::types::{ConnectionStatus, HealthMetrics};
+
+/// Periodically probes adapters and keeps their health up to date.
+///
+/// Each watched adapter gets a background task calling
+/// [`CapabilityProvider::connection_status`] every `interval`. A probe fails
+/// if it errors or reports [`ConnectionStatus::Unhealthy`]; the resulting
+/// status follows [`HealthMetrics::derive_status`], except that a successful
+/// probe reporting itself degraded stays degraded. Other components read the
+/// current health with [`status`](Self::status) and
+/// [`metrics`](Self::metrics) instead of probing adapters themselves.
+///
+/// Background tasks are aborted when the watchdog is dropped.
+pub struct HealthWatchdog {
+    interval: Duration,
+    failure_threshold: u32,
+    health: Arc<RwLock<HashMap<String, HealthMetrics>>>,
+    tasks: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl HealthWatchdog {
+    /// Create a watchdog probing every `interval`, marking adapters
+    /// unhealthy after `failure_threshold` consecutive failures
+    pub fn new(interval: Duration, failure_threshold: u32) -> Self {
+        Self {
+            interval,
+            failure_threshold,
+            health: Arc::new(RwLock::new(HashMap::new())),
+            tasks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Start monitoring an adapter under the given ID
+    ///
+    /// Watching an ID again stops probing the previous adapter and resets
+    /// the ID's metrics. Must be called within a Tokio runtime.
+    pub fn watch(&self, id: impl Into<String>, adapter: Arc<dyn CapabilityProvider>) {
+        let id = id.into();
+        let mut tasks = self.tasks.lock();
+        if let Some(previous) = tasks.remove(&id) {
+            previous.abort();
+        }
+        self.health.write().insert(id.clone(), initial_metrics());
+
+        let health = self.health.clone();
+        let interval = self.interval;
+        let failure_threshold = self.failure_threshold;
+        let task_id = id.clone();
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let started = Instant::now();
+                let probe = adapter.connection_status().await;
+                let elapsed = started.elapsed();
+                if let Some(metrics) = health.write().get_mut(&task_id) {
+                    record_probe(metrics, probe.ok(), elapsed, failure_threshold);
+                }
+            }
+        });
+        tasks.insert(id, task);
+    }
+
+    /// Get the current connection status of an adapter
+    pub fn status(&self, id: &str) -> Option<ConnectionStatus> {
+        self.health.read().get(id).map(|metrics| metrics.connection_status.clone())
+    }
+
+    /// Get the current health metrics of an adapter
+    pub fn metrics(&self, id: &str) -> Option<HealthMetrics> {
+        self.health.read().get(id).cloned()
+    }
+
+    /// Stop all background probes
+    pub fn stop(&self) {
+        for (_, task) in self.tasks.lock().drain() {
+            task.abort();
+        }
+    }
+}
+
+impl Drop for HealthWatchdog {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn initial_metrics() -> HealthMetrics {
+    HealthMetrics {
+        last_successful: None,
+        consecutive_failures: 0,
+        total_operations: 0,
+        failed_operations: 0,
+        avg_response_time: Duration::ZERO,
+        connection_status: ConnectionStatus::Unknown,
+        latest_block: None,
+        custom_metrics: HashMap::new(),
+    }
+}
+
+/// Fold one probe result into an adapter's metrics
+fn record_probe(
+    metrics: &mut HealthMetrics,
+    reported: Option<ConnectionStatus>,
+    elapsed: Duration,
+    failure_threshold: u32,
+) {
+    let total = metrics.total_operations as f64;
+    metrics.avg_response_time = Duration::from_secs_f64(
+        (metrics.avg_response_time.as_secs_f64() * total + elapsed.as_secs_f64()) / (total + 1.0),
+    );
+    metrics.total_operations += 1;
+
+    match reported {
+        Some(ConnectionStatus::Unhealthy(_)) | None => {
+            metrics.failed_operations += 1;
+            metrics.consecutive_failures += 1;
+            metrics.connection_status = metrics.derive_status(failure_threshold);
+        }
+        Some(status) => {
+            metrics.consecutive_failures = 0;
+            metrics.last_successful = Some(SystemTime::now());
+            metrics.connection_status = match status {
+                ConnectionStatus::Degraded(reason) => ConnectionStatus::Degraded(reason),
+                _ => metrics.derive_status(failure_threshold),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::traits::mock::{MockAdapter, MockFailure};
+    use crate::types::{AdapterError, ChainCapabilities};
+
+    // Adapter whose health can be toggled during the test
+    struct ToggleAdapter {
+        healthy: AtomicBool,
+        inner: MockAdapter,
+        broken: MockAdapter,
+    }
+
+    #[async_trait]
+    impl CapabilityProvider for ToggleAdapter {
+        async fn capabilities(&self) -> Result<ChainCapabilities, AdapterError> {
+            self.inner.capabilities().await
+        }
+
+        async fn supports_capability(&self, capability: &str) -> Result<bool, AdapterError> {
+            self.inner.supports_capability(capability).await
+        }
+
+        async fn connection_status(&self) -> Result<ConnectionStatus, AdapterError> {
+            if self.healthy.load(Ordering::SeqCst) {
+                self.inner.connection_status().await
+            } else {
+                self.broken.connection_status().await
+            }
+        }
+
+        async fn health_metrics(&self) -> Result<HealthMetrics, AdapterError> {
+            self.inner.health_metrics().await
+        }
+    }
+
+    async fn wait_for(watchdog: &HealthWatchdog, id: &str, check: impl Fn(&ConnectionStatus) -> bool) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !watchdog.status(id).is_some_and(|status| check(&status)) {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("status was not updated");
+    }
+
+    #[tokio::test]
+    async fn test_watchdog_tracks_health() {
+        let adapter = Arc::new(ToggleAdapter {
+            healthy: AtomicBool::new(true),
+            inner: MockAdapter::new("primary"),
+            broken: MockAdapter::failing("primary", MockFailure::Retryable),
+        });
+        let watchdog = HealthWatchdog::new(Duration::from_millis(10), 3);
+        assert_eq!(watchdog.status("primary"), None);
+        watchdog.watch("primary", adapter.clone());
+
+        wait_for(&watchdog, "primary", |s| *s == ConnectionStatus::Healthy).await;
+
+        adapter.healthy.store(false, Ordering::SeqCst);
+        wait_for(&watchdog, "primary", |s| matches!(s, ConnectionStatus::Unhealthy(_))).await;
+        let metrics = watchdog.metrics("primary").unwrap();
+        assert!(metrics.consecutive_failures >= 3);
+        assert!(metrics.failed_operations >= 3);
+
+        adapter.healthy.store(true, Ordering::SeqCst);
+        wait_for(&watchdog, "primary", |s| *s == ConnectionStatus::Healthy).await;
+        assert_eq!(watchdog.metrics("primary").unwrap().consecutive_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_watch_again_replaces_probe() {
+        let watchdog = HealthWatchdog::new(Duration::from_millis(10), 3);
+        watchdog.watch("primary", Arc::new(MockAdapter::new("primary")));
+        wait_for(&watchdog, "primary", |s| *s == ConnectionStatus::Healthy).await;
+
+        watchdog.watch("primary", Arc::new(MockAdapter::failing("primary", MockFailure::Retryable)));
+        wait_for(&watchdog, "primary", |s| matches!(s, ConnectionStatus::Unhealthy(_))).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // The healthy adapter is no longer probed
+        let metrics = watchdog.metrics("primary").unwrap();
+        assert!(matches!(metrics.connection_status, ConnectionStatus::Unhealthy(_)));
+        assert_eq!(metrics.failed_operations, metrics.total_operations);
+        assert_eq!(watchdog.tasks.lock().len(), 1);
+    }
+}
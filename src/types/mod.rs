@@ -61,6 +61,37 @@ pub struct ProofData {
     pub verification_key: Option<Vec<u8>>,
 }
 
+/// Structured fee parameters of a chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeParameters {
+    /// Base fee per unit of gas (or the flat fee for chains without gas)
+    pub base_fee: u128,
+    /// Priority fee (tip) per unit of gas
+    pub priority_fee: u128,
+    /// Gas limit the fee applies to, if the chain meters gas
+    pub gas_limit: Option<u64>,
+}
+
+impl FeeParameters {
+    /// Parameters for a chain only reporting a flat total fee
+    pub fn flat(fee: u128) -> Self {
+        Self {
+            base_fee: fee,
+            priority_fee: 0,
+            gas_limit: None,
+        }
+    }
+
+    /// Total fee for the gas limit, or the flat fee if no limit is set
+    pub fn total(&self) -> u128 {
+        let per_unit = self.base_fee.saturating_add(self.priority_fee);
+        match self.gas_limit {
+            Some(limit) => per_unit.saturating_mul(u128::from(limit)),
+            None => per_unit,
+        }
+    }
+}
+
 /// Chain capabilities and features
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChainCapabilities {
@@ -98,7 +129,28 @@ pub enum FinalityType {
     Instant,
 }
 
+impl FinalityType {
+    /// Confidence in `[0.0, 1.0]` that a block with the given confirmation
+    /// depth is final
+    ///
+    /// For probabilistic finality this is the fraction of the required
+    /// confirmations reached, capped at 1.0. Deterministic and instant
+    /// finality always yield 1.0.
+    pub fn confidence(&self, confirmations: u32) -> f64 {
+        match self {
+            FinalityType::Probabilistic { confirmations: required } if *required > 0 => {
+                (f64::from(confirmations) / f64::from(*required)).min(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
 /// Chain adapter error categories
+///
+/// This is the single error type returned by every adapter trait in
+/// [`crate::traits`], so errors propagate between adapters with `?` and no
+/// conversions are needed.
 #[derive(Debug, thiserror::Error)]
 pub enum AdapterError {
     #[error("Finality error: {0}")]
@@ -135,6 +187,22 @@ pub enum AdapterError {
     Other(#[from] anyhow::Error),
 }
 
+impl AdapterError {
+    /// Whether the error is transient, so the operation may succeed if retried
+    /// later or against another endpoint for the same chain
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            AdapterError::Connection(_)
+                | AdapterError::RateLimit(_)
+                | AdapterError::Finality(FinalityError::Timeout(_) | FinalityError::Network(_))
+                | AdapterError::Transaction(SubmissionError::Network(_))
+                | AdapterError::FeeEstimation(FeeEstimationError::Network(_))
+                | AdapterError::Event(EventError::Network(_))
+        )
+    }
+}
+
 /// Finality-related errors
 #[derive(Debug, thiserror::Error)]
 pub enum FinalityError {
@@ -221,6 +289,22 @@ pub enum EventError {
     Other(#[from] anyhow::Error),
 }
 
+/// Message signing errors
+#[derive(Debug, thiserror::Error)]
+pub enum SignError {
+    #[error("Signing failed: {0}")]
+    Failed(String),
+
+    #[error("Signer unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
 /// Chain health metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthMetrics {
@@ -242,6 +326,22 @@ pub struct HealthMetrics {
     pub custom_metrics: HashMap<String, String>,
 }
 
+impl HealthMetrics {
+    /// Derive the connection status from consecutive failures
+    ///
+    /// No failures is healthy, fewer than `failure_threshold` consecutive
+    /// failures is degraded, and reaching the threshold is unhealthy.
+    pub fn derive_status(&self, failure_threshold: u32) -> ConnectionStatus {
+        match self.consecutive_failures {
+            0 => ConnectionStatus::Healthy,
+            n if n < failure_threshold => {
+                ConnectionStatus::Degraded(format!("{} consecutive failures", n))
+            }
+            n => ConnectionStatus::Unhealthy(format!("{} consecutive failures", n)),
+        }
+    }
+}
+
 /// Connection health status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ConnectionStatus {
@@ -282,4 +382,28 @@ impl Default for SubmissionOptions {
             custom_options: HashMap::new(),
         }
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_status() {
+        let mut metrics = HealthMetrics {
+            last_successful: None,
+            consecutive_failures: 0,
+            total_operations: 0,
+            failed_operations: 0,
+            avg_response_time: Duration::ZERO,
+            connection_status: ConnectionStatus::Unknown,
+            latest_block: None,
+            custom_metrics: HashMap::new(),
+        };
+        assert_eq!(metrics.derive_status(3), ConnectionStatus::Healthy);
+        metrics.consecutive_failures = 2;
+        assert!(matches!(metrics.derive_status(3), ConnectionStatus::Degraded(_)));
+        metrics.consecutive_failures = 3;
+        assert!(matches!(metrics.derive_status(3), ConnectionStatus::Unhealthy(_)));
+    }
+}
\ No newline at end of file
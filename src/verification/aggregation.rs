@@ -0,0 +1,191 @@
+//! Caching of proof aggregation results
+//!
+//! Aggregating a set of proofs is expensive, and relayers frequently re-submit
+//! the same set (e.g. on retries). The [`AggregationCache`] stores the
+//! aggregated proof together with its verification outcome, keyed by the set
+//! of proofs it was built from.
+
+use std::num::NonZeroUsize;
+use std::time::{Duration, SystemTime};
+
+use blake2::{Blake2b512, Digest};
+use lru::LruCache;
+use parking_lot::RwLock;
+
+use super::VerificationResult;
+use crate::messages::Proof;
+
+/// Result of aggregating a set of proofs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregatedProof {
+    /// The aggregated proof bytes
+    pub data: Vec<u8>,
+    /// Whether the aggregated proof verified successfully
+    pub verified: bool,
+}
+
+/// Cache entry for an aggregation result
+#[derive(Debug)]
+struct AggregationCacheEntry {
+    result: AggregatedProof,
+    inserted_at: SystemTime,
+}
+
+/// Cache of aggregation results keyed by the set of aggregated proofs.
+///
+/// The key is derived from the *set* of proof hashes: each proof's data is
+/// hashed, the hashes are sorted and deduplicated, and the result is hashed
+/// again. Submitting the same proofs in a different order (or with
+/// duplicates) therefore hits the same entry.
+pub struct AggregationCache {
+    entries: RwLock<LruCache<[u8; 32], AggregationCacheEntry>>,
+    ttl: Duration,
+}
+
+impl AggregationCache {
+    /// Create a new aggregation cache
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: RwLock::new(LruCache::new(NonZeroUsize::new(capacity).unwrap())),
+            ttl,
+        }
+    }
+
+    /// Compute the cache key for a set of proofs
+    pub fn key_for(proofs: &[Proof]) -> [u8; 32] {
+        let mut hashes: Vec<[u8; 32]> = proofs.iter().map(|p| hash_bytes(&p.data)).collect();
+        hashes.sort_unstable();
+        hashes.dedup();
+
+        let mut hasher = Blake2b512::new();
+        for hash in &hashes {
+            hasher.update(hash);
+        }
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&hasher.finalize()[..32]);
+        key
+    }
+
+    /// Look up a cached aggregation result for a set of proofs
+    pub fn get(&self, proofs: &[Proof]) -> Option<AggregatedProof> {
+        let key = Self::key_for(proofs);
+        let mut entries = self.entries.write();
+        let expired = match entries.get(&key) {
+            Some(entry) => {
+                match SystemTime::now().duration_since(entry.inserted_at) {
+                    Ok(age) if age < self.ttl => return Some(entry.result.clone()),
+                    _ => true,
+                }
+            }
+            None => false,
+        };
+        if expired {
+            entries.pop(&key);
+        }
+        None
+    }
+
+    /// Store an aggregation result for a set of proofs
+    pub fn insert(&self, proofs: &[Proof], result: AggregatedProof) {
+        self.entries.write().put(Self::key_for(proofs), AggregationCacheEntry {
+            result,
+            inserted_at: SystemTime::now(),
+        });
+    }
+
+    /// Return the cached result for a set of proofs, aggregating on a miss
+    pub fn get_or_aggregate<F>(&self, proofs: &[Proof], aggregate: F) -> VerificationResult<AggregatedProof>
+    where
+        F: FnOnce(&[Proof]) -> VerificationResult<AggregatedProof>,
+    {
+        if let Some(result) = self.get(proofs) {
+            return Ok(result);
+        }
+        let result = aggregate(proofs)?;
+        self.insert(proofs, result.clone());
+        Ok(result)
+    }
+
+    /// Number of cached aggregation results
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.read().is_empty()
+    }
+
+    /// Clear all cached aggregation results
+    pub fn clear(&self) {
+        self.entries.write().clear();
+    }
+}
+
+fn hash_bytes(bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(bytes);
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&hasher.finalize()[..32]);
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frostgate_zkip::types::ProofMetadata;
+
+    fn proof(data: &[u8]) -> Proof {
+        Proof {
+            data: data.to_vec(),
+            metadata: ProofMetadata {
+                generation_time: Duration::from_secs(1),
+                proof_size: data.len(),
+                program_hash: "dummy".to_string(),
+                timestamp: SystemTime::now(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_identical_set_hits() {
+        let cache = AggregationCache::new(10, Duration::from_secs(60));
+        let mut calls = 0;
+
+        let set = vec![proof(&[1, 2]), proof(&[3, 4])];
+        let first = cache.get_or_aggregate(&set, |_| {
+            calls += 1;
+            Ok(AggregatedProof { data: vec![9], verified: true })
+        }).unwrap();
+
+        // Same set in a different order hits the cache
+        let reordered = vec![proof(&[3, 4]), proof(&[1, 2])];
+        let second = cache.get_or_aggregate(&reordered, |_| {
+            calls += 1;
+            Ok(AggregatedProof { data: vec![0], verified: false })
+        }).unwrap();
+
+        assert_eq!(calls, 1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_differing_set_misses() {
+        let cache = AggregationCache::new(10, Duration::from_secs(60));
+        cache.insert(&[proof(&[1, 2]), proof(&[3, 4])], AggregatedProof { data: vec![9], verified: true });
+
+        assert!(cache.get(&[proof(&[1, 2]), proof(&[5, 6])]).is_none());
+        assert!(cache.get(&[proof(&[1, 2])]).is_none());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_expired_entry_misses() {
+        let cache = AggregationCache::new(10, Duration::ZERO);
+        let set = vec![proof(&[1, 2])];
+        cache.insert(&set, AggregatedProof { data: vec![9], verified: true });
+
+        assert!(cache.get(&set).is_none());
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+//! Per-chain allowlists of message senders for permissioned corridors
+
+use std::collections::{HashMap, HashSet};
+
+use super::{VerificationError, VerificationResult};
+use crate::messages::{ChainId, FrostMessage};
+
+/// Senders allowed to originate messages, per source chain
+///
+/// Chains without an entry are unrestricted. For a restricted chain a
+/// message is accepted if its declared [`sender`](FrostMessage::sender) is
+/// listed, or, with the `signing` feature, if it is signed by one of the
+/// chain's listed ed25519 keys. The declared sender lives in unsigned
+/// metadata, so it should only be listed for senders whose metadata is
+/// trusted; signer keys hold up against forged metadata.
+#[derive(Debug, Clone, Default)]
+pub struct SenderAllowlist {
+    senders: HashMap<ChainId, HashSet<String>>,
+    #[cfg(feature = "signing")]
+    signers: HashMap<ChainId, Vec<[u8; 32]>>,
+}
+
+impl SenderAllowlist {
+    /// Create an allowlist restricting no chains
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow a declared sender on a chain, restricting the chain
+    pub fn allow_sender(mut self, chain_id: ChainId, sender: impl Into<String>) -> Self {
+        self.senders.entry(chain_id).or_default().insert(sender.into());
+        self
+    }
+
+    /// Allow messages signed by an ed25519 key on a chain, restricting the chain
+    #[cfg(feature = "signing")]
+    pub fn allow_signer(mut self, chain_id: ChainId, public_key: [u8; 32]) -> Self {
+        self.signers.entry(chain_id).or_default().push(public_key);
+        self
+    }
+
+    /// Whether messages from the chain are restricted
+    pub fn is_restricted(&self, chain_id: ChainId) -> bool {
+        #[cfg(feature = "signing")]
+        if self.signers.contains_key(&chain_id) {
+            return true;
+        }
+        self.senders.contains_key(&chain_id)
+    }
+
+    /// Check that the message's sender may originate messages on its source chain
+    pub fn check(&self, message: &FrostMessage) -> VerificationResult<()> {
+        let chain_id = message.from_chain;
+        if !self.is_restricted(chain_id) {
+            return Ok(());
+        }
+        let sender = message.sender();
+        let listed = sender.is_some_and(|sender| {
+            self.senders.get(&chain_id).is_some_and(|senders| senders.contains(sender))
+        });
+        #[cfg(feature = "signing")]
+        let listed = listed
+            || self
+                .signers
+                .get(&chain_id)
+                .is_some_and(|keys| message.verify_signature_multi(keys).is_some());
+        if listed {
+            return Ok(());
+        }
+        Err(VerificationError::UnauthorizedSender {
+            chain_id,
+            sender: sender.unwrap_or("<undeclared>").to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::SENDER_KEY;
+
+    fn message_from(chain_id: ChainId, sender: Option<&str>) -> FrostMessage {
+        let mut message = FrostMessage::new(chain_id, ChainId::Polkadot, b"test".to_vec(), 1, 1_725_000_000);
+        if let Some(sender) = sender {
+            message.metadata.get_or_insert_with(HashMap::new).insert(SENDER_KEY.to_string(), sender.to_string());
+        }
+        message
+    }
+
+    #[test]
+    fn test_declared_sender_allowlist() {
+        let allowlist = SenderAllowlist::new().allow_sender(ChainId::Ethereum, "0xbridge");
+
+        assert!(allowlist.check(&message_from(ChainId::Ethereum, Some("0xbridge"))).is_ok());
+        assert!(matches!(
+            allowlist.check(&message_from(ChainId::Ethereum, Some("0xmallory"))),
+            Err(VerificationError::UnauthorizedSender { chain_id: ChainId::Ethereum, ref sender }) if sender == "0xmallory"
+        ));
+        assert!(allowlist.check(&message_from(ChainId::Ethereum, None)).is_err());
+
+        // Unrestricted chains accept any sender
+        assert!(allowlist.check(&message_from(ChainId::Solana, Some("0xmallory"))).is_ok());
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_signer_allowlist() {
+        use crate::messages::LocalSigner;
+
+        let operator = LocalSigner::from_bytes(&[1u8; 32]);
+        let intruder = LocalSigner::from_bytes(&[2u8; 32]);
+        let allowlist = SenderAllowlist::new().allow_signer(ChainId::Ethereum, operator.public_key());
+
+        let mut message = message_from(ChainId::Ethereum, None);
+        operator.sign_message(&mut message);
+        assert!(allowlist.check(&message).is_ok());
+
+        intruder.sign_message(&mut message);
+        assert!(allowlist.check(&message).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! Synchronous verification for callers without an async runtime
+
+use frostgate_zkip::ZkBackend;
+
+use super::{MessageVerifier, VerificationResult};
+use crate::messages::FrostMessage;
+
+impl<B: ZkBackend> MessageVerifier<B> {
+    /// Verify a message, blocking the current thread until done
+    ///
+    /// Drives [`verify_message`](Self::verify_message) to completion with
+    /// [`futures::executor::block_on`], for synchronous callers such as FFI
+    /// bindings or CLI tools. The verifier holds no synchronous lock across
+    /// an await point and serializes program loads with runtime-agnostic
+    /// async locks, so blocking callers on several threads can share a
+    /// verifier, including with async callers on other threads.
+    ///
+    /// Calling this from within an async runtime is undefined: it blocks the
+    /// executor thread, which may deadlock if the verification waits on
+    /// work scheduled on that same thread. Backends must also not depend on
+    /// a runtime being present (e.g. tokio timers).
+    pub fn verify_message_blocking(&self, message: &FrostMessage) -> VerificationResult<bool> {
+        futures::executor::block_on(self.verify_message(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    use frostgate_zkip::types::ProofMetadata;
+
+    use super::*;
+    use crate::messages::{ChainId, Proof};
+    use crate::verification::VerifierConfig;
+
+    /// Backend accepting proofs that start with an odd byte
+    struct ParityBackend;
+
+    test_backend!(ParityBackend, |self, proof| Ok(proof[0] % 2 == 1));
+
+    fn message(proof: u8) -> FrostMessage {
+        let mut message = FrostMessage::new(ChainId::Ethereum, ChainId::Solana, vec![proof], 1, 1_725_000_000);
+        message.proof = Some(Proof {
+            data: vec![proof],
+            metadata: ProofMetadata {
+                generation_time: Duration::from_secs(1),
+                proof_size: 1,
+                program_hash: "dummy".to_string(),
+                timestamp: SystemTime::now(),
+            },
+        });
+        message
+    }
+
+    #[test]
+    fn test_blocking_verification_across_threads() {
+        let verifier = Arc::new(MessageVerifier::from_config(Arc::new(ParityBackend), VerifierConfig {
+            allow_dummy_programs: true,
+            ..Default::default()
+        }));
+
+        let handles: Vec<_> = (0..8u8)
+            .map(|i| {
+                let verifier = verifier.clone();
+                std::thread::spawn(move || verifier.verify_message_blocking(&message(i)).unwrap())
+            })
+            .collect();
+        let results: Vec<bool> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+        assert_eq!(results, (0..8u8).map(|i| i % 2 == 1).collect::<Vec<_>>());
+        // Concurrent cold loads of the same program were single-flighted
+        assert_eq!(verifier.metrics().total_loads(), 1);
+    }
+}
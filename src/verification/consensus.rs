@@ -0,0 +1,143 @@
+//! M-of-N verification across independent backends
+//!
+//! A proof accepted by a single backend is only as trustworthy as that
+//! backend. Verifying against several independent implementations and
+//! requiring a quorum to accept means one compromised or buggy backend can
+//! neither forge an acceptance nor, below the quorum, block one, while its
+//! disagreement with the others is surfaced.
+
+use std::sync::Arc;
+
+use frostgate_zkip::{types::ZkConfig, ZkBackend};
+
+/// Vote of each backend and the combined result of a consensus verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsensusOutcome {
+    /// Whether at least the threshold of backends accepted the proof
+    pub verified: bool,
+    /// Each backend's result, in backend order; `None` if the backend failed
+    pub votes: Vec<Option<bool>>,
+}
+
+impl ConsensusOutcome {
+    /// Number of backends that accepted the proof
+    pub fn approvals(&self) -> usize {
+        self.votes.iter().filter(|vote| **vote == Some(true)).count()
+    }
+
+    /// Indices of the backends whose vote differs from the outcome,
+    /// including backends that failed
+    pub fn dissenting(&self) -> Vec<usize> {
+        self.votes
+            .iter()
+            .enumerate()
+            .filter(|(_, vote)| **vote != Some(self.verified))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Whether every backend agreed with the outcome
+    pub fn is_unanimous(&self) -> bool {
+        self.votes.iter().all(|vote| *vote == Some(self.verified))
+    }
+}
+
+/// Verifier requiring `threshold` of several backends to accept a proof
+pub struct ConsensusVerifier {
+    backends: Vec<Arc<dyn ZkBackend>>,
+    threshold: usize,
+}
+
+impl ConsensusVerifier {
+    /// Create a verifier requiring `threshold` of `backends` to agree
+    ///
+    /// A threshold of zero is treated as one. A threshold above the number
+    /// of backends can never be met.
+    pub fn new(backends: Vec<Arc<dyn ZkBackend>>, threshold: usize) -> Self {
+        Self {
+            backends,
+            threshold: threshold.max(1),
+        }
+    }
+
+    /// Get the number of approvals required
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Get the number of backends
+    pub fn backend_count(&self) -> usize {
+        self.backends.len()
+    }
+
+    /// Verify a proof against every backend concurrently
+    ///
+    /// Backend errors count as votes against the proof.
+    pub async fn verify(&self, program: &[u8], proof: &[u8], config: Option<&ZkConfig>) -> ConsensusOutcome {
+        let votes: Vec<Option<bool>> = futures::future::join_all(
+            self.backends.iter().map(|backend| backend.verify(program, proof, config)),
+        )
+        .await
+        .into_iter()
+        .map(Result::ok)
+        .collect();
+        let approvals = votes.iter().filter(|vote| **vote == Some(true)).count();
+        ConsensusOutcome {
+            verified: approvals >= self.threshold,
+            votes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frostgate_zkip::ZkError;
+
+    /// Backend returning a fixed verdict, or failing if `None`
+    struct FixedBackend(Option<bool>);
+
+    test_backend!(FixedBackend, |self, _proof| self.0.ok_or_else(|| ZkError::Backend("unavailable".to_string())));
+
+    fn verifier(votes: &[Option<bool>], threshold: usize) -> ConsensusVerifier {
+        let backends = votes
+            .iter()
+            .map(|vote| Arc::new(FixedBackend(*vote)) as Arc<dyn ZkBackend>)
+            .collect();
+        ConsensusVerifier::new(backends, threshold)
+    }
+
+    #[tokio::test]
+    async fn test_quorum_reached_despite_dissent() {
+        let outcome = verifier(&[Some(true), Some(false), Some(true)], 2)
+            .verify(b"program", b"proof", None)
+            .await;
+        assert!(outcome.verified);
+        assert_eq!(outcome.approvals(), 2);
+        assert_eq!(outcome.dissenting(), vec![1]);
+        assert!(!outcome.is_unanimous());
+    }
+
+    #[tokio::test]
+    async fn test_quorum_missed() {
+        // A failing backend counts against the proof
+        let outcome = verifier(&[Some(true), None, Some(false)], 2)
+            .verify(b"program", b"proof", None)
+            .await;
+        assert!(!outcome.verified);
+        assert_eq!(outcome.votes, vec![Some(true), None, Some(false)]);
+        assert_eq!(outcome.dissenting(), vec![0, 1]);
+
+        let outcome = verifier(&[Some(true), Some(true)], 3)
+            .verify(b"program", b"proof", None)
+            .await;
+        assert!(!outcome.verified);
+    }
+
+    #[tokio::test]
+    async fn test_unanimous() {
+        let outcome = verifier(&[Some(true); 3], 3).verify(b"program", b"proof", None).await;
+        assert!(outcome.verified && outcome.is_unanimous());
+        assert!(outcome.dissenting().is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+//! Capture of failing verifications for debugging
+//!
+//! With [`VerifierConfig::capture_failures`](super::VerifierConfig::capture_failures)
+//! enabled, every proof the backend rejects through one of the
+//! [`MessageVerifier`](super::MessageVerifier) message entry points is
+//! recorded as a [`Counterexample`] holding the exact backend inputs, so the
+//! failure can be reproduced offline. Counterexamples never appear in verification results.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{VerificationError, VerificationResult};
+use crate::messages::ChainId;
+
+/// Full context of a proof the backend rejected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Counterexample {
+    /// ID of the verified message
+    pub message_id: Uuid,
+    /// Source chain of the message
+    pub chain_id: ChainId,
+    /// Hash of the verification program
+    pub program_hash: [u8; 32],
+    /// Proof bytes passed to the backend
+    pub proof: Vec<u8>,
+    /// Public input constructed for the message
+    pub input: Vec<u8>,
+    /// When the failure was captured
+    pub captured_at: SystemTime,
+}
+
+impl Counterexample {
+    /// Write the counterexample as JSON to
+    /// `<dir>/<message_id>-<captured_at>.json`, with the capture time in
+    /// nanoseconds since the Unix epoch, so repeated failures of the same
+    /// message are kept apart
+    pub fn save(&self, dir: impl AsRef<Path>) -> VerificationResult<PathBuf> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)
+            .map_err(|e| VerificationError::System(format!("Failed to create counterexample dir: {}", e)))?;
+        let captured_at = self.captured_at.duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_nanos());
+        let path = dir.join(format!("{}-{}.json", self.message_id, captured_at));
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|e| VerificationError::System(format!("Failed to encode counterexample: {}", e)))?;
+        fs::write(&path, json)
+            .map_err(|e| VerificationError::System(format!("Failed to write counterexample: {}", e)))?;
+        Ok(path)
+    }
+
+    /// Load a counterexample written by [`save`](Self::save)
+    pub fn load(path: impl AsRef<Path>) -> VerificationResult<Self> {
+        let json = fs::read(path)
+            .map_err(|e| VerificationError::System(format!("Failed to read counterexample: {}", e)))?;
+        serde_json::from_slice(&json)
+            .map_err(|e| VerificationError::InvalidFormat(format!("Invalid counterexample: {}", e)))
+    }
+}
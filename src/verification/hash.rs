@@ -0,0 +1,63 @@
+//! Hash algorithms used for program hashes and cache keys
+
+use base64::Engine;
+use blake2::{Blake2b512, Digest};
+use sha2::Sha256;
+
+/// Hash algorithm producing 32-byte digests
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HashAlgorithm {
+    /// Blake2b-512 truncated to 32 bytes
+    #[default]
+    Blake2b,
+    /// SHA-256
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// Length of a digest in bytes
+    pub fn digest_len(&self) -> usize {
+        32
+    }
+
+    /// Decode a textual digest, as carried in proof metadata
+    ///
+    /// Accepts hex, with or without a `0x` prefix, or standard base64.
+    /// Returns `None` if the string is neither or decodes to the wrong length.
+    pub fn parse_digest(&self, encoded: &str) -> Option<[u8; 32]> {
+        let hex_digits = encoded.strip_prefix("0x").unwrap_or(encoded);
+        let bytes = if hex_digits.len() == 2 * self.digest_len() {
+            hex::decode(hex_digits).ok()?
+        } else {
+            base64::engine::general_purpose::STANDARD.decode(encoded).ok()?
+        };
+        bytes.try_into().ok()
+    }
+
+    /// Hash a single byte string
+    pub fn digest(&self, bytes: &[u8]) -> [u8; 32] {
+        self.digest_parts(&[bytes])
+    }
+
+    /// Hash the concatenation of several byte strings
+    pub fn digest_parts(&self, parts: &[&[u8]]) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        match self {
+            HashAlgorithm::Blake2b => {
+                let mut hasher = Blake2b512::new();
+                for part in parts {
+                    hasher.update(part);
+                }
+                out.copy_from_slice(&hasher.finalize()[..32]);
+            }
+            HashAlgorithm::Sha256 => {
+                let mut hasher = Sha256::new();
+                for part in parts {
+                    hasher.update(part);
+                }
+                out.copy_from_slice(&hasher.finalize());
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,139 @@
+//! Public input encodings derived from message fields
+
+use crate::messages::{FrostMessage, INPUT_ENCODING_VERSION_KEY};
+use super::{VerificationError, VerificationResult};
+
+/// Byte order of the integer fields in a public input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endianness {
+    /// Most significant byte first
+    #[default]
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+impl Endianness {
+    fn u64_bytes(&self, value: u64) -> Vec<u8> {
+        match self {
+            Endianness::Big => value.to_be_bytes().to_vec(),
+            Endianness::Little => value.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+/// Layout version of the public input constructed for a message
+///
+/// Circuits fix the input layout they were compiled against, so proofs
+/// generated for an older circuit must be verified with the matching
+/// encoder. The version is read from the message metadata under
+/// [`INPUT_ENCODING_VERSION_KEY`]; messages without it use
+/// [`InputEncoding::CURRENT`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputEncoding {
+    /// `from_chain`, `to_chain`, payload length, `payload`, `nonce`,
+    /// `timestamp`, with integers as `u64`
+    V1,
+    /// The message `id` (16 bytes) followed by the V1 layout, binding the
+    /// proof to a single message
+    V2,
+}
+
+impl InputEncoding {
+    /// Encoding used when a message does not specify one
+    pub const CURRENT: InputEncoding = InputEncoding::V1;
+
+    /// Numeric version of this encoding
+    pub fn version(&self) -> u32 {
+        match self {
+            InputEncoding::V1 => 1,
+            InputEncoding::V2 => 2,
+        }
+    }
+
+    /// Look up an encoding by numeric version
+    pub fn from_version(version: u32) -> Option<Self> {
+        match version {
+            1 => Some(InputEncoding::V1),
+            2 => Some(InputEncoding::V2),
+            _ => None,
+        }
+    }
+
+    /// Select the encoding declared by a message
+    pub fn for_message(message: &FrostMessage) -> VerificationResult<Self> {
+        let declared = message.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(INPUT_ENCODING_VERSION_KEY));
+        match declared {
+            None => Ok(Self::CURRENT),
+            Some(version) => version
+                .parse()
+                .ok()
+                .and_then(Self::from_version)
+                .ok_or_else(|| VerificationError::InvalidFormat(format!(
+                    "Unsupported input encoding version: {}",
+                    version
+                ))),
+        }
+    }
+
+    /// Labeled segments of the input for a message, in encoding order,
+    /// with big-endian integers
+    pub fn segments(&self, message: &FrostMessage) -> Vec<(&'static str, Vec<u8>)> {
+        self.segments_with(message, Endianness::Big)
+    }
+
+    /// Labeled segments of the input for a message with the given integer
+    /// byte order
+    pub fn segments_with(&self, message: &FrostMessage, endianness: Endianness) -> Vec<(&'static str, Vec<u8>)> {
+        let mut segments = Vec::with_capacity(7);
+        if *self == InputEncoding::V2 {
+            segments.push(("id", message.id.as_bytes().to_vec()));
+        }
+        segments.extend([
+            ("from_chain", endianness.u64_bytes(message.from_chain.to_u64())),
+            ("to_chain", endianness.u64_bytes(message.to_chain.to_u64())),
+            ("payload_len", endianness.u64_bytes(message.payload.len() as u64)),
+            ("payload", message.payload.clone()),
+            ("nonce", endianness.u64_bytes(message.nonce)),
+            ("timestamp", endianness.u64_bytes(message.timestamp)),
+        ]);
+        segments
+    }
+
+    /// Encode the input for a message with big-endian integers
+    pub fn encode(&self, message: &FrostMessage) -> Vec<u8> {
+        self.encode_with(message, Endianness::Big)
+    }
+
+    /// Encode the input for a message with the given integer byte order
+    pub fn encode_with(&self, message: &FrostMessage, endianness: Endianness) -> Vec<u8> {
+        self.segments_with(message, endianness)
+            .into_iter()
+            .flat_map(|(_, bytes)| bytes)
+            .collect()
+    }
+}
+
+/// Check a message's payload can be encoded into a public input
+///
+/// The payload length must be representable in the `u64` `payload_len`
+/// field and must not exceed `max_len` bytes, so an encoder can never emit a
+/// length that disagrees with the payload bytes that follow it.
+pub fn check_payload_len(message: &FrostMessage, max_len: usize) -> VerificationResult<()> {
+    let len = message.payload.len();
+    if u64::try_from(len).is_err() || len > max_len {
+        return Err(VerificationError::InvalidFormat(format!(
+            "payload of {} bytes exceeds the {} byte input limit",
+            len, max_len
+        )));
+    }
+    Ok(())
+}
+
+impl Default for InputEncoding {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
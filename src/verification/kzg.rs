@@ -0,0 +1,167 @@
+//! KZG commitment openings for blob-carrying messages
+//!
+//! EIP-4844-style messages carry their payload as a blob together with a
+//! KZG commitment and an opening proof, attached under
+//! [`KZG_COMMITMENT_ATTACHMENT`] and [`KZG_PROOF_ATTACHMENT`]. With a
+//! [`KzgVerifier`] configured, the opening is checked against the payload
+//! before the ZK proof is verified. Messages without a commitment are not
+//! affected.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use c_kzg::{Blob, Bytes48, KzgSettings};
+
+use super::{ParamsLoader, VerificationError, VerificationResult};
+use crate::messages::{ChainId, FrostMessage};
+
+/// Attachment holding the 48-byte KZG commitment to the payload blob
+pub const KZG_COMMITMENT_ATTACHMENT: &str = "kzg_commitment";
+
+/// Attachment holding the 48-byte KZG opening proof for the commitment
+pub const KZG_PROOF_ATTACHMENT: &str = "kzg_proof";
+
+/// Size in bytes of a blob payload
+pub const BYTES_PER_BLOB: usize = c_kzg::BYTES_PER_BLOB;
+
+/// KZG opening failures
+#[derive(Debug, thiserror::Error)]
+pub enum KzgError {
+    #[error("Missing {0} attachment")]
+    MissingAttachment(&'static str),
+
+    #[error("Malformed KZG input: {0}")]
+    Malformed(String),
+
+    #[error("KZG opening does not match the payload blob")]
+    InvalidOpening,
+
+    #[error("Invalid trusted setup: {0}")]
+    TrustedSetup(String),
+}
+
+/// Checks KZG commitment openings against payload blobs
+#[derive(Clone)]
+pub struct KzgVerifier {
+    settings: Arc<KzgSettings>,
+}
+
+impl KzgVerifier {
+    /// Use the Ethereum mainnet trusted setup
+    pub fn ethereum() -> Self {
+        Self {
+            settings: c_kzg::ethereum_kzg_settings_arc(0),
+        }
+    }
+
+    /// Parse a trusted setup in the c-kzg text format
+    pub fn from_trusted_setup(setup: &str) -> Result<Self, KzgError> {
+        KzgSettings::parse_kzg_trusted_setup(setup, 0)
+            .map(|settings| Self { settings: Arc::new(settings) })
+            .map_err(|e| KzgError::TrustedSetup(format!("{:?}", e)))
+    }
+
+    /// Load a trusted setup file in the c-kzg text format
+    pub fn from_trusted_setup_file(path: impl AsRef<Path>) -> Result<Self, KzgError> {
+        KzgSettings::load_trusted_setup_file(path.as_ref(), 0)
+            .map(|settings| Self { settings: Arc::new(settings) })
+            .map_err(|e| KzgError::TrustedSetup(format!("{:?}", e)))
+    }
+
+    /// Load the trusted setup for a chain through a [`ParamsLoader`]
+    pub async fn load(loader: &dyn ParamsLoader, chain_id: ChainId) -> VerificationResult<Self> {
+        let setup = loader.load_params(chain_id).await?;
+        let setup = std::str::from_utf8(&setup)
+            .map_err(|e| KzgError::TrustedSetup(format!("not UTF-8: {}", e)))?;
+        Ok(Self::from_trusted_setup(setup)?)
+    }
+
+    /// Check that `proof` opens `commitment` to `blob`
+    pub fn verify_opening(&self, blob: &[u8], commitment: &[u8], proof: &[u8]) -> Result<(), KzgError> {
+        let blob = Blob::from_bytes(blob).map_err(|e| KzgError::Malformed(format!("blob: {:?}", e)))?;
+        let commitment = Bytes48::from_bytes(commitment)
+            .map_err(|e| KzgError::Malformed(format!("commitment: {:?}", e)))?;
+        let proof = Bytes48::from_bytes(proof).map_err(|e| KzgError::Malformed(format!("proof: {:?}", e)))?;
+        match self.settings.verify_blob_kzg_proof(&blob, &commitment, &proof) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(KzgError::InvalidOpening),
+            Err(e) => Err(KzgError::Malformed(format!("{:?}", e))),
+        }
+    }
+
+    /// Check the opening attached to a message against its payload
+    ///
+    /// Messages without a [`KZG_COMMITMENT_ATTACHMENT`] pass unchecked.
+    pub fn verify_message(&self, message: &FrostMessage) -> Result<(), KzgError> {
+        let Some(commitment) = message.attachment(KZG_COMMITMENT_ATTACHMENT) else {
+            return Ok(());
+        };
+        let proof = message
+            .attachment(KZG_PROOF_ATTACHMENT)
+            .ok_or(KzgError::MissingAttachment(KZG_PROOF_ATTACHMENT))?;
+        self.verify_opening(&message.payload, commitment, proof)
+    }
+}
+
+impl std::fmt::Debug for KzgVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KzgVerifier").finish_non_exhaustive()
+    }
+}
+
+impl From<KzgError> for VerificationError {
+    fn from(error: KzgError) -> Self {
+        VerificationError::Kzg(error)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A valid blob: every 32-byte field element starts with a zero byte
+    pub(crate) fn blob(seed: u8) -> Vec<u8> {
+        let mut blob = vec![0u8; BYTES_PER_BLOB];
+        for (i, element) in blob.chunks_mut(32).enumerate() {
+            element[1..].fill(seed.wrapping_add(i as u8));
+        }
+        blob
+    }
+
+    /// Commitment and opening proof for a blob
+    pub(crate) fn open(blob: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let settings = c_kzg::ethereum_kzg_settings(0);
+        let commitment = settings.blob_to_kzg_commitment(&Blob::from_bytes(blob).unwrap()).unwrap().to_bytes();
+        let proof = settings
+            .compute_blob_kzg_proof(&Blob::from_bytes(blob).unwrap(), &commitment)
+            .unwrap()
+            .to_bytes();
+        (commitment.into_inner().to_vec(), proof.into_inner().to_vec())
+    }
+
+    #[test]
+    fn test_valid_opening() {
+        let blob = blob(1);
+        let (commitment, proof) = open(&blob);
+        assert!(KzgVerifier::ethereum().verify_opening(&blob, &commitment, &proof).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_opening() {
+        let verifier = KzgVerifier::ethereum();
+        let (commitment, proof) = open(&blob(1));
+
+        assert!(matches!(
+            verifier.verify_opening(&blob(2), &commitment, &proof),
+            Err(KzgError::InvalidOpening)
+        ));
+        assert!(matches!(
+            verifier.verify_opening(&blob(1)[..64], &commitment, &proof),
+            Err(KzgError::Malformed(_))
+        ));
+        assert!(matches!(
+            KzgVerifier::from_trusted_setup("not a setup"),
+            Err(KzgError::TrustedSetup(_))
+        ));
+    }
+}
@@ -0,0 +1,192 @@
+//! Merkle commitments to sets of allowed verification programs
+//!
+//! A set of program hashes is committed as the root of a binary Merkle tree.
+//! Leaf nodes hash `0x00` followed by a program hash; inner nodes hash
+//! `0x01` followed by the left and right children, so a program whose bytes
+//! encode two children can never be passed off as an inner node. Proofs
+//! must also be exactly as deep as the committed tree. An odd node at the
+//! end of a level is paired with itself.
+
+use serde::{Deserialize, Serialize};
+
+use super::HashAlgorithm;
+
+/// Domain separator for leaf nodes
+const LEAF_PREFIX: &[u8] = &[0x00];
+
+/// Domain separator for inner nodes
+const NODE_PREFIX: &[u8] = &[0x01];
+
+/// Inclusion proof of a leaf in a program Merkle tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// Position of the leaf in the tree
+    pub leaf_index: u64,
+    /// Sibling hashes from the leaf level up to just below the root
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MerkleProof {
+    /// Compute the root implied by this proof for a leaf program hash
+    pub fn compute_root(&self, leaf: [u8; 32], algorithm: HashAlgorithm) -> [u8; 32] {
+        let mut index = self.leaf_index;
+        self.siblings.iter().fold(hash_leaf(algorithm, &leaf), |node, sibling| {
+            let parent = if index & 1 == 0 {
+                hash_node(algorithm, &node, sibling)
+            } else {
+                hash_node(algorithm, sibling, &node)
+            };
+            index >>= 1;
+            parent
+        })
+    }
+
+    /// Whether the proof places the leaf program hash under the given root
+    /// of a tree of the given [`height`](MerkleTree::height)
+    pub fn verify(&self, leaf: [u8; 32], root: &[u8; 32], height: usize, algorithm: HashAlgorithm) -> bool {
+        self.siblings.len() == height
+            && self.siblings.len() < 64
+            && self.leaf_index >> self.siblings.len() == 0
+            && self.compute_root(leaf, algorithm) == *root
+    }
+}
+
+/// Binary Merkle tree over program hashes
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    algorithm: HashAlgorithm,
+    /// Node hashes per level, leaves first; the last level holds the root
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build a tree over the given leaf program hashes
+    ///
+    /// An empty tree has an all-zero root that no proof can match.
+    pub fn new(leaves: Vec<[u8; 32]>, algorithm: HashAlgorithm) -> Self {
+        let mut levels = vec![leaves.iter().map(|leaf| hash_leaf(algorithm, leaf)).collect::<Vec<_>>()];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let level = levels.last().expect("non-empty levels");
+            let parents = level
+                .chunks(2)
+                .map(|pair| hash_node(algorithm, &pair[0], pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+            levels.push(parents);
+        }
+        Self { algorithm, levels }
+    }
+
+    /// Build a tree over the hashes of the given programs
+    pub fn from_programs<P: AsRef<[u8]>>(programs: &[P], algorithm: HashAlgorithm) -> Self {
+        Self::new(programs.iter().map(|program| algorithm.digest(program.as_ref())).collect(), algorithm)
+    }
+
+    /// Root hash committing to every leaf
+    pub fn root(&self) -> [u8; 32] {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .unwrap_or([0u8; 32])
+    }
+
+    /// Number of levels above the leaves, i.e. the length of every proof
+    pub fn height(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Inclusion proof for the leaf at `index`
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        self.levels.first()?.get(index)?;
+        let siblings = self.levels[..self.levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(depth, level)| {
+                let position = index >> depth;
+                // The last node of an odd level is its own sibling
+                level.get(position ^ 1).copied().unwrap_or(level[position])
+            })
+            .collect();
+        Some(MerkleProof {
+            leaf_index: index as u64,
+            siblings,
+        })
+    }
+
+    /// Algorithm the tree was built with
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+}
+
+fn hash_leaf(algorithm: HashAlgorithm, leaf: &[u8; 32]) -> [u8; 32] {
+    algorithm.digest_parts(&[LEAF_PREFIX, leaf])
+}
+
+fn hash_node(algorithm: HashAlgorithm, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    algorithm.digest_parts(&[NODE_PREFIX, left, right])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proofs_for_every_leaf() {
+        for algorithm in [HashAlgorithm::Blake2b, HashAlgorithm::Sha256] {
+            let programs: Vec<Vec<u8>> = (0..5u8).map(|i| vec![i; 16]).collect();
+            let tree = MerkleTree::from_programs(&programs, algorithm);
+
+            for (index, program) in programs.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert!(proof.verify(algorithm.digest(program), &tree.root(), tree.height(), algorithm));
+            }
+            assert!(tree.proof(5).is_none());
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_other_leaf_or_index() {
+        let algorithm = HashAlgorithm::default();
+        let tree = MerkleTree::from_programs(&[b"a", b"b", b"c"], algorithm);
+        let mut proof = tree.proof(1).unwrap();
+
+        assert!(!proof.verify(algorithm.digest(b"x"), &tree.root(), tree.height(), algorithm));
+        proof.leaf_index = 0;
+        assert!(!proof.verify(algorithm.digest(b"b"), &tree.root(), tree.height(), algorithm));
+        proof.leaf_index = 5;
+        assert!(!proof.verify(algorithm.digest(b"b"), &tree.root(), tree.height(), algorithm));
+
+        let single = MerkleTree::from_programs(&[b"only"], algorithm);
+        assert_eq!(single.root(), hash_leaf(algorithm, &algorithm.digest(b"only")));
+        assert_eq!(single.height(), 0);
+        assert!(single.proof(0).unwrap().siblings.is_empty());
+    }
+
+    #[test]
+    fn test_inner_node_preimage_rejected() {
+        let algorithm = HashAlgorithm::default();
+        let programs: Vec<Vec<u8>> = (0..4u8).map(|i| vec![i; 16]).collect();
+        let tree = MerkleTree::from_programs(&programs, algorithm);
+
+        // A "program" made of an inner node's preimage, proven with the
+        // upper half of a real proof, must not verify
+        let level = &tree.levels[0];
+        let forged_program = [NODE_PREFIX, &level[0], &level[1]].concat();
+        let real = tree.proof(0).unwrap();
+        let forged = MerkleProof {
+            leaf_index: 0,
+            siblings: real.siblings[1..].to_vec(),
+        };
+        let forged_leaf = algorithm.digest(&forged_program);
+        assert!(!forged.verify(forged_leaf, &tree.root(), forged.siblings.len(), algorithm));
+        assert!(!forged.verify(forged_leaf, &tree.root(), tree.height(), algorithm));
+
+        // Padding the forged proof to the tree height does not help either
+        let padded = MerkleProof {
+            leaf_index: 0,
+            siblings: real.siblings.clone(),
+        };
+        assert!(!padded.verify(forged_leaf, &tree.root(), tree.height(), algorithm));
+    }
+}
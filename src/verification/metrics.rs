@@ -0,0 +1,77 @@
+//! Verifier metrics
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::messages::ChainId;
+
+/// Program loading statistics for a single chain
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProgramLoadMetrics {
+    /// Number of programs loaded (cache misses that read the program)
+    pub loads: u64,
+    /// Total time spent loading and hashing programs
+    pub total_load_time: Duration,
+    /// Number of requests served from cache
+    pub cache_hits: u64,
+    /// Number of requests that required a load
+    pub cache_misses: u64,
+    /// Number of loads that fell back to dummy program bytes
+    pub dummy_fallbacks: u64,
+}
+
+impl ProgramLoadMetrics {
+    /// Average time per program load
+    pub fn avg_load_time(&self) -> Duration {
+        match u32::try_from(self.loads) {
+            Ok(0) => Duration::ZERO,
+            Ok(loads) => self.total_load_time / loads,
+            Err(_) => Duration::ZERO,
+        }
+    }
+
+    /// Fraction of program requests served from cache
+    pub fn cache_hit_ratio(&self) -> f64 {
+        let total = self.cache_hits + self.cache_misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.cache_hits as f64 / total as f64
+        }
+    }
+}
+
+/// Metrics collected by a message verifier
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VerifierMetrics {
+    /// Program loading statistics per chain
+    pub program_loads: HashMap<ChainId, ProgramLoadMetrics>,
+}
+
+impl VerifierMetrics {
+    /// Total number of program loads across chains
+    pub fn total_loads(&self) -> u64 {
+        self.program_loads.values().map(|m| m.loads).sum()
+    }
+
+    /// Total number of dummy-program fallbacks across chains
+    ///
+    /// A non-zero value usually means program paths are misconfigured.
+    pub fn total_dummy_fallbacks(&self) -> u64 {
+        self.program_loads.values().map(|m| m.dummy_fallbacks).sum()
+    }
+
+    pub(crate) fn record_cache_hit(&mut self, chain_id: ChainId) {
+        self.program_loads.entry(chain_id).or_default().cache_hits += 1;
+    }
+
+    pub(crate) fn record_load(&mut self, chain_id: ChainId, elapsed: Duration, dummy: bool) {
+        let metrics = self.program_loads.entry(chain_id).or_default();
+        metrics.cache_misses += 1;
+        metrics.loads += 1;
+        metrics.total_load_time += elapsed;
+        if dummy {
+            metrics.dummy_fallbacks += 1;
+        }
+    }
+}
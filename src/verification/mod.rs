@@ -3,22 +3,87 @@
 //! Message verification module for the Frostgate SDK
 //! This module provides functionality for verifying cross-chain messages using the new ZkBackend interface.
 
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use async_trait::async_trait;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use lru::LruCache;
 use blake2::{Blake2b512, Digest};
 use std::num::NonZeroUsize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::fs;
-use std::time::{Duration, SystemTime};
+use std::future::Future;
+use std::io::BufRead;
+use std::time::{Duration, Instant, SystemTime};
 
-use crate::messages::{FrostMessage, ChainId, Proof};
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::messages::{
+    FrostMessage, ChainId, Proof, SerializedProof, PROOF_BLOCK_HASH_KEY, PROOF_BLOCK_HEIGHT_KEY,
+    PROTOCOL_VERSION,
+};
+use crate::traits::PayloadValidators;
+use crate::types::{ChainCapabilities, FinalityType};
 use frostgate_zkip::{
     ZkBackend, ZkBackendExt, ZkError, ZkResult,
     types::{HealthStatus, ProofMetadata, ResourceUsage, ZkConfig},
 };
 
+#[cfg(test)]
+#[macro_use]
+mod testing;
+
+mod aggregation;
+mod allowlist;
+#[cfg(feature = "blocking")]
+mod blocking;
+mod consensus;
+mod counterexample;
+mod hash;
+mod input;
+#[cfg(feature = "kzg")]
+mod kzg;
+mod merkle;
+mod metrics;
+mod params;
+mod quota;
+mod registry;
+mod route;
+mod scheduler;
+mod session;
+mod slow;
+mod store;
+mod trace;
+#[cfg(feature = "testing")]
+mod vectors;
+
+pub use aggregation::{AggregatedProof, AggregationCache};
+pub use allowlist::SenderAllowlist;
+pub use consensus::{ConsensusOutcome, ConsensusVerifier};
+pub use route::{RequiredArtifacts, RouteRequirements};
+pub use counterexample::Counterexample;
+pub use hash::HashAlgorithm;
+pub use input::{check_payload_len, Endianness, InputEncoding};
+#[cfg(feature = "kzg")]
+pub use kzg::{KzgError, KzgVerifier, BYTES_PER_BLOB, KZG_COMMITMENT_ATTACHMENT, KZG_PROOF_ATTACHMENT};
+pub use merkle::{MerkleProof, MerkleTree};
+pub use metrics::{ProgramLoadMetrics, VerifierMetrics};
+pub use params::{FileParamsLoader, ParamsLoader};
+pub use quota::{Clock, QuotaGuard, SystemClock};
+pub use registry::ProgramRegistry;
+pub use scheduler::{FairPermit, FairScheduler};
+pub use session::{
+    RecordedVerification, ReplayMismatch, ReplayReport, SessionRecorder, SessionReplayer,
+};
+pub use slow::{SlowVerification, SlowVerificationCallback};
+pub use store::ProgramStore;
+pub use trace::{TraceStep, VerificationTrace};
+#[cfg(feature = "testing")]
+pub use vectors::{TestVector, TestVectorBuilder};
+
 /// Error types for message verification
 #[derive(Debug, thiserror::Error)]
 pub enum VerificationError {
@@ -32,6 +97,169 @@ pub enum VerificationError {
     InvalidChainId,
     #[error("System error: {0}")]
     System(String),
+    #[error("Verification quota exceeded for client {client_id}, retry after {retry_after:?}")]
+    QuotaExceeded {
+        client_id: String,
+        retry_after: Duration,
+    },
+    #[error("Sender {sender} is not allowed to send from {chain_id:?}")]
+    UnauthorizedSender {
+        chain_id: ChainId,
+        sender: String,
+    },
+    #[error("Proof system mismatch: expected {expected}, found {found}")]
+    ProofSystemMismatch {
+        expected: String,
+        found: String,
+    },
+    #[error("No verification program found for {0:?}")]
+    ProgramNotFound(ChainId),
+    #[error("Malformed program hash in proof metadata: {0:?}")]
+    MalformedProgramHash(String),
+    #[error("Proof was generated for program {found}, expected {expected}")]
+    ProgramHashMismatch {
+        expected: String,
+        found: String,
+    },
+    #[error("Proof commits to block {found}, expected {expected}")]
+    BlockHashMismatch {
+        expected: String,
+        found: String,
+    },
+    #[error("Program {0} is not in the committed program set")]
+    UncommittedProgram(String),
+    #[error("Proof attests to block {proof_height}, more than {max_lag} blocks behind finalized block {current_height}")]
+    StaleProof {
+        proof_height: u64,
+        current_height: u64,
+        max_lag: u64,
+    },
+    #[error("Failed to preload programs: {0:?}")]
+    WarmUpFailed(Vec<(ChainId, VerificationError)>),
+    #[cfg(feature = "kzg")]
+    #[error("KZG error: {0}")]
+    Kzg(KzgError),
+}
+
+/// Placeholder substituted for redacted error detail
+const REDACTED: &str = "<redacted>";
+
+impl VerificationError {
+    /// Return a copy of this error with sensitive detail stripped.
+    ///
+    /// The variant (and therefore the error category in the `Display` output)
+    /// is preserved, but any free-form detail such as filesystem paths or
+    /// backend messages is replaced. Backend timeouts and resource
+    /// exhaustion keep their variant so [`is_retryable`](Self::is_retryable)
+    /// is unchanged. Use this before returning errors to untrusted clients.
+    pub fn redacted(&self) -> Self {
+        match self {
+            VerificationError::Backend(error) => VerificationError::Backend(match error {
+                ZkError::Timeout(duration) => ZkError::Timeout(*duration),
+                ZkError::ResourceExhausted(_) => ZkError::ResourceExhausted(REDACTED.to_string()),
+                _ => ZkError::Backend(REDACTED.to_string()),
+            }),
+            VerificationError::InvalidFormat(_) => VerificationError::InvalidFormat(REDACTED.to_string()),
+            VerificationError::MissingProof => VerificationError::MissingProof,
+            VerificationError::InvalidChainId => VerificationError::InvalidChainId,
+            VerificationError::System(_) => VerificationError::System(REDACTED.to_string()),
+            VerificationError::QuotaExceeded { retry_after, .. } => VerificationError::QuotaExceeded {
+                client_id: REDACTED.to_string(),
+                retry_after: *retry_after,
+            },
+            VerificationError::UnauthorizedSender { chain_id, .. } => VerificationError::UnauthorizedSender {
+                chain_id: *chain_id,
+                sender: REDACTED.to_string(),
+            },
+            VerificationError::ProofSystemMismatch { expected, found } => VerificationError::ProofSystemMismatch {
+                expected: expected.clone(),
+                found: found.clone(),
+            },
+            VerificationError::ProgramNotFound(chain_id) => VerificationError::ProgramNotFound(*chain_id),
+            VerificationError::MalformedProgramHash(_) => VerificationError::MalformedProgramHash(REDACTED.to_string()),
+            VerificationError::ProgramHashMismatch { expected, found } => VerificationError::ProgramHashMismatch {
+                expected: expected.clone(),
+                found: found.clone(),
+            },
+            VerificationError::BlockHashMismatch { expected, found } => VerificationError::BlockHashMismatch {
+                expected: expected.clone(),
+                found: found.clone(),
+            },
+            VerificationError::UncommittedProgram(hash) => VerificationError::UncommittedProgram(hash.clone()),
+            VerificationError::StaleProof { proof_height, current_height, max_lag } => VerificationError::StaleProof {
+                proof_height: *proof_height,
+                current_height: *current_height,
+                max_lag: *max_lag,
+            },
+            VerificationError::WarmUpFailed(failures) => VerificationError::WarmUpFailed(
+                failures.iter().map(|(chain_id, error)| (*chain_id, error.redacted())).collect(),
+            ),
+            #[cfg(feature = "kzg")]
+            VerificationError::Kzg(error) => VerificationError::Kzg(match error {
+                KzgError::MissingAttachment(name) => KzgError::MissingAttachment(name),
+                KzgError::Malformed(_) => KzgError::Malformed(REDACTED.to_string()),
+                KzgError::InvalidOpening => KzgError::InvalidOpening,
+                KzgError::TrustedSetup(_) => KzgError::TrustedSetup(REDACTED.to_string()),
+            }),
+        }
+    }
+
+    /// Stable machine-readable identifier of the error variant
+    ///
+    /// Unlike the `Display` output, codes do not change between releases,
+    /// so they are suitable for structured logs and metrics labels.
+    pub fn code(&self) -> &'static str {
+        match self {
+            VerificationError::Backend(_) => "backend",
+            VerificationError::InvalidFormat(_) => "invalid_format",
+            VerificationError::MissingProof => "missing_proof",
+            VerificationError::InvalidChainId => "invalid_chain_id",
+            VerificationError::System(_) => "system",
+            VerificationError::QuotaExceeded { .. } => "quota_exceeded",
+            VerificationError::UnauthorizedSender { .. } => "unauthorized_sender",
+            VerificationError::ProofSystemMismatch { .. } => "proof_system_mismatch",
+            VerificationError::ProgramNotFound(_) => "program_not_found",
+            VerificationError::MalformedProgramHash(_) => "malformed_program_hash",
+            VerificationError::ProgramHashMismatch { .. } => "program_hash_mismatch",
+            VerificationError::BlockHashMismatch { .. } => "block_hash_mismatch",
+            VerificationError::UncommittedProgram(_) => "uncommitted_program",
+            VerificationError::StaleProof { .. } => "stale_proof",
+            VerificationError::WarmUpFailed(_) => "warm_up_failed",
+            #[cfg(feature = "kzg")]
+            VerificationError::Kzg(_) => "kzg",
+        }
+    }
+
+    /// Whether the error is transient, so verification may succeed if retried
+    ///
+    /// System errors, exhausted quotas and backend timeouts or resource
+    /// exhaustion are transient; validation failures are permanent.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            VerificationError::System(_)
+                | VerificationError::QuotaExceeded { .. }
+                | VerificationError::Backend(ZkError::Timeout(_) | ZkError::ResourceExhausted(_))
+        )
+    }
+
+    /// Apply the given verbosity to this error
+    pub fn with_verbosity(self, verbosity: ErrorVerbosity) -> Self {
+        match verbosity {
+            ErrorVerbosity::Verbose => self,
+            ErrorVerbosity::Redacted => self.redacted(),
+        }
+    }
+}
+
+/// How much internal detail errors should expose
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorVerbosity {
+    /// Full detail, suitable for logs
+    #[default]
+    Verbose,
+    /// Sensitive detail stripped, suitable for client-facing responses
+    Redacted,
 }
 
 /// Result type for verification operations
@@ -40,286 +268,3799 @@ pub type VerificationResult<T> = Result<T, VerificationError>;
 /// Cache entry for verification programs
 #[derive(Debug)]
 struct ProgramCacheEntry {
-    #[allow(dead_code)]  // Used for future validation
     program_hash: [u8; 32],
-    program_bytes: Vec<u8>,
+    program_bytes: Arc<Vec<u8>>,
+    is_dummy: bool,
     last_used: std::time::SystemTime,
     use_count: u64,
 }
 
+/// A verification program resolved for a chain
+#[derive(Debug)]
+struct LoadedProgram {
+    bytes: Arc<Vec<u8>>,
+    hash: [u8; 32],
+    is_dummy: bool,
+    cache_hit: bool,
+}
+
+/// Outcome of the checks every message entry point runs before the backend
+enum Precheck<'a> {
+    /// The route settled the result without a proof
+    Decided(bool),
+    /// The proof bytes to verify
+    Proof(&'a [u8]),
+}
+
+/// Result of a backend call, as handled after the call by every message
+/// entry point
+trait Verdict: Sized {
+    /// Whether a cached validity can stand in for a backend call
+    const CACHEABLE: bool = true;
+
+    /// Whether the proof was accepted
+    fn is_valid(&self) -> bool;
+
+    /// The result for a message whose validity was settled without the
+    /// backend
+    fn from_valid(valid: bool) -> Self;
+}
+
+impl Verdict for bool {
+    fn is_valid(&self) -> bool {
+        *self
+    }
+
+    fn from_valid(valid: bool) -> Self {
+        valid
+    }
+}
+
+/// Validity and public outputs; outputs are not cached, so every call
+/// reaches the backend
+impl Verdict for (bool, Vec<u8>) {
+    const CACHEABLE: bool = false;
+
+    fn is_valid(&self) -> bool {
+        self.0
+    }
+
+    fn from_valid(valid: bool) -> Self {
+        (valid, Vec::new())
+    }
+}
+
+/// A backend that can verify a proof against explicit public inputs
+#[async_trait]
+pub trait PublicInputBackend: ZkBackend {
+    /// Verify a proof against the given public input bytes
+    async fn verify_with_inputs(
+        &self,
+        program: &[u8],
+        proof: &[u8],
+        public_inputs: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<bool>;
+}
+
+/// A backend that can surface the public outputs committed to by a proof
+///
+/// Outputs are the raw bytes the program committed to while proving (for
+/// example an SP1 program's public values), returned without any additional
+/// framing. Their layout is defined by the verification program.
+#[async_trait]
+pub trait PublicOutputBackend: ZkBackend {
+    /// Verify a proof, returning the result together with its public outputs
+    async fn verify_with_outputs(
+        &self,
+        program: &[u8],
+        proof: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<(bool, Vec<u8>)>;
+}
+
+/// A backend that can receive a proof as an ordered stream of chunks
+///
+/// Used for proofs above
+/// [`VerifierConfig::chunked_proof_threshold`], which may exceed the
+/// backend's single-call input limit. The chunks concatenate to the full
+/// proof of `proof_len` bytes.
+#[async_trait]
+pub trait ChunkedProofBackend: ZkBackend {
+    /// Verify a proof delivered in chunks
+    async fn verify_chunked(
+        &self,
+        program: &[u8],
+        proof_len: usize,
+        chunks: BoxStream<'_, &[u8]>,
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<bool>;
+}
+
+/// Stage of proof generation reported by [`ProofProgress`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofPhase {
+    /// Proving was handed to the backend
+    Started,
+    /// A backend-defined stage, e.g. `"witness"` or `"recursion"`
+    Stage(String),
+    /// The proof was generated
+    Finished,
+}
+
+/// Progress event emitted while proving
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofProgress {
+    /// Current phase
+    pub phase: ProofPhase,
+    /// Overall completion in percent, if the backend can estimate it
+    pub percent: Option<f32>,
+}
+
+/// A backend that can report progress while proving
+///
+/// The default implementation proves without intermediate events, so
+/// backends without progress information can opt in with an empty impl and
+/// callers still receive coarse start and finish events.
+#[async_trait]
+pub trait ProgressBackend: ZkBackend {
+    /// Generate a proof, reporting intermediate [`ProofPhase::Stage`] events
+    async fn prove_with_progress(
+        &self,
+        program: &[u8],
+        input: &[u8],
+        config: Option<&ZkConfig>,
+        progress: &(dyn Fn(ProofProgress) + Send + Sync),
+    ) -> ZkResult<(Vec<u8>, ProofMetadata)> {
+        let _ = progress;
+        self.prove(program, input, config).await
+    }
+}
+
+/// A backend whose proof system verifies against trusted setup parameters
+#[async_trait]
+pub trait ParamsBackend: ZkBackend {
+    /// Verify a proof using the given verification parameters
+    async fn verify_with_params(
+        &self,
+        program: &[u8],
+        proof: &[u8],
+        params: &[u8],
+        config: Option<&ZkConfig>,
+    ) -> ZkResult<bool>;
+}
+
+/// A cached verification program captured in a [`CacheSnapshot`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    /// Chain the program verifies
+    pub chain_id: ChainId,
+    /// Hash of the program bytes
+    pub program_hash: [u8; 32],
+    /// The program bytes
+    pub program_bytes: Vec<u8>,
+    /// Whether the program is the development placeholder used when the
+    /// program file is missing
+    #[serde(default)]
+    pub is_dummy: bool,
+}
+
+/// Serializable snapshot of the program cache
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheSnapshot {
+    /// Cached programs, from most to least recently used
+    pub entries: Vec<SnapshotEntry>,
+}
+
+/// Occupancy of the program cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of cached programs
+    pub entries: usize,
+    /// Maximum number of cached programs
+    pub capacity: usize,
+    /// Total size in bytes of the cached program binaries
+    pub bytes: usize,
+}
+
+/// How the verifier treats errors returned by the backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackendErrorPolicy {
+    /// Return the error as [`VerificationError::Backend`]
+    #[default]
+    Propagate,
+    /// Fail closed: report the proof as not verified (`Ok(false)`)
+    TreatAsInvalid,
+}
+
+/// When [`MessageVerifier::verify_message`] runs the backend verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerificationStrategy {
+    /// Verify every message before returning
+    #[default]
+    Immediate,
+    /// Accept messages without verifying their proofs, recording each for
+    /// mandatory verification within `challenge_window` through
+    /// [`verify_pending_challenges`](MessageVerifier::verify_pending_challenges)
+    ///
+    /// Only the backend call is deferred: origin, route signature, proof
+    /// envelope and size, payload and KZG checks still run before a message
+    /// is accepted. This applies to [`verify_message`](MessageVerifier::verify_message)
+    /// and everything built on it: the client, confidence, freshness,
+    /// signature, batch, stream, frame and log variants, whose `true`
+    /// results are then provisional. The traced, params, chunked, program
+    /// proof, inputs, block hash, outputs and raw entry points always
+    /// verify immediately.
+    Optimistic {
+        /// Time after acceptance by which the message must be verified
+        challenge_window: Duration,
+    },
+}
+
+/// A message accepted optimistically and awaiting verification
+#[derive(Debug)]
+struct PendingChallenge {
+    message: FrostMessage,
+    deadline: Instant,
+}
+
+/// Result of verifying an optimistically accepted message
+#[derive(Debug)]
+pub struct ChallengeOutcome {
+    /// ID of the verified message
+    pub message_id: Uuid,
+    /// Verification result
+    pub result: VerificationResult<bool>,
+    /// Whether the challenge window closed before verification finished
+    pub window_closed: bool,
+}
+
+/// Combined outcome of [`MessageVerifier::verify_message_full`]
+#[cfg(feature = "signing")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FullOutcome {
+    /// Whether the message signature is valid for the given key
+    pub signature_ok: bool,
+    /// Whether the proof verified; always `false` if the signature failed,
+    /// as the proof is then not checked
+    pub proof_ok: bool,
+}
+
+#[cfg(feature = "signing")]
+impl FullOutcome {
+    /// Whether both the signature and the proof are valid
+    pub fn is_valid(&self) -> bool {
+        self.signature_ok && self.proof_ok
+    }
+}
+
+/// Proof system and version a chain's proofs must be tagged with
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProofSystem {
+    /// Proof system name, as in [`SerializedProof::system`]
+    pub system: String,
+    /// Proof system version
+    pub version: u32,
+}
+
+impl ProofSystem {
+    /// Create a proof system tag
+    pub fn new(system: impl Into<String>, version: u32) -> Self {
+        Self {
+            system: system.into(),
+            version,
+        }
+    }
+}
+
+impl std::fmt::Display for ProofSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} v{}", self.system, self.version)
+    }
+}
+
+/// Per-message result of [`MessageVerifier::verify_messages_batch_skipping`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchResult {
+    /// The message was verified with the given outcome
+    Verified(bool),
+    /// The message was skipped as already verified
+    Skipped,
+}
+
+impl BatchResult {
+    /// Whether the message was skipped
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, BatchResult::Skipped)
+    }
+}
+
+/// Message verifier configuration
+#[derive(Debug, Clone)]
+pub struct VerifierConfig {
+    /// Maximum number of cached programs
+    pub cache_size: usize,
+    /// Program cache TTL in seconds
+    pub cache_ttl: u64,
+    /// Maximum number of cached verification results (0 disables the cache)
+    pub result_cache_size: usize,
+    /// Algorithm used for program hashes and result cache keys
+    pub hash_algorithm: HashAlgorithm,
+    /// Payload format validators, checked against the destination chain
+    pub payload_validators: Option<PayloadValidators>,
+    /// Recorder capturing every backend verification for later replay
+    pub session_recorder: Option<Arc<SessionRecorder>>,
+    /// How backend errors are surfaced
+    pub on_backend_error: BackendErrorPolicy,
+    /// Whether messages are verified immediately or accepted optimistically
+    pub strategy: VerificationStrategy,
+    /// Maximum number of optimistically accepted messages awaiting
+    /// verification; further messages are rejected until the queue drains
+    pub max_pending_challenges: usize,
+    /// Maximum number of concurrent backend verifications
+    pub max_concurrent_verifications: usize,
+    /// Backend verifications taking longer than this are reported to
+    /// `on_slow_verification`; `None` disables the check
+    pub slow_threshold: Option<Duration>,
+    /// Called with the message ID, chain and proof size of every
+    /// verification exceeding `slow_threshold`
+    pub on_slow_verification: Option<SlowVerificationCallback>,
+    /// Reject verifications while the backend reports a
+    /// [`ResourceUsage::queue_depth`] above this; `None` disables the check
+    pub max_backend_queue_depth: Option<usize>,
+    /// Relative share of the verification budget per source chain under
+    /// contention; unlisted chains have weight 1
+    pub chain_weights: HashMap<ChainId, u32>,
+    /// Byte order of integer input fields per source chain; unlisted chains
+    /// use big-endian
+    pub input_endianness: HashMap<ChainId, Endianness>,
+    /// Largest payload, in bytes, encoded into a public input; longer
+    /// payloads are rejected before reaching the backend
+    pub max_input_payload_len: usize,
+    /// Reject proofs whose metadata `program_hash` does not match the
+    /// program they are verified against (see
+    /// [`check_program_hash`](MessageVerifier::check_program_hash)), on
+    /// every message entry point
+    pub require_program_hash: bool,
+    /// Pass zero-length proofs to the backend instead of rejecting them
+    pub allow_empty_proofs: bool,
+    /// Capabilities per source chain; proofs above a chain's
+    /// [`ChainCapabilities::max_proof_size`] are rejected before reaching
+    /// the backend. Unlisted chains have no size limit
+    pub chain_capabilities: HashMap<ChainId, ChainCapabilities>,
+    /// Substitute a zero-filled placeholder for missing program files
+    /// instead of failing with [`VerificationError::ProgramNotFound`]; for
+    /// tests only
+    pub allow_dummy_programs: bool,
+    /// Store program files are read through, shared with other verifiers
+    /// using it (e.g. [`ProgramStore::global`]); `None` reads programs
+    /// directly
+    pub program_store: Option<Arc<ProgramStore>>,
+    /// Source of trusted setup parameters for [`ParamsBackend`] verification
+    pub params_loader: Option<Arc<dyn ParamsLoader>>,
+    /// Per-client quotas enforced by
+    /// [`verify_message_for_client`](MessageVerifier::verify_message_for_client)
+    pub quota: Option<Arc<QuotaGuard>>,
+    /// Finality of each source chain, used to score verification confidence;
+    /// unlisted chains are treated as deterministic
+    pub finality: HashMap<ChainId, FinalityType>,
+    /// Record a [`Counterexample`] for every proof the backend rejects on a
+    /// message entry point; [`verify_raw`](MessageVerifier::verify_raw) and
+    /// optimistically accepted messages not yet challenged are not captured
+    pub capture_failures: bool,
+    /// Number of captured counterexamples kept in memory; the oldest are
+    /// dropped first
+    pub max_captured_failures: usize,
+    /// Directory captured counterexamples are also written to
+    pub counterexample_dir: Option<PathBuf>,
+    /// Proof system each source chain's proofs must be enveloped with; for
+    /// unlisted chains proof bytes are passed to the backend as-is, even if
+    /// they happen to start with the envelope magic
+    pub proof_systems: HashMap<ChainId, ProofSystem>,
+    /// Senders allowed per source chain; chains without an entry are
+    /// unrestricted
+    pub sender_allowlist: SenderAllowlist,
+    /// Artifacts [`verify_message`](MessageVerifier::verify_message)
+    /// requires per route; unlisted routes require a proof
+    pub route_requirements: RouteRequirements,
+    /// Largest number of messages
+    /// [`verify_messages_batch`](MessageVerifier::verify_messages_batch)
+    /// verifies at once
+    pub max_batch_size: usize,
+    /// Number of batch verifications dispatched concurrently, further capped
+    /// by `max_batch_size`; `None` uses the backend's reported
+    /// [`ResourceUsage::max_concurrent`]
+    pub max_batch_concurrency: Option<usize>,
+    /// Proofs longer than this many bytes are streamed to a
+    /// [`ChunkedProofBackend`] in chunks of this size
+    pub chunked_proof_threshold: usize,
+    /// Checks KZG openings of blob-carrying messages before ZK verification
+    #[cfg(feature = "kzg")]
+    pub kzg: Option<Arc<KzgVerifier>>,
+}
+
+impl Default for VerifierConfig {
+    fn default() -> Self {
+        Self {
+            cache_size: 100,
+            cache_ttl: 3600, // 1 hour
+            result_cache_size: 0,
+            hash_algorithm: HashAlgorithm::default(),
+            payload_validators: None,
+            session_recorder: None,
+            on_backend_error: BackendErrorPolicy::default(),
+            strategy: VerificationStrategy::default(),
+            max_pending_challenges: 10_000,
+            max_concurrent_verifications: num_cpus::get(),
+            slow_threshold: None,
+            on_slow_verification: None,
+            max_backend_queue_depth: None,
+            chain_weights: HashMap::new(),
+            input_endianness: HashMap::new(),
+            max_input_payload_len: 16 * 1024 * 1024,
+            require_program_hash: false,
+            allow_empty_proofs: false,
+            chain_capabilities: HashMap::new(),
+            allow_dummy_programs: false,
+            program_store: None,
+            params_loader: None,
+            quota: None,
+            finality: HashMap::new(),
+            capture_failures: false,
+            max_captured_failures: 100,
+            counterexample_dir: None,
+            proof_systems: HashMap::new(),
+            sender_allowlist: SenderAllowlist::default(),
+            route_requirements: RouteRequirements::default(),
+            max_batch_size: 64,
+            max_batch_concurrency: None,
+            chunked_proof_threshold: 4 * 1024 * 1024,
+            #[cfg(feature = "kzg")]
+            kzg: None,
+        }
+    }
+}
+
+/// Best-effort recovery of the `id` field of a malformed serialized message
+fn recover_message_id(json: &[u8]) -> Uuid {
+    serde_json::from_slice::<serde_json::Value>(json)
+        .ok()
+        .and_then(|value| value.get("id")?.as_str()?.parse().ok())
+        .unwrap_or_else(Uuid::nil)
+}
+
 /// Message verifier using the new ZkBackend interface
 pub struct MessageVerifier<B: ZkBackend> {
     /// ZK backend instance
     backend: Arc<B>,
     /// Program cache
     program_cache: Arc<RwLock<LruCache<ChainId, ProgramCacheEntry>>>,
-    /// Cache TTL in seconds
-    cache_ttl: u64,
-    /// Cache configuration
-    #[allow(dead_code)]  // Used for future cache resizing
-    cache_size: usize,
+    /// Verification parameters cache, kept apart from programs
+    params_cache: RwLock<HashMap<ChainId, Arc<Vec<u8>>>>,
+    /// Verification result cache, keyed by a digest of program, proof and input
+    result_cache: Option<RwLock<LruCache<[u8; 32], bool>>>,
+    /// Per-chain locks serializing program loads
+    load_locks: Mutex<HashMap<ChainId, Arc<tokio::sync::Mutex<()>>>>,
+    /// Verifier metrics
+    metrics: Mutex<VerifierMetrics>,
+    /// Fair admission of backend verifications across chains
+    scheduler: FairScheduler,
+    /// Counterexamples captured for rejected proofs
+    failures: Mutex<VecDeque<Counterexample>>,
+    /// Optimistically accepted messages awaiting verification
+    pending_challenges: Mutex<Vec<PendingChallenge>>,
+    /// Program file locations
+    registry: ProgramRegistry,
+    /// Verifier configuration
+    config: VerifierConfig,
 }
 
 impl<B: ZkBackend> MessageVerifier<B> {
     /// Create a new message verifier with the given backend
     pub fn new(backend: Arc<B>) -> Self {
-        Self::with_config(backend, 100, 3600) // Default 100 entries, 1 hour TTL
+        Self::from_config(backend, VerifierConfig::default())
     }
 
     /// Create a new message verifier with custom configuration
     pub fn with_config(backend: Arc<B>, cache_size: usize, cache_ttl: u64) -> Self {
-        Self {
-            backend,
-            program_cache: Arc::new(RwLock::new(LruCache::new(NonZeroUsize::new(cache_size).unwrap()))),
+        Self::from_config(backend, VerifierConfig {
             cache_size,
             cache_ttl,
+            ..Default::default()
+        })
+    }
+
+    /// Create a new message verifier from a full configuration
+    pub fn from_config(backend: Arc<B>, config: VerifierConfig) -> Self {
+        Self::with_config_and_registry(backend, config, ProgramRegistry::default())
+    }
+
+    /// Create a new message verifier loading programs from the given registry
+    pub fn with_config_and_registry(backend: Arc<B>, config: VerifierConfig, registry: ProgramRegistry) -> Self {
+        Self {
+            backend,
+            program_cache: Arc::new(RwLock::new(LruCache::new(NonZeroUsize::new(config.cache_size).unwrap()))),
+            params_cache: RwLock::new(HashMap::new()),
+            result_cache: NonZeroUsize::new(config.result_cache_size)
+                .map(|size| RwLock::new(LruCache::new(size))),
+            load_locks: Mutex::new(HashMap::new()),
+            metrics: Mutex::new(VerifierMetrics::default()),
+            scheduler: FairScheduler::new(config.max_concurrent_verifications, config.chain_weights.clone()),
+            failures: Mutex::new(VecDeque::new()),
+            pending_challenges: Mutex::new(Vec::new()),
+            registry,
+            config,
         }
     }
 
     /// Get or load verification program for a chain
-    async fn get_program(&self, chain_id: ChainId) -> VerificationResult<Vec<u8>> {
+    async fn get_program(&self, chain_id: ChainId) -> VerificationResult<Arc<Vec<u8>>> {
+        Ok(self.load_program(chain_id).await?.bytes)
+    }
+
+    /// Get the program used to verify messages from a chain
+    ///
+    /// Returns the program bytes, their hash, and whether they are the
+    /// zero-filled placeholder used when the program file is missing and
+    /// [`VerifierConfig::allow_dummy_programs`] is set, so audits can tell which program a verification ran against.
+    pub async fn program_for(&self, chain_id: ChainId) -> VerificationResult<(Vec<u8>, [u8; 32], bool)> {
+        let program = self.load_program(chain_id).await?;
+        Ok((program.bytes.to_vec(), program.hash, program.is_dummy))
+    }
+
+    /// Get or load the verification parameters for a chain
+    async fn get_params(&self, chain_id: ChainId) -> VerificationResult<Arc<Vec<u8>>> {
+        if let Some(params) = self.params_cache.read().get(&chain_id) {
+            return Ok(params.clone());
+        }
+        let loader = self.config.params_loader.as_ref()
+            .ok_or_else(|| VerificationError::System("No params loader configured".to_string()))?;
+        let params = Arc::new(loader.load_params(chain_id).await?);
+        self.params_cache.write().insert(chain_id, params.clone());
+        Ok(params)
+    }
+
+    /// Get or load verification program for a chain, reporting cache details
+    ///
+    /// Loads are single-flight per chain: on a cold cache, the first caller
+    /// loads the program while concurrent callers for the same chain wait on
+    /// its lock and are then served from cache.
+    async fn load_program(&self, chain_id: ChainId) -> VerificationResult<LoadedProgram> {
         // Check cache first
-        let mut cache = self.program_cache.write();
-        if let Some(entry) = cache.get_mut(&chain_id) {
-            // Check if entry is still valid
-            if let Ok(age) = std::time::SystemTime::now().duration_since(entry.last_used) {
-                if age.as_secs() < self.cache_ttl {
-                    entry.use_count += 1;
-                    entry.last_used = std::time::SystemTime::now();
-                    return Ok(entry.program_bytes.clone());
-                }
-            }
+        if let Some(program) = self.cached_program(chain_id) {
+            return Ok(program);
         }
 
-        // Load program based on chain ID
-        let program_path = match chain_id {
+        // Load program from the registry, falling back to the default locations
+        let default_path = match chain_id {
             ChainId::Ethereum => "../../../frostgate-circuits/programs/eth_verifier.sp1",
             ChainId::Polkadot => "../../../frostgate-circuits/programs/dot_verifier.sp1",
             ChainId::Solana => "../../../frostgate-circuits/programs/sol_verifier.sp1",
             ChainId::Unknown => return Err(VerificationError::InvalidChainId),
         };
+        let program_path = self.registry.path(chain_id).unwrap_or(Path::new(default_path));
 
-        let program_bytes = if let Ok(bytes) = fs::read(Path::new(program_path)) {
-            bytes
-        } else {
-            // For development/testing, return dummy program bytes
-            vec![0u8; 64] // Placeholder for development
-        };
+        // Wait for any in-flight load of the same program
+        let load_lock = self.load_locks.lock().entry(chain_id).or_default().clone();
+        let _guard = load_lock.lock().await;
+        if let Some(program) = self.cached_program(chain_id) {
+            return Ok(program);
+        }
 
-        // Calculate program hash
-        let mut hasher = Blake2b512::new();
-        hasher.update(&program_bytes);
-        let mut program_hash = [0u8; 32];
-        program_hash.copy_from_slice(&hasher.finalize()[..32]);
+        let started = Instant::now();
+        let (program_bytes, program_hash, is_dummy) = match self.read_program(program_path) {
+            Ok((bytes, hash)) => (bytes, hash, false),
+            // For testing, return dummy program bytes
+            Err(_) if self.config.allow_dummy_programs => {
+                let bytes = Arc::new(vec![0u8; 64]);
+                let hash = self.config.hash_algorithm.digest(&bytes);
+                (bytes, hash, true)
+            }
+            Err(_) => return Err(VerificationError::ProgramNotFound(chain_id)),
+        };
+        self.metrics.lock().record_load(chain_id, started.elapsed(), is_dummy);
 
         // Cache program
-        cache.put(chain_id, ProgramCacheEntry {
+        self.program_cache.write().put(chain_id, ProgramCacheEntry {
             program_hash,
             program_bytes: program_bytes.clone(),
+            is_dummy,
             last_used: std::time::SystemTime::now(),
             use_count: 1,
         });
 
-        Ok(program_bytes)
+        Ok(LoadedProgram {
+            bytes: program_bytes,
+            hash: program_hash,
+            is_dummy,
+            cache_hit: false,
+        })
+    }
+
+    /// Read and hash a program file, through the shared store if configured
+    fn read_program(&self, path: &Path) -> std::io::Result<(Arc<Vec<u8>>, [u8; 32])> {
+        match &self.config.program_store {
+            Some(store) => store.load(path, self.config.hash_algorithm),
+            None => {
+                let bytes = Arc::new(fs::read(path)?);
+                let hash = self.config.hash_algorithm.digest(&bytes);
+                Ok((bytes, hash))
+            }
+        }
+    }
+
+    /// Look up a program in the cache, honoring the cache TTL
+    fn cached_program(&self, chain_id: ChainId) -> Option<LoadedProgram> {
+        let mut cache = self.program_cache.write();
+        let entry = cache.get_mut(&chain_id)?;
+        // Check if entry is still valid
+        let age = std::time::SystemTime::now().duration_since(entry.last_used).ok()?;
+        if age.as_secs() >= self.config.cache_ttl {
+            return None;
+        }
+        entry.use_count += 1;
+        entry.last_used = std::time::SystemTime::now();
+        self.metrics.lock().record_cache_hit(chain_id);
+        Some(LoadedProgram {
+            bytes: entry.program_bytes.clone(),
+            hash: entry.program_hash,
+            is_dummy: entry.is_dummy,
+            cache_hit: true,
+        })
     }
 
     /// Verify a message using the ZK backend
+    ///
+    /// What is checked depends on the message's route in
+    /// [`VerifierConfig::route_requirements`]: a trusted signature is checked
+    /// first if required, and the proof is skipped on signature-only routes.
+    ///
+    /// Under [`VerificationStrategy::Optimistic`] a message passing the
+    /// checks that precede the backend is instead accepted with `Ok(true)`
+    /// and queued for [`verify_pending_challenges`](Self::verify_pending_challenges).
+    /// While [`VerifierConfig::max_pending_challenges`] messages are queued,
+    /// further messages fail with [`VerificationError::System`].
     pub async fn verify_message(&self, message: &FrostMessage) -> VerificationResult<bool> {
-        // Get proof
-        let proof = message.proof.as_ref()
-            .ok_or(VerificationError::MissingProof)?;
+        if let VerificationStrategy::Optimistic { challenge_window } = self.config.strategy {
+            if let Precheck::Decided(result) = self.precheck(message)? {
+                return Ok(result);
+            }
+            let mut pending = self.pending_challenges.lock();
+            if pending.len() >= self.config.max_pending_challenges {
+                return Err(VerificationError::System("challenge queue full".to_string()));
+            }
+            pending.push(PendingChallenge {
+                message: message.clone(),
+                deadline: Instant::now() + challenge_window,
+            });
+            return Ok(true);
+        }
+        self.verify_message_now(message).await
+    }
+
+    /// Verify a message immediately, regardless of the configured strategy
+    async fn verify_message_now(&self, message: &FrostMessage) -> VerificationResult<bool> {
+        self.verify_message_using(message, |program, proof| async move {
+            self.backend.verify(&program, proof, None).await
+        })
+        .await
+    }
+
+    /// Verify a message, making the backend call through `verify` with the
+    /// program and proof
+    ///
+    /// The pre-backend checks, program load and input encoding run before
+    /// [`verify_prepared`](Self::verify_prepared).
+    async fn verify_message_using<'a, T, F, Fut>(&self, message: &'a FrostMessage, verify: F) -> VerificationResult<T>
+    where
+        T: Verdict,
+        F: FnOnce(Arc<Vec<u8>>, &'a [u8]) -> Fut,
+        Fut: Future<Output = ZkResult<T>>,
+    {
+        let proof = match self.precheck(message)? {
+            Precheck::Proof(proof) => proof,
+            Precheck::Decided(result) => return Ok(T::from_valid(result)),
+        };
 
         // Get verification program
-        let program = self.get_program(message.from_chain).await?;
+        let program = self.load_message_program(message).await?;
 
         // Prepare input data
-        let mut input = Vec::new();
-        input.extend_from_slice(&message.from_chain.to_u64().to_be_bytes());
-        input.extend_from_slice(&message.to_chain.to_u64().to_be_bytes());
-        input.extend_from_slice(&(message.payload.len() as u64).to_be_bytes());
-        input.extend_from_slice(&message.payload);
-        input.extend_from_slice(&message.nonce.to_be_bytes());
-        input.extend_from_slice(&message.timestamp.to_be_bytes());
+        let input = self.encode_input(message)?;
+
+        self.verify_prepared(message, proof, &program, &input, verify).await
+    }
+
+    /// Make the backend call for a message that passed the pre-backend
+    /// checks, through `verify` with the program and proof
+    ///
+    /// `input` is the public input the proof is checked against. The result
+    /// cache lookup runs before `verify`; the slow verification report,
+    /// session recording, failure capture and result cache update after.
+    async fn verify_prepared<'a, T, F, Fut>(
+        &self,
+        message: &FrostMessage,
+        proof: &'a [u8],
+        program: &LoadedProgram,
+        input: &[u8],
+        verify: F,
+    ) -> VerificationResult<T>
+    where
+        T: Verdict,
+        F: FnOnce(Arc<Vec<u8>>, &'a [u8]) -> Fut,
+        Fut: Future<Output = ZkResult<T>>,
+    {
+        // Check result cache
+        let cache_key = self.result_cache_key(&program.hash, proof, input);
+        if let (true, Some(cache)) = (T::CACHEABLE, &self.result_cache) {
+            if let Some(result) = cache.write().get(&cache_key) {
+                return Ok(T::from_valid(*result));
+            }
+        }
 
         // Verify proof
-        let result = self.backend.verify(&program, &proof.data, None).await?;
+        let permit = self.acquire_permit(message.from_chain).await?;
+        let started = Instant::now();
+        let result = verify(program.bytes.clone(), proof).await;
+        drop(permit);
+        self.report_if_slow(message, proof, started.elapsed());
+        if let Some(recorder) = &self.config.session_recorder {
+            recorder.record(RecordedVerification {
+                message_id: message.id,
+                program: program.bytes.to_vec(),
+                proof: proof.to_vec(),
+                input: input.to_vec(),
+                outcome: result.as_ref().map(T::is_valid).map_err(|e| e.to_string()),
+            });
+        }
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => return self.handle_backend_error(e).map(T::from_valid),
+        };
+        if !result.is_valid() {
+            self.capture_failure(message, program.hash, proof, input);
+        }
+
+        if let Some(cache) = &self.result_cache {
+            cache.write().put(cache_key, result.is_valid());
+        }
 
         Ok(result)
     }
 
-    /// Verify multiple messages in batch
-    pub async fn verify_messages_batch(&self, messages: &[FrostMessage]) -> VerificationResult<Vec<bool>> {
-        let mut results = Vec::with_capacity(messages.len());
+    /// Verify a message with a caller-supplied program from a committed set
+    ///
+    /// The program's hash (under the configured [`HashAlgorithm`]) must be
+    /// proven by `merkle_proof` to be a leaf of the tree with the given
+    /// `root` and `height`, typically read from an on-chain commitment. Otherwise
+    /// [`VerificationError::UncommittedProgram`] is returned without calling
+    /// the backend. The program cache is not consulted or updated; otherwise
+    /// this behaves like an immediate [`verify_message`](Self::verify_message).
+    pub async fn verify_message_with_program_proof(
+        &self,
+        message: &FrostMessage,
+        program: &[u8],
+        merkle_proof: &MerkleProof,
+        root: &[u8; 32],
+        height: usize,
+    ) -> VerificationResult<bool> {
+        let proof = match self.precheck(message)? {
+            Precheck::Proof(proof) => proof,
+            Precheck::Decided(result) => return Ok(result),
+        };
 
-        for message in messages {
-            results.push(self.verify_message(message).await?);
+        let algorithm = self.config.hash_algorithm;
+        let program_hash = algorithm.digest(program);
+        if !merkle_proof.verify(program_hash, root, height, algorithm) {
+            return Err(VerificationError::UncommittedProgram(hex::encode(program_hash)));
         }
+        self.check_required_program_hash(message, &program_hash)?;
+        let program = LoadedProgram {
+            bytes: Arc::new(program.to_vec()),
+            hash: program_hash,
+            is_dummy: false,
+            cache_hit: false,
+        };
+        let input = self.encode_input(message)?;
 
-        Ok(results)
+        self.verify_prepared(message, proof, &program, &input, |program, proof| async move {
+            self.backend.verify(&program, proof, None).await
+        })
+        .await
     }
 
-    /// Get backend health status
-    pub async fn health_check(&self) -> HealthStatus {
-        self.backend.health_check().await
+    /// Number of optimistically accepted messages awaiting verification
+    pub fn pending_challenge_count(&self) -> usize {
+        self.pending_challenges.lock().len()
     }
 
-    /// Get backend resource usage
-    pub fn resource_usage(&self) -> ResourceUsage {
-        self.backend.resource_usage()
+    /// Earliest deadline by which a pending message must be verified
+    pub fn next_challenge_deadline(&self) -> Option<Instant> {
+        self.pending_challenges.lock().iter().map(|pending| pending.deadline).min()
     }
 
-    /// Clear program cache
-    pub async fn clear_cache(&mut self) -> VerificationResult<()> {
-        self.program_cache.write().clear();
-        Ok(())
+    /// Verify every optimistically accepted message, clearing the queue
+    ///
+    /// Outcomes are returned in acceptance order, so callers can revert
+    /// messages that fail. Messages are verified even if their challenge
+    /// window has already closed, which is reported in
+    /// [`ChallengeOutcome::window_closed`].
+    pub async fn verify_pending_challenges(&self) -> Vec<ChallengeOutcome> {
+        let pending = std::mem::take(&mut *self.pending_challenges.lock());
+        futures::stream::iter(pending)
+            .map(|pending| async move {
+                let result = self.verify_message_now(&pending.message).await;
+                ChallengeOutcome {
+                    message_id: pending.message.id,
+                    result,
+                    window_closed: Instant::now() > pending.deadline,
+                }
+            })
+            .buffered(self.batch_concurrency())
+            .collect()
+            .await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use uuid::Uuid;
-
-    // Mock backend for testing
-    #[derive(Debug)]
-    struct MockBackend;
-    
-    #[async_trait]
-    impl ZkBackend for MockBackend {
-        async fn prove(&self, _program: &[u8], _input: &[u8], _config: Option<&ZkConfig>) -> ZkResult<(Vec<u8>, frostgate_zkip::types::ProofMetadata)> {
-            Ok((vec![], frostgate_zkip::types::ProofMetadata {
-                generation_time: Duration::from_secs(1),
-                proof_size: 4,
-                program_hash: "dummy".to_string(),
-                timestamp: SystemTime::now(),
-            }))
+    /// Record a rejected proof if failure capture is enabled
+    fn capture_failure(&self, message: &FrostMessage, program_hash: [u8; 32], proof: &[u8], input: &[u8]) {
+        if !self.config.capture_failures {
+            return;
+        }
+        let counterexample = Counterexample {
+            message_id: message.id,
+            chain_id: message.from_chain,
+            program_hash,
+            proof: proof.to_vec(),
+            input: input.to_vec(),
+            captured_at: SystemTime::now(),
+        };
+        if let Some(dir) = &self.config.counterexample_dir {
+            // Capture is a debugging aid and must not change the result
+            let _ = counterexample.save(dir);
+        }
+        let mut failures = self.failures.lock();
+        failures.push_back(counterexample);
+        while failures.len() > self.config.max_captured_failures {
+            failures.pop_front();
         }
+    }
 
-        async fn verify(&self, _program: &[u8], _proof: &[u8], _config: Option<&ZkConfig>) -> ZkResult<bool> {
-            Ok(true)
+    /// Get the counterexamples captured so far, oldest first
+    ///
+    /// At most [`VerifierConfig::max_captured_failures`] are kept.
+    pub fn failures(&self) -> Vec<Counterexample> {
+        self.failures.lock().iter().cloned().collect()
+    }
+
+    /// Remove and return the counterexamples captured so far, oldest first
+    pub fn take_failures(&self) -> Vec<Counterexample> {
+        self.failures.lock().drain(..).collect()
+    }
+
+    /// Run the checks shared by every message entry point
+    ///
+    /// In order: the protocol version and sender ([`check_origin`](Self::check_origin)),
+    /// the trusted signature if the message's route requires one, the proof
+    /// envelope and size, the payload validators and the KZG opening.
+    /// Messages with an invalid route signature are decided as `false`, and
+    /// valid messages on signature-only routes as `true`, so callers skip the
+    /// backend.
+    fn precheck<'a>(&self, message: &'a FrostMessage) -> VerificationResult<Precheck<'a>> {
+        self.check_origin(message)?;
+        let required = self.config.route_requirements.for_message(message);
+        if required.requires_signature() && !self.config.route_requirements.verify_signature(message)? {
+            return Ok(Precheck::Decided(false));
         }
+        let proof = required.requires_proof().then(|| self.message_proof(message)).transpose()?;
+        self.validate_payload(message)?;
+        self.check_kzg(message)?;
+        Ok(proof.map_or(Precheck::Decided(true), Precheck::Proof))
+    }
 
-        async fn health_check(&self) -> HealthStatus {
-            HealthStatus::Healthy
+    /// Check the message's protocol version and that its sender is allowed
+    fn check_origin(&self, message: &FrostMessage) -> VerificationResult<()> {
+        if !message.is_compatible_with(PROTOCOL_VERSION) {
+            return Err(VerificationError::InvalidFormat(format!(
+                "Incompatible protocol version: {} (supported: {})",
+                message.protocol_version().unwrap_or("<none>"),
+                PROTOCOL_VERSION
+            )));
         }
+        self.config.sender_allowlist.check(message)
+    }
 
-        fn resource_usage(&self) -> ResourceUsage {
-            ResourceUsage {
-                cpu_usage: 0.0,
-                memory_usage: 0,
-                active_tasks: 0,
-                max_concurrent: 1,
-                queue_depth: 0,
+    /// Get the proof bytes to hand to the backend, rejecting empty proofs
+    /// unless allowed
+    ///
+    /// Chains with a
+    /// proof system in [`VerifierConfig::proof_systems`] must send enveloped
+    /// proofs of that system, which are unwrapped; other chains' proofs are
+    /// used as-is.
+    fn message_proof<'a>(&self, message: &'a FrostMessage) -> VerificationResult<&'a [u8]> {
+        let data = &message.proof.as_ref()
+            .ok_or(VerificationError::MissingProof)?
+            .data;
+        let proof = match self.config.proof_systems.get(&message.from_chain) {
+            None => data.as_slice(),
+            Some(expected) => {
+                let envelope = SerializedProof::split(data)
+                    .map_err(|e| VerificationError::InvalidFormat(e.to_string()))?;
+                match envelope {
+                    Some((system, version, bytes))
+                        if system == expected.system && version == expected.version => bytes,
+                    Some((system, version, _)) => {
+                        return Err(VerificationError::ProofSystemMismatch {
+                            expected: expected.to_string(),
+                            found: format!("{} v{}", system, version),
+                        });
+                    }
+                    None => {
+                        return Err(VerificationError::ProofSystemMismatch {
+                            expected: expected.to_string(),
+                            found: "untagged proof".to_string(),
+                        });
+                    }
+                }
             }
+        };
+        self.check_proof_size(message.from_chain, proof)?;
+        Ok(proof)
+    }
+
+    /// Reject empty proofs, and proofs larger than the chain's
+    /// [`ChainCapabilities::max_proof_size`]
+    fn check_proof_size(&self, chain_id: ChainId, proof: &[u8]) -> VerificationResult<()> {
+        if proof.is_empty() && !self.config.allow_empty_proofs {
+            return Err(VerificationError::InvalidFormat("empty proof".to_string()));
         }
+        let max_size = self.config.chain_capabilities
+            .get(&chain_id)
+            .and_then(|capabilities| capabilities.max_proof_size);
+        if let Some(max_size) = max_size.filter(|max_size| proof.len() > *max_size) {
+            return Err(VerificationError::InvalidFormat(format!(
+                "proof of {} bytes exceeds the {} byte limit for {:?}",
+                proof.len(), max_size, chain_id
+            )));
+        }
+        Ok(())
     }
 
-    #[tokio::test]
-    async fn test_message_verification() {
-        // Create backend and verifier
-        let backend = Arc::new(MockBackend);
-        let verifier = MessageVerifier::new(backend);
+    /// Check the proof's declared program hash against the loaded program
+    ///
+    /// The `program_hash` in the proof metadata must be a hex or base64
+    /// encoded digest of the configured [`HashAlgorithm`]'s length, failing
+    /// with [`VerificationError::MalformedProgramHash`] otherwise, and must
+    /// equal the hash of the program loaded for the source chain, failing
+    /// with [`VerificationError::ProgramHashMismatch`] otherwise.
+    pub async fn check_program_hash(&self, message: &FrostMessage) -> VerificationResult<()> {
+        let program = self.load_program(message.from_chain).await?;
+        self.match_program_hash(message, &program.hash)
+    }
 
-        // Create test message
-        let message = FrostMessage {
-            id: Uuid::new_v4(),
-            from_chain: ChainId::Ethereum,
-            to_chain: ChainId::Polkadot,
-            payload: b"test".to_vec(),
-            proof: Some(crate::messages::Proof {
-                data: vec![1, 2, 3, 4],
-                metadata: frostgate_zkip::types::ProofMetadata {
-                    generation_time: Duration::from_secs(1),
-                    proof_size: 4,
-                    program_hash: "dummy".to_string(),
-                    timestamp: SystemTime::now(),
-                },
-            }),
-            timestamp: 1_725_000_000,
-            nonce: 1,
-            signature: None,
-            fee: None,
-            metadata: None,
-        };
+    /// Load the program for a message's source chain, checking the proof's
+    /// declared program hash against it if
+    /// [`VerifierConfig::require_program_hash`] is set
+    async fn load_message_program(&self, message: &FrostMessage) -> VerificationResult<LoadedProgram> {
+        let program = self.load_program(message.from_chain).await?;
+        self.check_required_program_hash(message, &program.hash)?;
+        Ok(program)
+    }
 
-        // Test verification
-        let result = verifier.verify_message(&message).await;
-        assert!(result.is_ok());
+    /// Check the proof's declared program hash against `program_hash` if
+    /// [`VerifierConfig::require_program_hash`] is set
+    fn check_required_program_hash(&self, message: &FrostMessage, program_hash: &[u8; 32]) -> VerificationResult<()> {
+        if self.config.require_program_hash {
+            self.match_program_hash(message, program_hash)?;
+        }
+        Ok(())
     }
 
-    #[tokio::test]
-    async fn test_batch_verification() {
+    fn match_program_hash(&self, message: &FrostMessage, program_hash: &[u8; 32]) -> VerificationResult<()> {
+        let declared = &message.proof.as_ref()
+            .ok_or(VerificationError::MissingProof)?
+            .metadata
+            .program_hash;
+        let found = self.config.hash_algorithm.parse_digest(declared)
+            .ok_or_else(|| VerificationError::MalformedProgramHash(declared.clone()))?;
+        if found != *program_hash {
+            return Err(VerificationError::ProgramHashMismatch {
+                expected: hex::encode(program_hash),
+                found: hex::encode(found),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verify a message on behalf of a client, charging the client's quota
+    ///
+    /// Fails with [`VerificationError::QuotaExceeded`] without verifying if
+    /// the client has used up its quota. Without a configured
+    /// [`QuotaGuard`] this is the same as [`verify_message`](Self::verify_message).
+    pub async fn verify_message_for_client(
+        &self,
+        client_id: &str,
+        message: &FrostMessage,
+    ) -> VerificationResult<bool> {
+        if let Some(quota) = &self.config.quota {
+            quota.try_acquire(client_id)?;
+        }
+        self.verify_message(message).await
+    }
+
+    /// Verify a message and score confidence in the result
+    ///
+    /// `confirmations` is the depth of the message's source block at
+    /// verification time. The confidence is
+    /// [`FinalityType::confidence`] for the source chain's configured
+    /// finality, or 0.0 if the proof is invalid.
+    pub async fn verify_message_with_confidence(
+        &self,
+        message: &FrostMessage,
+        confirmations: u32,
+    ) -> VerificationResult<(bool, f64)> {
+        let valid = self.verify_message(message).await?;
+        if !valid {
+            return Ok((false, 0.0));
+        }
+        let confidence = self.config.finality
+            .get(&message.from_chain)
+            .map_or(1.0, |finality| finality.confidence(confirmations));
+        Ok((true, confidence))
+    }
+
+    /// Verify a message whose proof must attest to recent state
+    ///
+    /// The proof's block height is read from the message metadata under
+    /// [`PROOF_BLOCK_HEIGHT_KEY`]. Proofs more than `max_lag` blocks behind
+    /// `current_height`, the source chain's latest finalized height, fail
+    /// with [`VerificationError::StaleProof`] before the backend is called;
+    /// heights above `current_height` are rejected as invalid.
+    pub async fn verify_message_with_freshness(
+        &self,
+        message: &FrostMessage,
+        current_height: u64,
+        max_lag: u64,
+    ) -> VerificationResult<bool> {
+        let proof_height = message.proof_block_height().ok_or_else(|| {
+            VerificationError::InvalidFormat(format!("missing or malformed {}", PROOF_BLOCK_HEIGHT_KEY))
+        })?;
+        if proof_height > current_height {
+            return Err(VerificationError::InvalidFormat(format!(
+                "proof attests to block {} above finalized block {}",
+                proof_height, current_height
+            )));
+        }
+        if current_height - proof_height > max_lag {
+            return Err(VerificationError::StaleProof {
+                proof_height,
+                current_height,
+                max_lag,
+            });
+        }
+        self.verify_message(message).await
+    }
+
+    /// Check the payload against the destination chain's format rules
+    fn validate_payload(&self, message: &FrostMessage) -> VerificationResult<()> {
+        if let Some(validators) = &self.config.payload_validators {
+            validators
+                .validate(message.to_chain, &message.payload)
+                .map_err(|e| VerificationError::InvalidFormat(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Check the KZG opening of a blob-carrying message, if configured
+    #[cfg_attr(not(feature = "kzg"), allow(unused_variables))]
+    fn check_kzg(&self, message: &FrostMessage) -> VerificationResult<()> {
+        #[cfg(feature = "kzg")]
+        if let Some(kzg) = &self.config.kzg {
+            kzg.verify_message(message)?;
+        }
+        Ok(())
+    }
+
+    /// Encode the public input for a message
+    fn encode_input(&self, message: &FrostMessage) -> VerificationResult<Vec<u8>> {
+        check_payload_len(message, self.config.max_input_payload_len)?;
+        Ok(InputEncoding::for_message(message)?.encode_with(message, self.input_endianness(message)))
+    }
+
+    /// Describe the public input for a message as labeled segments
+    ///
+    /// The segments are those [`verify_message`](Self::verify_message)
+    /// concatenates into the input, in order, using the message's input
+    /// encoding version and the source chain's configured byte order. For
+    /// diagnosing input mismatches only; errors are those encoding the
+    /// input would fail with.
+    pub fn describe_input(&self, message: &FrostMessage) -> VerificationResult<Vec<(String, Vec<u8>)>> {
+        Ok(self.input_segments(message)?
+            .into_iter()
+            .map(|(label, bytes)| (label.to_string(), bytes))
+            .collect())
+    }
+
+    /// Byte order of integer input fields for the message's source chain
+    fn input_endianness(&self, message: &FrostMessage) -> Endianness {
+        self.config.input_endianness
+            .get(&message.from_chain)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Compute the result cache key for a verification.
+    ///
+    /// The key is `H(program_hash || H(proof) || H(input))` using the
+    /// configured [`HashAlgorithm`], so cache memory is independent of proof
+    /// and input size. Distinct verifications collide only with negligible
+    /// (~2^-128) probability.
+    fn result_cache_key(&self, program_hash: &[u8; 32], proof: &[u8], input: &[u8]) -> [u8; 32] {
+        let algorithm = self.config.hash_algorithm;
+        let proof_hash = algorithm.digest(proof);
+        let input_hash = algorithm.digest(input);
+        algorithm.digest_parts(&[program_hash, &proof_hash, &input_hash])
+    }
+
+    /// Verify both a message's ed25519 signature and its proof
+    ///
+    /// The signature is checked first; if it is invalid the backend is not
+    /// called and `proof_ok` is reported as `false`.
+    #[cfg(feature = "signing")]
+    pub async fn verify_message_full(
+        &self,
+        message: &FrostMessage,
+        public_key: &[u8; 32],
+    ) -> VerificationResult<FullOutcome> {
+        let signature_ok = message.verify_signature(public_key)
+            .map_err(|e| VerificationError::InvalidFormat(e.to_string()))?;
+        if !signature_ok {
+            return Ok(FullOutcome {
+                signature_ok: false,
+                proof_ok: false,
+            });
+        }
+        let proof_ok = self.verify_message(message).await?;
+        Ok(FullOutcome {
+            signature_ok: true,
+            proof_ok,
+        })
+    }
+
+    /// Verify a message, recording every step taken
+    ///
+    /// Behaves like an immediate [`verify_message`](Self::verify_message),
+    /// whatever the configured strategy, but additionally returns a
+    /// [`VerificationTrace`] describing the pre-backend checks, program
+    /// loading, input construction and the backend call. Steps are recorded
+    /// up to the point of failure, starting with the outcome of the
+    /// pre-backend checks; a result served from the result cache has no
+    /// backend call step.
+    pub async fn verify_message_traced(
+        &self,
+        message: &FrostMessage,
+    ) -> (VerificationResult<bool>, VerificationTrace) {
+        let mut trace = VerificationTrace::new(message.id);
+        let result = self.verify_with_trace(message, &mut trace).await;
+        (result, trace)
+    }
+
+    async fn verify_with_trace(
+        &self,
+        message: &FrostMessage,
+        trace: &mut VerificationTrace,
+    ) -> VerificationResult<bool> {
+        let precheck = self.precheck(message);
+        trace.record(TraceStep::ChainValidation {
+            chain_id: message.from_chain,
+            valid: precheck.is_ok(),
+        });
+        let proof = match precheck? {
+            Precheck::Proof(proof) => proof,
+            Precheck::Decided(result) => return Ok(result),
+        };
+
+        let program = self.load_program(message.from_chain).await?;
+        trace.record(TraceStep::ProgramLoad {
+            chain_id: message.from_chain,
+            cache_hit: program.cache_hit,
+            program_hash: program.hash,
+        });
+        self.check_required_program_hash(message, &program.hash)?;
+
+        let segments = self.input_segments(message)?;
+        let layout = segments.iter()
+            .map(|(label, bytes)| (*label, bytes.len()))
+            .collect();
+        trace.record(TraceStep::InputConstruction { layout });
+        let input: Vec<u8> = segments.into_iter().flat_map(|(_, bytes)| bytes).collect();
+
+        self.verify_prepared(message, proof, &program, &input, |program, proof| async move {
+            let started = Instant::now();
+            let result = self.backend.verify(&program, proof, None).await;
+            trace.record(TraceStep::BackendCall {
+                latency: started.elapsed(),
+                outcome: result.as_ref().map(|valid| *valid).map_err(|e| e.to_string()),
+            });
+            result
+        })
+        .await
+    }
+
+    /// Wait for a verification slot, refusing work while the backend is
+    /// overloaded
+    ///
+    /// The backend's queue depth is checked before waiting so callers fail
+    /// fast instead of queueing behind an overwhelmed prover.
+    async fn acquire_permit(&self, chain_id: ChainId) -> VerificationResult<FairPermit> {
+        if let Some(max_depth) = self.config.max_backend_queue_depth {
+            if self.backend.resource_usage().queue_depth > max_depth {
+                return Err(VerificationError::System("backend overloaded".to_string()));
+            }
+        }
+        Ok(self.scheduler.acquire(chain_id).await)
+    }
+
+    /// Report a backend call exceeding [`VerifierConfig::slow_threshold`]
+    fn report_if_slow(&self, message: &FrostMessage, proof: &[u8], elapsed: Duration) {
+        let (Some(threshold), Some(callback)) = (self.config.slow_threshold, &self.config.on_slow_verification) else {
+            return;
+        };
+        if elapsed > threshold {
+            callback.call(&SlowVerification {
+                message_id: message.id,
+                chain_id: message.from_chain,
+                proof_size: proof.len(),
+                elapsed,
+            });
+        }
+    }
+
+    /// Apply the configured [`BackendErrorPolicy`] to a backend error
+    fn handle_backend_error(&self, error: ZkError) -> VerificationResult<bool> {
+        match self.config.on_backend_error {
+            BackendErrorPolicy::Propagate => Err(VerificationError::Backend(error)),
+            BackendErrorPolicy::TreatAsInvalid => Ok(false),
+        }
+    }
+
+    /// Labeled segments of the public input for a message, in encoding order
+    fn input_segments(&self, message: &FrostMessage) -> VerificationResult<Vec<(&'static str, Vec<u8>)>> {
+        check_payload_len(message, self.config.max_input_payload_len)?;
+        Ok(InputEncoding::for_message(message)?.segments_with(message, self.input_endianness(message)))
+    }
+
+    /// Re-verify messages stored as newline-delimited JSON
+    ///
+    /// Each non-empty line is parsed as a [`FrostMessage`] and verified in
+    /// order. Malformed lines do not end the stream: they are reported as
+    /// [`VerificationError::InvalidFormat`] with the line's `id` field if it
+    /// can be recovered, or [`Uuid::nil`] otherwise. Read errors are reported
+    /// as [`VerificationError::System`] with a nil ID.
+    pub fn verify_log<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Stream<Item = (Uuid, VerificationResult<bool>)> + 'a {
+        futures::stream::iter(reader.lines().enumerate())
+            .filter(|(_, line)| std::future::ready(!matches!(line, Ok(line) if line.trim().is_empty())))
+            .then(move |(index, line)| async move {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(e) => {
+                        return (Uuid::nil(), Err(VerificationError::System(format!(
+                            "Failed to read log line {}: {}", index + 1, e
+                        ))));
+                    }
+                };
+                match serde_json::from_str::<FrostMessage>(&line) {
+                    Ok(message) => (message.id, self.verify_message(&message).await),
+                    Err(e) => (recover_message_id(line.as_bytes()), Err(VerificationError::InvalidFormat(format!(
+                        "Malformed log line {}: {}", index + 1, e
+                    )))),
+                }
+            })
+    }
+
+    /// Verify messages delivered as JSON frames, e.g. over a WebSocket
+    ///
+    /// Each frame is parsed as a [`FrostMessage`] and verified as it
+    /// arrives, in order. Errors are reported per frame and do not end the
+    /// stream: malformed frames as [`VerificationError::InvalidFormat`] with
+    /// the frame's `id` field if it can be recovered, and transport errors as
+    /// [`VerificationError::System`] with a nil ID.
+    pub fn verify_frames<'a, S, T, E>(
+        &'a self,
+        frames: S,
+    ) -> impl Stream<Item = (Uuid, VerificationResult<bool>)> + 'a
+    where
+        S: Stream<Item = Result<T, E>> + 'a,
+        T: AsRef<[u8]> + 'a,
+        E: std::fmt::Display + 'a,
+    {
+        frames.enumerate().then(move |(index, frame)| async move {
+            let frame = match frame {
+                Ok(frame) => frame,
+                Err(e) => {
+                    return (Uuid::nil(), Err(VerificationError::System(format!(
+                        "Failed to receive frame {}: {}", index + 1, e
+                    ))));
+                }
+            };
+            match serde_json::from_slice::<FrostMessage>(frame.as_ref()) {
+                Ok(message) => (message.id, self.verify_message(&message).await),
+                Err(e) => (recover_message_id(frame.as_ref()), Err(VerificationError::InvalidFormat(format!(
+                    "Malformed frame {}: {}", index + 1, e
+                )))),
+            }
+        })
+    }
+
+    /// Verify messages as they arrive from a stream
+    ///
+    /// Up to [`batch_concurrency`](Self::batch_concurrency) messages are
+    /// verified at a time, and results are emitted as they complete, which
+    /// may differ from arrival order; each is tagged with its message's
+    /// `id`. Errors are reported per message and do not end the stream. To
+    /// feed the stream from a channel, wrap the receiver with
+    /// [`futures::stream::unfold`].
+    pub fn verify_stream<'a, S>(&'a self, stream: S) -> impl Stream<Item = (Uuid, VerificationResult<bool>)> + 'a
+    where
+        S: Stream<Item = FrostMessage> + 'a,
+    {
+        stream
+            .map(move |message| async move {
+                let result = self.verify_message(&message).await;
+                (message.id, result)
+            })
+            .buffer_unordered(self.batch_concurrency())
+    }
+
+    /// Verify multiple messages in batch
+    ///
+    /// Messages are verified concurrently, up to [`batch_concurrency`](Self::batch_concurrency)
+    /// at a time, and results are in input order. The first error fails the
+    /// whole batch and cancels the verifications still in flight; use
+    /// [`verify_messages_batch_lenient`](Self::verify_messages_batch_lenient)
+    /// to get a result per message instead.
+    pub async fn verify_messages_batch(&self, messages: &[FrostMessage]) -> VerificationResult<Vec<bool>> {
+        futures::stream::iter(messages.iter().map(|message| self.verify_message(message)))
+            .buffered(self.batch_concurrency())
+            .try_collect()
+            .await
+    }
+
+    /// Verify multiple messages in batch, returning one result per message
+    ///
+    /// Like [`verify_messages_batch`](Self::verify_messages_batch), but a
+    /// failing message does not stop the batch: every message is verified
+    /// and its result, or error, is returned in input order.
+    pub async fn verify_messages_batch_lenient(&self, messages: &[FrostMessage]) -> Vec<VerificationResult<bool>> {
+        futures::stream::iter(messages.iter().map(|message| self.verify_message(message)))
+            .buffered(self.batch_concurrency())
+            .collect()
+            .await
+    }
+
+    /// Number of messages a batch verifies concurrently
+    ///
+    /// [`VerifierConfig::max_batch_concurrency`], or the backend's reported
+    /// [`ResourceUsage::max_concurrent`] if unset, capped by
+    /// [`VerifierConfig::max_batch_size`].
+    pub fn batch_concurrency(&self) -> usize {
+        self.config.max_batch_concurrency
+            .unwrap_or_else(|| self.backend.resource_usage().max_concurrent)
+            .min(self.config.max_batch_size)
+            .max(1)
+    }
+
+    /// Verify multiple messages in batch, skipping those already verified
+    ///
+    /// Messages for which `already_verified` returns `true` are not sent to
+    /// the backend and are reported as [`BatchResult::Skipped`], so a batch
+    /// can be retried after a partial failure without redoing prover work.
+    /// To skip a set of IDs, pass `|m| verified_ids.contains(&m.id)`. The
+    /// remaining messages are verified as in
+    /// [`verify_messages_batch`](Self::verify_messages_batch).
+    pub async fn verify_messages_batch_skipping<F>(
+        &self,
+        messages: &[FrostMessage],
+        already_verified: F,
+    ) -> VerificationResult<Vec<BatchResult>>
+    where
+        F: Fn(&FrostMessage) -> bool,
+    {
+        futures::stream::iter(messages.iter().map(|message| {
+            let skipped = already_verified(message);
+            async move {
+                if skipped {
+                    return Ok(BatchResult::Skipped);
+                }
+                self.verify_message(message).await.map(BatchResult::Verified)
+            }
+        }))
+        .buffered(self.batch_concurrency())
+        .try_collect()
+        .await
+    }
+
+    /// Get a snapshot of the verifier metrics
+    pub fn metrics(&self) -> VerifierMetrics {
+        self.metrics.lock().clone()
+    }
+
+    /// Get backend health status
+    pub async fn health_check(&self) -> HealthStatus {
+        self.backend.health_check().await
+    }
+
+    /// Get backend resource usage
+    pub fn resource_usage(&self) -> ResourceUsage {
+        self.backend.resource_usage()
+    }
+
+    /// List the chains whose programs are currently cached
+    ///
+    /// Entries are returned from most to least recently used.
+    pub fn cached_chains(&self) -> Vec<ChainId> {
+        self.program_cache.read().iter().map(|(chain_id, _)| *chain_id).collect()
+    }
+
+    /// List the hashes of the currently cached programs
+    ///
+    /// Entries are returned from most to least recently used.
+    pub fn cached_program_hashes(&self) -> Vec<(ChainId, [u8; 32])> {
+        self.program_cache
+            .read()
+            .iter()
+            .map(|(chain_id, entry)| (*chain_id, entry.program_hash))
+            .collect()
+    }
+
+    /// Total size in bytes of the cached program binaries
+    pub fn cached_program_bytes(&self) -> usize {
+        self.program_cache
+            .read()
+            .iter()
+            .map(|(_, entry)| entry.program_bytes.len())
+            .sum()
+    }
+
+    /// Get the occupancy of the program cache
+    pub fn cache_stats(&self) -> CacheStats {
+        // Summed before taking the lock below, which is not reentrant
+        let bytes = self.cached_program_bytes();
+        let cache = self.program_cache.read();
+        CacheStats {
+            entries: cache.len(),
+            capacity: cache.cap().get(),
+            bytes,
+        }
+    }
+
+    /// Load and cache the programs for the given chains ahead of the first
+    /// verification
+    ///
+    /// Every chain is attempted; if any fail, the failures are returned
+    /// together as [`VerificationError::WarmUpFailed`] while the programs
+    /// that did load stay cached.
+    pub async fn warm_cache(&self, chains: &[ChainId]) -> VerificationResult<()> {
+        let mut failures = Vec::new();
+        for &chain_id in chains {
+            if let Err(e) = self.load_program(chain_id).await {
+                failures.push((chain_id, e));
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(VerificationError::WarmUpFailed(failures))
+        }
+    }
+
+    /// Evict least recently used programs until estimated memory use is at
+    /// most `target_bytes`, returning the number of program bytes freed
+    ///
+    /// Memory use is estimated as the backend's reported
+    /// [`ResourceUsage::memory_usage`] plus the cached program bytes. If the
+    /// backend alone exceeds the target the whole cache is evicted. Programs
+    /// shared through a [`ProgramStore`] are only freed once also removed
+    /// from the store.
+    pub fn trim_to_memory(&self, target_bytes: usize) -> usize {
+        let backend_bytes = self.backend.resource_usage().memory_usage;
+        let mut cache = self.program_cache.write();
+        let mut cached: usize = cache.iter().map(|(_, entry)| entry.program_bytes.len()).sum();
+        let mut freed = 0;
+        while backend_bytes.saturating_add(cached) > target_bytes {
+            let Some((_, entry)) = cache.pop_lru() else {
+                break;
+            };
+            cached -= entry.program_bytes.len();
+            freed += entry.program_bytes.len();
+        }
+        freed
+    }
+
+    /// Capture the program cache for persistence
+    pub fn snapshot(&self) -> CacheSnapshot {
+        let entries = self.program_cache
+            .read()
+            .iter()
+            .map(|(chain_id, entry)| SnapshotEntry {
+                chain_id: *chain_id,
+                program_hash: entry.program_hash,
+                program_bytes: entry.program_bytes.to_vec(),
+                is_dummy: entry.is_dummy,
+            })
+            .collect();
+        CacheSnapshot { entries }
+    }
+
+    /// Restore programs from a snapshot, returning how many were restored
+    ///
+    /// Each entry's hash is recomputed with the configured [`HashAlgorithm`];
+    /// entries whose stored hash does not match (corrupted, tampered, or
+    /// captured with a different algorithm) are discarded. Restored entries
+    /// start a fresh TTL.
+    pub fn restore(&self, snapshot: CacheSnapshot) -> usize {
+        let mut cache = self.program_cache.write();
+        let mut restored = 0;
+        // Insert least recently used first so recency order is preserved
+        for entry in snapshot.entries.into_iter().rev() {
+            if self.config.hash_algorithm.digest(&entry.program_bytes) != entry.program_hash {
+                continue;
+            }
+            cache.put(entry.chain_id, ProgramCacheEntry {
+                program_hash: entry.program_hash,
+                program_bytes: Arc::new(entry.program_bytes),
+                is_dummy: entry.is_dummy,
+                last_used: SystemTime::now(),
+                use_count: 0,
+            });
+            restored += 1;
+        }
+        restored
+    }
+
+    /// Clear program and parameters caches
+    pub async fn clear_cache(&mut self) -> VerificationResult<()> {
+        self.program_cache.write().clear();
+        self.params_cache.write().clear();
+        Ok(())
+    }
+}
+
+impl<B: ParamsBackend> MessageVerifier<B> {
+    /// Verify a message against the trusted setup parameters of its source
+    /// chain
+    ///
+    /// Parameters are fetched from the configured [`ParamsLoader`] on first
+    /// use and cached per chain. Otherwise this behaves like an immediate
+    /// [`verify_message`](Self::verify_message).
+    pub async fn verify_message_with_params(&self, message: &FrostMessage) -> VerificationResult<bool> {
+        let proof = match self.precheck(message)? {
+            Precheck::Proof(proof) => proof,
+            Precheck::Decided(result) => return Ok(result),
+        };
+
+        let program = self.load_message_program(message).await?;
+        let params = self.get_params(message.from_chain).await?;
+        let input = self.encode_input(message)?;
+
+        self.verify_prepared(message, proof, &program, &input, |program, proof| async move {
+            self.backend.verify_with_params(&program, proof, &params, None).await
+        })
+        .await
+    }
+}
+
+impl<B: ChunkedProofBackend> MessageVerifier<B> {
+    /// Verify a message, streaming oversized proofs to the backend in chunks
+    ///
+    /// Proofs up to [`VerifierConfig::chunked_proof_threshold`] bytes are
+    /// passed in a single call. Otherwise this behaves like an immediate
+    /// [`verify_message`](Self::verify_message), including the result
+    /// cache, slow verification reports, session recording and failure
+    /// capture.
+    pub async fn verify_message_chunked(&self, message: &FrostMessage) -> VerificationResult<bool> {
+        let chunk_size = self.config.chunked_proof_threshold.max(1);
+        self.verify_message_using(message, |program, proof| async move {
+            if proof.len() > chunk_size {
+                let chunks = futures::stream::iter(proof.chunks(chunk_size)).boxed();
+                self.backend.verify_chunked(&program, proof.len(), chunks, None).await
+            } else {
+                self.backend.verify(&program, proof, None).await
+            }
+        })
+        .await
+    }
+}
+
+impl<B: ProgressBackend> MessageVerifier<B> {
+    /// Generate a proof for a message, reporting progress to `progress`
+    ///
+    /// The program and public input are those
+    /// [`verify_message`](Self::verify_message) checks the proof against.
+    /// [`ProofPhase::Started`] and [`ProofPhase::Finished`] are always
+    /// emitted around the backend's own events; nothing is emitted after a
+    /// failure. The proof is returned rather than attached to the message.
+    pub async fn prove_message_with_progress(
+        &self,
+        message: &FrostMessage,
+        config: Option<&ZkConfig>,
+        progress: impl Fn(ProofProgress) + Send + Sync,
+    ) -> VerificationResult<Proof> {
+        self.validate_payload(message)?;
+        let program = self.get_program(message.from_chain).await?;
+        let input = self.encode_input(message)?;
+
+        progress(ProofProgress {
+            phase: ProofPhase::Started,
+            percent: Some(0.0),
+        });
+        let (data, metadata) = self.backend
+            .prove_with_progress(&program, &input, config, &progress)
+            .await
+            .map_err(VerificationError::Backend)?;
+        progress(ProofProgress {
+            phase: ProofPhase::Finished,
+            percent: Some(100.0),
+        });
+        Ok(Proof { data, metadata })
+    }
+}
+
+impl<B: PublicInputBackend> MessageVerifier<B> {
+    /// Verify a message against caller-supplied public inputs
+    ///
+    /// The provided bytes are passed to the backend unchanged instead of the
+    /// input derived from the message fields, and are what the result cache,
+    /// session recording and failure capture see. The verification program
+    /// is still selected by the message's source chain. Otherwise this
+    /// behaves like an immediate [`verify_message`](Self::verify_message).
+    pub async fn verify_message_with_inputs(
+        &self,
+        message: &FrostMessage,
+        public_inputs: &[u8],
+    ) -> VerificationResult<bool> {
+        let proof = match self.precheck(message)? {
+            Precheck::Proof(proof) => proof,
+            Precheck::Decided(result) => return Ok(result),
+        };
+        self.verify_prepared_inputs(message, proof, public_inputs).await
+    }
+
+    /// Verify a message that passed the pre-backend checks against
+    /// `public_inputs`
+    async fn verify_prepared_inputs(
+        &self,
+        message: &FrostMessage,
+        proof: &[u8],
+        public_inputs: &[u8],
+    ) -> VerificationResult<bool> {
+        let program = self.load_message_program(message).await?;
+
+        self.verify_prepared(message, proof, &program, public_inputs, |program, proof| async move {
+            self.backend.verify_with_inputs(&program, proof, public_inputs, None).await
+        })
+        .await
+    }
+
+    /// Verify a message whose proof commits to a source block hash
+    ///
+    /// `expected_block_hash` comes from a trusted light client and is
+    /// appended to the message's public input, so the proof only verifies if
+    /// it was generated against that block. A block hash declared in the
+    /// message metadata under [`PROOF_BLOCK_HASH_KEY`] is checked first: a
+    /// malformed one fails with [`VerificationError::InvalidFormat`] and a
+    /// mismatch with [`VerificationError::BlockHashMismatch`], both without a
+    /// backend call.
+    pub async fn verify_message_with_block_hash(
+        &self,
+        message: &FrostMessage,
+        expected_block_hash: [u8; 32],
+    ) -> VerificationResult<bool> {
+        let declared = message.metadata.as_ref()
+            .is_some_and(|metadata| metadata.contains_key(PROOF_BLOCK_HASH_KEY));
+        if declared {
+            let found = message.proof_block_hash().ok_or_else(|| {
+                VerificationError::InvalidFormat(format!("malformed {}", PROOF_BLOCK_HASH_KEY))
+            })?;
+            if found != expected_block_hash {
+                return Err(VerificationError::BlockHashMismatch {
+                    expected: hex::encode(expected_block_hash),
+                    found: hex::encode(found),
+                });
+            }
+        }
+        let proof = match self.precheck(message)? {
+            Precheck::Proof(proof) => proof,
+            Precheck::Decided(result) => return Ok(result),
+        };
+        let mut input = self.encode_input(message)?;
+        input.extend_from_slice(&expected_block_hash);
+        self.verify_prepared_inputs(message, proof, &input).await
+    }
+
+    /// Verify raw proof bytes against a chain's program without a message
+    ///
+    /// This is the entry point beneath
+    /// [`verify_message_with_inputs`](Self::verify_message_with_inputs) for
+    /// proofs that do not belong to a [`FrostMessage`], such as standalone
+    /// finality proofs. No message checks apply; only empty proofs, unless
+    /// [`VerifierConfig::allow_empty_proofs`] is set, and proofs above the
+    /// chain's maximum proof size are rejected.
+    pub async fn verify_raw(
+        &self,
+        chain_id: ChainId,
+        proof: &[u8],
+        public_inputs: &[u8],
+    ) -> VerificationResult<bool> {
+        self.check_proof_size(chain_id, proof)?;
+        let program = self.get_program(chain_id).await?;
+
+        let _permit = self.acquire_permit(chain_id).await?;
+        self.backend
+            .verify_with_inputs(&program, proof, public_inputs, None)
+            .await
+            .or_else(|e| self.handle_backend_error(e))
+    }
+}
+
+impl<B: PublicOutputBackend> MessageVerifier<B> {
+    /// Verify a message and return the proof's public outputs
+    ///
+    /// The outputs are passed through exactly as returned by the backend; see
+    /// [`PublicOutputBackend`] for the encoding. Otherwise this behaves like
+    /// an immediate [`verify_message`](Self::verify_message), except that the
+    /// result cache is only updated, never read, since it holds no outputs.
+    pub async fn verify_message_with_outputs(
+        &self,
+        message: &FrostMessage,
+    ) -> VerificationResult<(bool, Vec<u8>)> {
+        self.verify_message_using(message, |program, proof| async move {
+            self.backend.verify_with_outputs(&program, proof, None).await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use uuid::Uuid;
+
+    // Verifier configuration substituting placeholders for missing programs
+    fn test_config() -> VerifierConfig {
+        VerifierConfig {
+            allow_dummy_programs: true,
+            ..Default::default()
+        }
+    }
+
+    // Mock backend for testing
+    #[derive(Debug)]
+    struct MockBackend;
+    
+    #[async_trait]
+    impl ZkBackend for MockBackend {
+        async fn prove(&self, _program: &[u8], _input: &[u8], _config: Option<&ZkConfig>) -> ZkResult<(Vec<u8>, frostgate_zkip::types::ProofMetadata)> {
+            Ok((vec![], frostgate_zkip::types::ProofMetadata {
+                generation_time: Duration::from_secs(1),
+                proof_size: 4,
+                program_hash: "dummy".to_string(),
+                timestamp: SystemTime::now(),
+            }))
+        }
+
+        async fn verify(&self, _program: &[u8], _proof: &[u8], _config: Option<&ZkConfig>) -> ZkResult<bool> {
+            Ok(true)
+        }
+
+        async fn health_check(&self) -> HealthStatus {
+            HealthStatus::Healthy
+        }
+
+        fn resource_usage(&self) -> ResourceUsage {
+            ResourceUsage {
+                cpu_usage: 0.0,
+                memory_usage: 0,
+                active_tasks: 0,
+                max_concurrent: 1,
+                queue_depth: 0,
+            }
+        }
+    }
+
+    // Mock backend recording the public inputs it was asked to verify
+    #[derive(Debug, Default)]
+    struct RecordingBackend {
+        inputs: parking_lot::Mutex<Vec<Vec<u8>>>,
+        params: parking_lot::Mutex<Vec<Vec<u8>>>,
+        outputs: Vec<u8>,
+        verifications: std::sync::atomic::AtomicUsize,
+        reject: bool,
+    }
+
+    test_backend!(RecordingBackend, |self, _proof| {
+        self.verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(!self.reject)
+    });
+
+    #[async_trait]
+    impl PublicInputBackend for RecordingBackend {
+        async fn verify_with_inputs(
+            &self,
+            _program: &[u8],
+            _proof: &[u8],
+            public_inputs: &[u8],
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<bool> {
+            self.inputs.lock().push(public_inputs.to_vec());
+            Ok(!self.reject)
+        }
+    }
+
+    #[async_trait]
+    impl ParamsBackend for RecordingBackend {
+        async fn verify_with_params(
+            &self,
+            _program: &[u8],
+            _proof: &[u8],
+            params: &[u8],
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<bool> {
+            self.params.lock().push(params.to_vec());
+            Ok(!self.reject)
+        }
+    }
+
+    #[async_trait]
+    impl PublicOutputBackend for RecordingBackend {
+        async fn verify_with_outputs(
+            &self,
+            _program: &[u8],
+            _proof: &[u8],
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<(bool, Vec<u8>)> {
+            self.verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok((!self.reject, self.outputs.clone()))
+        }
+    }
+
+    #[async_trait]
+    impl ChunkedProofBackend for RecordingBackend {
+        async fn verify_chunked(
+            &self,
+            program: &[u8],
+            _proof_len: usize,
+            _chunks: BoxStream<'_, &[u8]>,
+            config: Option<&ZkConfig>,
+        ) -> ZkResult<bool> {
+            self.verify(program, &[], config).await
+        }
+    }
+
+    fn test_message(from_chain: ChainId, to_chain: ChainId, payload: &[u8]) -> FrostMessage {
+        let mut message = FrostMessage::new(from_chain, to_chain, payload.to_vec(), 1, 1_725_000_000);
+        message.proof = Some(crate::messages::Proof {
+            data: vec![1, 2, 3, 4],
+            metadata: ProofMetadata {
+                generation_time: Duration::from_secs(1),
+                proof_size: 4,
+                program_hash: "dummy".to_string(),
+                timestamp: SystemTime::now(),
+            },
+        });
+        message
+    }
+
+    #[tokio::test]
+    async fn test_message_verification() {
         // Create backend and verifier
         let backend = Arc::new(MockBackend);
-        let verifier = MessageVerifier::new(backend);
+        let verifier = MessageVerifier::from_config(backend, test_config());
+
+        // Create test message
+        let message = FrostMessage {
+            id: Uuid::new_v4(),
+            from_chain: ChainId::Ethereum,
+            to_chain: ChainId::Polkadot,
+            payload: b"test".to_vec(),
+            proof: Some(crate::messages::Proof {
+                data: vec![1, 2, 3, 4],
+                metadata: frostgate_zkip::types::ProofMetadata {
+                    generation_time: Duration::from_secs(1),
+                    proof_size: 4,
+                    program_hash: "dummy".to_string(),
+                    timestamp: SystemTime::now(),
+                },
+            }),
+            timestamp: 1_725_000_000,
+            nonce: 1,
+            signature: None,
+            fee: None,
+            metadata: None,
+            attachments: HashMap::new(),
+            canonical_cache: Default::default(),
+        };
+
+        // Test verification
+        let result = verifier.verify_message(&message).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batch_verification() {
+        // Create backend and verifier
+        let backend = Arc::new(MockBackend);
+        let verifier = MessageVerifier::from_config(backend, test_config());
+
+        // Create test messages
+        let messages = vec![
+            FrostMessage {
+                id: Uuid::new_v4(),
+                from_chain: ChainId::Ethereum,
+                to_chain: ChainId::Polkadot,
+                payload: b"test1".to_vec(),
+                proof: Some(crate::messages::Proof {
+                    data: vec![1, 2, 3, 4],
+                    metadata: frostgate_zkip::types::ProofMetadata {
+                        generation_time: Duration::from_secs(1),
+                        proof_size: 4,
+                        program_hash: "dummy".to_string(),
+                        timestamp: SystemTime::now(),
+                    },
+                }),
+                timestamp: 1_725_000_000,
+                nonce: 1,
+                signature: None,
+                fee: None,
+                metadata: None,
+                attachments: HashMap::new(),
+                canonical_cache: Default::default(),
+            },
+            FrostMessage {
+                id: Uuid::new_v4(),
+                from_chain: ChainId::Solana,
+                to_chain: ChainId::Ethereum,
+                payload: b"test2".to_vec(),
+                proof: Some(crate::messages::Proof {
+                    data: vec![5, 6, 7, 8],
+                    metadata: frostgate_zkip::types::ProofMetadata {
+                        generation_time: Duration::from_secs(1),
+                        proof_size: 4,
+                        program_hash: "dummy".to_string(),
+                        timestamp: SystemTime::now(),
+                    },
+                }),
+                timestamp: 1_725_000_001,
+                nonce: 2,
+                signature: None,
+                fee: None,
+                metadata: None,
+                attachments: HashMap::new(),
+                canonical_cache: Default::default(),
+            },
+        ];
+
+        // Test batch verification
+        let results = verifier.verify_messages_batch(&messages).await;
+        assert!(results.is_ok());
+        assert_eq!(results.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_program_cache() {
+        // Create backend and verifier with small cache
+        let backend = Arc::new(MockBackend);
+        let verifier = MessageVerifier::from_config(backend, VerifierConfig {
+            cache_size: 2,
+            cache_ttl: 1,
+            ..test_config()
+        });
+
+        // Test program loading
+        let program1 = verifier.get_program(ChainId::Ethereum).await;
+        assert!(program1.is_ok());
+
+        let program2 = verifier.get_program(ChainId::Polkadot).await;
+        assert!(program2.is_ok());
+
+        let program3 = verifier.get_program(ChainId::Solana).await;
+        assert!(program3.is_ok());
+
+        // Check cache size is maintained
+        assert_eq!(verifier.program_cache.read().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_program_hash_validation() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            require_program_hash: true,
+            ..test_config()
+        });
+        let (_, hash, _) = verifier.program_for(ChainId::Ethereum).await.unwrap();
+        let with_hash = |program_hash: String| {
+            let mut message = test_message(ChainId::Ethereum, ChainId::Solana, b"test");
+            message.proof.as_mut().unwrap().metadata.program_hash = program_hash;
+            message
+        };
+
+        // Matching hash, hex with or without prefix or base64
+        for encoded in [
+            hex::encode(hash),
+            format!("0x{}", hex::encode(hash)),
+            base64::engine::general_purpose::STANDARD.encode(hash),
+        ] {
+            assert!(verifier.verify_message(&with_hash(encoded)).await.unwrap());
+        }
+
+        let mut other = hash;
+        other[0] ^= 1;
+        assert!(matches!(
+            verifier.verify_message(&with_hash(hex::encode(other))).await,
+            Err(VerificationError::ProgramHashMismatch { expected, found })
+                if expected == hex::encode(hash) && found == hex::encode(other)
+        ));
+
+        for malformed in ["dummy", "abcd", &"zz".repeat(32)] {
+            assert!(matches!(
+                verifier.check_program_hash(&with_hash(malformed.to_string())).await,
+                Err(VerificationError::MalformedProgramHash(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_program_registry() {
+        let path = std::env::temp_dir().join(format!("frostgate-program-{}.sp1", Uuid::new_v4()));
+        fs::write(&path, b"eth-program").unwrap();
+        let registry = ProgramRegistry::new().register(ChainId::Ethereum, &path);
+        let verifier = MessageVerifier::with_config_and_registry(
+            Arc::new(MockBackend),
+            VerifierConfig::default(),
+            registry,
+        );
+
+        let (bytes, _, is_dummy) = verifier.program_for(ChainId::Ethereum).await.unwrap();
+        assert_eq!((bytes, is_dummy), (b"eth-program".to_vec(), false));
+
+        // Unregistered chains without a program file fail instead of using a placeholder
+        assert!(matches!(
+            verifier.program_for(ChainId::Solana).await,
+            Err(VerificationError::ProgramNotFound(ChainId::Solana))
+        ));
+        let message = test_message(ChainId::Solana, ChainId::Ethereum, b"test");
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::ProgramNotFound(ChainId::Solana))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_warm_cache() {
+        let eth_path = std::env::temp_dir().join(format!("frostgate-program-{}.sp1", Uuid::new_v4()));
+        let dot_path = std::env::temp_dir().join(format!("frostgate-program-{}.sp1", Uuid::new_v4()));
+        fs::write(&eth_path, b"eth-program").unwrap();
+        fs::write(&dot_path, b"dot-program").unwrap();
+        let registry = ProgramRegistry::new()
+            .register(ChainId::Ethereum, &eth_path)
+            .register(ChainId::Polkadot, &dot_path);
+        let verifier = MessageVerifier::with_config_and_registry(
+            Arc::new(MockBackend),
+            VerifierConfig::default(),
+            registry,
+        );
+
+        let result = verifier
+            .warm_cache(&[ChainId::Ethereum, ChainId::Solana, ChainId::Polkadot])
+            .await;
+        match result {
+            Err(VerificationError::WarmUpFailed(failures)) => {
+                assert_eq!(failures.len(), 1);
+                assert!(matches!(failures[0], (ChainId::Solana, VerificationError::ProgramNotFound(_))));
+            }
+            other => panic!("expected warm-up failure, got {:?}", other),
+        }
+        let stats = verifier.cache_stats();
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.bytes, 22);
+
+        // Preloaded programs are served from cache
+        verifier.program_for(ChainId::Ethereum).await.unwrap();
+        assert_eq!(verifier.metrics().total_loads(), 2);
+        assert!(verifier.warm_cache(&[ChainId::Ethereum, ChainId::Polkadot]).await.is_ok());
+        fs::remove_file(&eth_path).unwrap();
+        fs::remove_file(&dot_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_global_program_store() {
+        let path = std::env::temp_dir().join(format!("frostgate-program-{}.sp1", Uuid::new_v4()));
+        fs::write(&path, b"shared-program").unwrap();
+        let store = ProgramStore::global();
+        let reads = store.reads();
+        let verifier = || {
+            MessageVerifier::with_config_and_registry(
+                Arc::new(MockBackend),
+                VerifierConfig {
+                    program_store: Some(ProgramStore::global()),
+                    ..Default::default()
+                },
+                ProgramRegistry::new().register(ChainId::Ethereum, &path),
+            )
+        };
+        let (first, second) = (verifier(), verifier());
+
+        let (bytes, hash, _) = first.program_for(ChainId::Ethereum).await.unwrap();
+        assert_eq!(second.program_for(ChainId::Ethereum).await.unwrap(), (bytes, hash, false));
+        assert_eq!(store.reads() - reads, 1);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_program_for_reports_dummy_fallback() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+
+        // No program files exist in the test environment
+        let (bytes, hash, is_dummy) = verifier.program_for(ChainId::Ethereum).await.unwrap();
+        assert!(is_dummy);
+        assert_eq!(bytes, vec![0u8; 64]);
+        assert_eq!(hash, HashAlgorithm::default().digest(&bytes));
+
+        // Served from cache with the same flag
+        let (_, cached_hash, cached_dummy) = verifier.program_for(ChainId::Ethereum).await.unwrap();
+        assert_eq!((cached_hash, cached_dummy), (hash, true));
+        assert!(verifier.snapshot().entries[0].is_dummy);
+
+        assert!(matches!(
+            verifier.program_for(ChainId::Unknown).await,
+            Err(VerificationError::InvalidChainId)
+        ));
+    }
+
+    // Mock backend emitting two proving stages
+    #[derive(Debug)]
+    struct StagedBackend;
+
+    test_backend!(StagedBackend, |self, _proof| Ok(true));
+
+    #[async_trait]
+    impl ProgressBackend for StagedBackend {
+        async fn prove_with_progress(
+            &self,
+            program: &[u8],
+            input: &[u8],
+            config: Option<&ZkConfig>,
+            progress: &(dyn Fn(ProofProgress) + Send + Sync),
+        ) -> ZkResult<(Vec<u8>, ProofMetadata)> {
+            for (stage, percent) in [("witness", 25.0), ("prove", 75.0)] {
+                progress(ProofProgress {
+                    phase: ProofPhase::Stage(stage.to_string()),
+                    percent: Some(percent),
+                });
+            }
+            self.prove(program, input, config).await
+        }
+    }
+
+    #[async_trait]
+    impl ProgressBackend for MockBackend {}
+
+    #[tokio::test]
+    async fn test_prove_with_progress() {
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        let events = parking_lot::Mutex::new(Vec::new());
+        let record = |event: ProofProgress| events.lock().push((event.phase, event.percent));
+
+        let verifier = MessageVerifier::from_config(Arc::new(StagedBackend), test_config());
+        let proof = verifier.prove_message_with_progress(&message, None, record).await.unwrap();
+        assert_eq!(proof.metadata.program_hash, "dummy");
+        assert_eq!(*events.lock(), vec![
+            (ProofPhase::Started, Some(0.0)),
+            (ProofPhase::Stage("witness".to_string()), Some(25.0)),
+            (ProofPhase::Stage("prove".to_string()), Some(75.0)),
+            (ProofPhase::Finished, Some(100.0)),
+        ]);
+
+        // Backends without progress fall back to start and finish events
+        events.lock().clear();
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        verifier.prove_message_with_progress(&message, None, record).await.unwrap();
+        let phases: Vec<ProofPhase> = events.lock().drain(..).map(|(phase, _)| phase).collect();
+        assert_eq!(phases, vec![ProofPhase::Started, ProofPhase::Finished]);
+    }
+
+    // Mock backend reporting a fixed queue depth
+    #[derive(Debug)]
+    struct QueuedBackend {
+        queue_depth: usize,
+    }
+
+    test_backend!(QueuedBackend, |self, _proof| Ok(true), |self| ResourceUsage {
+        queue_depth: self.queue_depth,
+        ..testing::idle_usage()
+    });
+
+    #[tokio::test]
+    async fn test_backend_queue_depth_bound() {
+        let config = || VerifierConfig {
+            max_backend_queue_depth: Some(8),
+            ..test_config()
+        };
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let busy = MessageVerifier::from_config(Arc::new(QueuedBackend { queue_depth: 8 }), config());
+        assert!(busy.verify_message(&message).await.unwrap());
+
+        let overloaded = MessageVerifier::from_config(Arc::new(QueuedBackend { queue_depth: 9 }), config());
+        assert!(matches!(
+            overloaded.verify_message(&message).await,
+            Err(VerificationError::System(e)) if e == "backend overloaded"
+        ));
+
+        // Unbounded by default
+        let unbounded = MessageVerifier::from_config(Arc::new(QueuedBackend { queue_depth: 1_000 }), test_config());
+        assert!(unbounded.verify_message(&message).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_adapter_and_verifier_share_message_type() {
+        use crate::traits::{mock::MockAdapter, MessageSubmitter};
+
+        // One FrostMessage flows through both the adapter and the verifier
+        let adapter = MockAdapter::new("mock");
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let tx_id = adapter.submit_message(&message, None).await.unwrap();
+        let submitted = adapter.submitted.lock()[tx_id as usize].clone();
+        assert!(verifier.verify_message(&submitted).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_raw() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+
+        assert!(verifier.verify_raw(ChainId::Solana, &[1, 2, 3], b"finality").await.unwrap());
+        assert_eq!(*backend.inputs.lock(), vec![b"finality".to_vec()]);
+        assert_eq!(verifier.cached_chains(), vec![ChainId::Solana]);
+
+        assert!(matches!(
+            verifier.verify_raw(ChainId::Solana, &[], b"finality").await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            verifier.verify_raw(ChainId::Unknown, &[1], b"finality").await,
+            Err(VerificationError::InvalidChainId)
+        ));
+        assert_eq!(backend.inputs.lock().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trim_to_memory() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        for chain in [ChainId::Ethereum, ChainId::Polkadot, ChainId::Solana] {
+            verifier.get_program(chain).await.unwrap();
+        }
+        // Dummy programs are 64 bytes each
+        assert_eq!(verifier.cached_program_bytes(), 192);
+
+        assert_eq!(verifier.trim_to_memory(192), 0);
+        assert_eq!(verifier.trim_to_memory(100), 128);
+        assert!(verifier.cached_program_bytes() <= 100);
+        assert_eq!(verifier.cached_chains(), vec![ChainId::Solana]);
+
+        assert_eq!(verifier.trim_to_memory(0), 64);
+        assert_eq!(verifier.cached_program_bytes(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cached_program_listing() {
+        let backend = Arc::new(MockBackend);
+        let verifier = MessageVerifier::from_config(backend, test_config());
+
+        let ethereum = verifier.get_program(ChainId::Ethereum).await.unwrap();
+        verifier.get_program(ChainId::Solana).await.unwrap();
+
+        let chains = verifier.cached_chains();
+        assert_eq!(chains.len(), 2);
+        assert!(chains.contains(&ChainId::Ethereum));
+        assert!(chains.contains(&ChainId::Solana));
+
+        let mut hasher = Blake2b512::new();
+        hasher.update(ethereum.as_slice());
+        let hashes = verifier.cached_program_hashes();
+        let (_, ethereum_hash) = hashes.iter().find(|(c, _)| *c == ChainId::Ethereum).unwrap();
+        assert_eq!(&ethereum_hash[..], &hasher.finalize()[..32]);
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_provided_inputs() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let inputs = b"exact-public-inputs".to_vec();
+        assert!(verifier.verify_message_with_inputs(&message, &inputs).await.unwrap());
+        assert_eq!(*backend.inputs.lock(), vec![inputs]);
+    }
+
+    // Backend whose proofs commit to a single source block hash
+    #[derive(Debug)]
+    struct CommittedBlockBackend([u8; 32]);
+
+    test_backend!(CommittedBlockBackend, |self, _proof| Ok(false));
+
+    #[async_trait]
+    impl PublicInputBackend for CommittedBlockBackend {
+        async fn verify_with_inputs(
+            &self,
+            _program: &[u8],
+            _proof: &[u8],
+            public_inputs: &[u8],
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<bool> {
+            Ok(public_inputs.ends_with(&self.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_block_hash() {
+        let block_hash = [0xB1; 32];
+        let verifier = MessageVerifier::from_config(Arc::new(CommittedBlockBackend(block_hash)), test_config());
+        let mut message = test_message(ChainId::Ethereum, ChainId::Solana, b"test");
+
+        assert!(verifier.verify_message_with_block_hash(&message, block_hash).await.unwrap());
+        assert!(!verifier.verify_message_with_block_hash(&message, [0xB2; 32]).await.unwrap());
+
+        // A declared block hash must match the expected one
+        message.metadata.get_or_insert_with(HashMap::new)
+            .insert(PROOF_BLOCK_HASH_KEY.to_string(), format!("0x{}", hex::encode(block_hash)));
+        assert_eq!(message.proof_block_hash(), Some(block_hash));
+        assert!(verifier.verify_message_with_block_hash(&message, block_hash).await.unwrap());
+        assert!(matches!(
+            verifier.verify_message_with_block_hash(&message, [0xB2; 32]).await,
+            Err(VerificationError::BlockHashMismatch { ref found, .. }) if *found == hex::encode(block_hash)
+        ));
+
+        // A declared block hash that does not parse is rejected, not ignored
+        message.metadata.as_mut().unwrap()
+            .insert(PROOF_BLOCK_HASH_KEY.to_string(), "0xb1b1".to_string());
+        assert!(matches!(
+            verifier.verify_message_with_block_hash(&message, block_hash).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_outputs() {
+        let backend = Arc::new(RecordingBackend {
+            outputs: b"state-root".to_vec(),
+            ..Default::default()
+        });
+        let verifier = MessageVerifier::from_config(backend, test_config());
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let (valid, outputs) = verifier.verify_message_with_outputs(&message).await.unwrap();
+        assert!(valid);
+        assert_eq!(outputs, b"state-root");
+    }
+
+    #[tokio::test]
+    async fn test_verification_trace() {
+        let backend = Arc::new(MockBackend);
+        let verifier = MessageVerifier::from_config(backend, test_config());
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let (result, first) = verifier.verify_message_traced(&message).await;
+        assert!(result.unwrap());
+        let (result, second) = verifier.verify_message_traced(&message).await;
+        assert!(result.unwrap());
+
+        assert_eq!(first.program_cache_hit(), Some(false));
+        assert_eq!(second.program_cache_hit(), Some(true));
+        assert_eq!(first.steps.len(), 4);
+        assert!(matches!(
+            second.steps.last(),
+            Some(TraceStep::BackendCall { outcome: Ok(true), .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verification_trace_records_precheck_failure() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            sender_allowlist: SenderAllowlist::new().allow_sender(ChainId::Ethereum, "0xbridge"),
+            ..test_config()
+        });
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let (result, trace) = verifier.verify_message_traced(&message).await;
+        assert!(matches!(result, Err(VerificationError::UnauthorizedSender { .. })));
+        assert_eq!(trace.steps, vec![TraceStep::ChainValidation {
+            chain_id: ChainId::Ethereum,
+            valid: false,
+        }]);
+    }
+
+    #[tokio::test]
+    async fn test_result_cache() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            result_cache_size: 10,
+            hash_algorithm: HashAlgorithm::Sha256,
+            ..test_config()
+        });
+
+        let first = test_message(ChainId::Ethereum, ChainId::Polkadot, b"first");
+        let second = test_message(ChainId::Ethereum, ChainId::Polkadot, b"second");
+
+        // Distinct messages miss
+        assert!(verifier.verify_message(&first).await.unwrap());
+        assert!(verifier.verify_message(&second).await.unwrap());
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // Identical verification hits
+        assert!(verifier.verify_message(&first.clone()).await.unwrap());
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(verifier.result_cache.as_ref().unwrap().read().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_payload_validation() {
+        let backend = Arc::new(MockBackend);
+        let verifier = MessageVerifier::from_config(backend, VerifierConfig {
+            payload_validators: Some(PayloadValidators::with_defaults()),
+            ..test_config()
+        });
+
+        let valid = test_message(ChainId::Polkadot, ChainId::Ethereum, &[0u8; 36]);
+        assert!(verifier.verify_message(&valid).await.unwrap());
+
+        let malformed = test_message(ChainId::Polkadot, ChainId::Ethereum, &[0u8; 10]);
+        assert!(matches!(
+            verifier.verify_message(&malformed).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+    }
+
+    // Mock backend rejecting every proof
+    #[derive(Debug)]
+    struct RejectingBackend;
+
+    test_backend!(RejectingBackend, |self, _proof| Ok(false));
+
+    // Mock backend failing every verification
+    #[derive(Debug)]
+    struct FailingBackend;
+
+    test_backend!(FailingBackend, |self, _proof| Err(ZkError::Backend("prover unavailable".to_string())));
+
+    #[tokio::test]
+    async fn test_backend_error_policy() {
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        let verifier = MessageVerifier::from_config(Arc::new(FailingBackend), test_config());
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::Backend(_))
+        ));
+
+        let verifier = MessageVerifier::from_config(Arc::new(FailingBackend), VerifierConfig {
+            on_backend_error: BackendErrorPolicy::TreatAsInvalid,
+            ..test_config()
+        });
+        assert!(!verifier.verify_message(&message).await.unwrap());
+        let (result, _) = verifier.verify_message_traced(&message).await;
+        assert!(!result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_capture_failures() {
+        let dir = std::env::temp_dir().join(format!("frostgate-counterexamples-{}", Uuid::new_v4()));
+        let verifier = MessageVerifier::from_config(Arc::new(RejectingBackend), VerifierConfig {
+            capture_failures: true,
+            counterexample_dir: Some(dir.clone()),
+            ..test_config()
+        });
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        assert!(!verifier.verify_message(&message).await.unwrap());
+        let failures = verifier.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].message_id, message.id);
+        assert_eq!(failures[0].chain_id, ChainId::Ethereum);
+        assert_eq!(failures[0].proof, vec![1, 2, 3, 4]);
+        assert_eq!(failures[0].input, verifier.encode_input(&message).unwrap());
+
+        // Repeated failures of a message are saved to separate files
+        assert!(!verifier.verify_message(&message).await.unwrap());
+        let mut saved: Vec<Counterexample> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| Counterexample::load(entry.unwrap().path()).unwrap())
+            .collect();
+        saved.sort_by_key(|counterexample| counterexample.captured_at);
+        assert_eq!(saved, verifier.failures());
+        assert_eq!(saved.len(), 2);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(verifier.take_failures().len(), 2);
+        assert!(verifier.failures().is_empty());
+
+        // Nothing is captured unless enabled
+        let verifier = MessageVerifier::from_config(Arc::new(RejectingBackend), test_config());
+        assert!(!verifier.verify_message(&message).await.unwrap());
+        assert!(verifier.failures().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_captured_failures_bounded() {
+        let verifier = MessageVerifier::from_config(Arc::new(RejectingBackend), VerifierConfig {
+            capture_failures: true,
+            max_captured_failures: 2,
+            ..test_config()
+        });
+        let messages: Vec<_> = (0..3u8)
+            .map(|i| test_message(ChainId::Ethereum, ChainId::Polkadot, &[i]))
+            .collect();
+        for message in &messages {
+            assert!(!verifier.verify_message(message).await.unwrap());
+        }
+
+        let kept: Vec<_> = verifier.failures().iter().map(|failure| failure.message_id).collect();
+        assert_eq!(kept, vec![messages[1].id, messages[2].id]);
+    }
+
+    #[tokio::test]
+    async fn test_session_record_and_replay() {
+        let recorder = Arc::new(SessionRecorder::new());
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            session_recorder: Some(recorder.clone()),
+            ..test_config()
+        });
+
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        verifier.verify_message(&message).await.unwrap();
+        verifier.verify_message(&test_message(ChainId::Solana, ChainId::Ethereum, b"other")).await.unwrap();
+
+        let mut file = Vec::new();
+        recorder.write_to(&mut file).unwrap();
+        let entries = SessionReplayer::<MockBackend>::read_from(&file[..]).unwrap();
+        assert_eq!(entries, recorder.entries());
+        assert_eq!(entries[0].message_id, message.id);
+        assert_eq!(entries[0].input, verifier.encode_input(&message).unwrap());
+
+        // Same backend behavior replays cleanly
+        let report = SessionReplayer::new(Arc::new(MockBackend)).replay(&entries).await;
+        assert_eq!(report.total, 2);
+        assert!(report.is_clean());
+
+        // A regressed backend is reported
+        let report = SessionReplayer::new(Arc::new(RejectingBackend)).replay(&entries).await;
+        assert_eq!(report.mismatches.len(), 2);
+        assert_eq!(report.mismatches[0].replayed, Ok(false));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_single_flight_program_load() {
+        let verifier = Arc::new(MessageVerifier::from_config(Arc::new(MockBackend), test_config()));
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for i in 0..32 {
+            let verifier = verifier.clone();
+            let chain_id = if i % 2 == 0 { ChainId::Ethereum } else { ChainId::Solana };
+            tasks.spawn(async move { verifier.get_program(chain_id).await });
+        }
+        while let Some(result) = tasks.join_next().await {
+            assert!(result.unwrap().is_ok());
+        }
+
+        // One load per chain despite concurrent misses
+        assert_eq!(verifier.metrics().total_loads(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_restore() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        verifier.get_program(ChainId::Ethereum).await.unwrap();
+        verifier.get_program(ChainId::Solana).await.unwrap();
+
+        let encoded = serde_json::to_string(&verifier.snapshot()).unwrap();
+        let snapshot: CacheSnapshot = serde_json::from_str(&encoded).unwrap();
+
+        let restored = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        assert_eq!(restored.restore(snapshot.clone()), 2);
+        assert_eq!(restored.snapshot(), snapshot);
+        restored.get_program(ChainId::Ethereum).await.unwrap();
+        assert_eq!(restored.metrics().total_loads(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_tampered_entry() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        verifier.get_program(ChainId::Ethereum).await.unwrap();
+        verifier.get_program(ChainId::Solana).await.unwrap();
+
+        let mut snapshot = verifier.snapshot();
+        snapshot.entries[0].program_bytes[0] ^= 0xFF;
+        let tampered_chain = snapshot.entries[0].chain_id;
+
+        let restored = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        assert_eq!(restored.restore(snapshot), 1);
+        assert!(!restored.cached_chains().contains(&tampered_chain));
+    }
+
+    #[tokio::test]
+    async fn test_input_encoding_version_selection() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        // Absent version uses the current encoder
+        let (_, trace) = verifier.verify_message_traced(&message).await;
+        assert_eq!(trace.input_len(), Some(InputEncoding::CURRENT.encode(&message).len()));
+
+        let mut metadata = HashMap::new();
+        metadata.insert(crate::messages::INPUT_ENCODING_VERSION_KEY.to_string(), "1".to_string());
+        message.metadata = Some(metadata);
+        let (_, trace) = verifier.verify_message_traced(&message).await;
+        assert!(matches!(&trace.steps[2], TraceStep::InputConstruction { layout } if layout[0].0 == "from_chain"));
+
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::INPUT_ENCODING_VERSION_KEY.to_string(), "2".to_string());
+        let (_, trace) = verifier.verify_message_traced(&message).await;
+        assert!(matches!(&trace.steps[2], TraceStep::InputConstruction { layout } if layout[0].0 == "id"));
+        assert_eq!(
+            verifier.encode_input(&message).unwrap(),
+            [message.id.as_bytes().as_slice(), &InputEncoding::V1.encode(&message)].concat()
+        );
+
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::INPUT_ENCODING_VERSION_KEY.to_string(), "9".to_string());
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_input_payload_length_cap() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            max_input_payload_len: 8,
+            ..test_config()
+        });
+
+        let consistent = test_message(ChainId::Ethereum, ChainId::Polkadot, &[7; 8]);
+        assert!(verifier.verify_message(&consistent).await.unwrap());
+        let input = verifier.encode_input(&consistent).unwrap();
+        assert_eq!(&input[16..24], &8u64.to_be_bytes());
+        assert_eq!(&input[24..32], &[7; 8]);
+
+        let oversized = test_message(ChainId::Ethereum, ChainId::Polkadot, &[7; 9]);
+        assert!(matches!(
+            verifier.verify_message(&oversized).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+        let (result, _) = verifier.verify_message_traced(&oversized).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_incompatible_protocol_version_rejected() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::PROTOCOL_VERSION_KEY.to_string(), "1.9".to_string());
+        assert!(verifier.verify_message(&message).await.unwrap());
+
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::PROTOCOL_VERSION_KEY.to_string(), "2.0".to_string());
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "kzg")]
+    #[tokio::test]
+    async fn test_kzg_opening_checked_before_proof() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            kzg: Some(Arc::new(KzgVerifier::ethereum())),
+            ..test_config()
+        });
+        let blob = kzg::tests::blob(3);
+        let (commitment, proof) = kzg::tests::open(&blob);
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, &blob);
+        message.set_attachment(KZG_COMMITMENT_ATTACHMENT, commitment);
+        message.set_attachment(KZG_PROOF_ATTACHMENT, proof);
+
+        assert!(verifier.verify_message(&message).await.unwrap());
+
+        message.set_payload(kzg::tests::blob(4));
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::Kzg(KzgError::InvalidOpening))
+        ));
+        message.remove_attachment(KZG_PROOF_ATTACHMENT);
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::Kzg(KzgError::MissingAttachment(KZG_PROOF_ATTACHMENT)))
+        ));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    // Mock backend recording the chunk sizes of streamed proofs
+    #[derive(Debug, Default)]
+    struct ChunkRecordingBackend {
+        single_calls: std::sync::atomic::AtomicUsize,
+        chunks: parking_lot::Mutex<Vec<usize>>,
+    }
+
+    test_backend!(ChunkRecordingBackend, |self, _proof| {
+        self.single_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(true)
+    });
+
+    #[async_trait]
+    impl ChunkedProofBackend for ChunkRecordingBackend {
+        async fn verify_chunked(
+            &self,
+            _program: &[u8],
+            proof_len: usize,
+            chunks: BoxStream<'_, &[u8]>,
+            _config: Option<&ZkConfig>,
+        ) -> ZkResult<bool> {
+            let sizes: Vec<usize> = chunks.map(<[u8]>::len).collect().await;
+            let received = sizes.iter().sum::<usize>();
+            self.chunks.lock().extend(sizes);
+            Ok(received == proof_len)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oversized_proof_streamed_in_chunks() {
+        let backend = Arc::new(ChunkRecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            chunked_proof_threshold: 4,
+            ..test_config()
+        });
+
+        // At the threshold the proof goes in a single call
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        assert!(verifier.verify_message_chunked(&message).await.unwrap());
+        assert_eq!(backend.single_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(backend.chunks.lock().is_empty());
+
+        message.proof.as_mut().unwrap().data = vec![7; 10];
+        assert!(verifier.verify_message_chunked(&message).await.unwrap());
+        assert_eq!(*backend.chunks.lock(), vec![4, 4, 2]);
+        assert_eq!(backend.single_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_verification_bookkeeping() {
+        let backend = Arc::new(ChunkRecordingBackend::default());
+        let recorder = Arc::new(SessionRecorder::new());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            chunked_proof_threshold: 4,
+            result_cache_size: 8,
+            session_recorder: Some(recorder.clone()),
+            ..test_config()
+        });
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        message.proof.as_mut().unwrap().data = vec![7; 10];
+
+        assert!(verifier.verify_message_chunked(&message).await.unwrap());
+        assert!(verifier.verify_message_chunked(&message).await.unwrap());
+        assert_eq!(*backend.chunks.lock(), vec![4, 4, 2]);
+        assert_eq!(recorder.entries().len(), 1);
+        assert_eq!(recorder.entries()[0].message_id, message.id);
+
+        // Results are shared with the single-call path
+        assert!(verifier.verify_message(&message).await.unwrap());
+        assert_eq!(backend.single_calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_program_proof() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        let programs = [b"eth-v1".to_vec(), b"eth-v2".to_vec(), b"dot-v1".to_vec()];
+        let tree = MerkleTree::from_programs(&programs, HashAlgorithm::default());
+
+        let proof = tree.proof(1).unwrap();
+        assert!(verifier
+            .verify_message_with_program_proof(&message, &programs[1], &proof, &tree.root(), tree.height())
+            .await
+            .unwrap());
+
+        // A program outside the set fails even with a proof for another leaf
+        let result = verifier
+            .verify_message_with_program_proof(&message, b"rogue", &proof, &tree.root(), tree.height())
+            .await;
+        assert!(matches!(result, Err(VerificationError::UncommittedProgram(_))));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_sender_rejected() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            sender_allowlist: SenderAllowlist::new().allow_sender(ChainId::Ethereum, "0xbridge"),
+            ..test_config()
+        });
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        let metadata = message.metadata.as_mut().unwrap();
+        metadata.insert(crate::messages::SENDER_KEY.to_string(), "0xbridge".to_string());
+        assert!(verifier.verify_message(&message).await.unwrap());
+
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::SENDER_KEY.to_string(), "0xmallory".to_string());
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::UnauthorizedSender { .. })
+        ));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_route_requirements() {
+        let backend = Arc::new(RecordingBackend::default());
+        let routes = RouteRequirements::new()
+            .require(ChainId::Ethereum, ChainId::Polkadot, RequiredArtifacts::Proof)
+            .require(ChainId::Solana, ChainId::Ethereum, RequiredArtifacts::Signature);
+        #[cfg(feature = "signing")]
+        let signer = crate::messages::LocalSigner::from_bytes(&[3u8; 32]);
+        #[cfg(feature = "signing")]
+        let routes = routes.trust_signer(ChainId::Solana, ChainId::Ethereum, signer.public_key());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            route_requirements: routes,
+            ..test_config()
+        });
+
+        // Proof-required route
+        let mut proven = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        assert!(verifier.verify_message(&proven).await.unwrap());
+        proven.proof = None;
+        assert!(matches!(verifier.verify_message(&proven).await, Err(VerificationError::MissingProof)));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // Signature-only route never reaches the backend
+        let trusted = FrostMessage::new(ChainId::Solana, ChainId::Ethereum, b"test".to_vec(), 1, 1_725_000_000);
+        #[cfg(feature = "signing")]
+        {
+            assert!(!verifier.verify_message(&trusted).await.unwrap());
+            let mut signed = trusted.clone();
+            signer.sign_message(&mut signed);
+            assert!(verifier.verify_message(&signed).await.unwrap());
+            crate::messages::LocalSigner::from_bytes(&[4u8; 32]).sign_message(&mut signed);
+            assert!(!verifier.verify_message(&signed).await.unwrap());
+        }
+        #[cfg(not(feature = "signing"))]
+        assert!(matches!(verifier.verify_message(&trusted).await, Err(VerificationError::System(_))));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_proof_system_envelope() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            proof_systems: HashMap::from([(ChainId::Ethereum, ProofSystem::new("sp1", 1))]),
+            capture_failures: true,
+            ..test_config()
+        });
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        // Untagged proofs fail on a chain with an expected proof system
+        assert!(matches!(
+            verifier.verify_message(&message).await,
+            Err(VerificationError::ProofSystemMismatch { .. })
+        ));
+
+        message.proof.as_mut().unwrap().data = SerializedProof::new("sp1", 1, vec![1, 2, 3, 4]).encode().unwrap();
+        assert_eq!(verifier.message_proof(&message).unwrap(), &[1, 2, 3, 4]);
+        assert!(verifier.verify_message(&message).await.unwrap());
+
+        message.proof.as_mut().unwrap().data = SerializedProof::new("sp1", 2, vec![1, 2, 3, 4]).encode().unwrap();
+        let result = verifier.verify_message(&message).await;
+        assert!(matches!(
+            result,
+            Err(VerificationError::ProofSystemMismatch { ref expected, ref found }) if expected == "sp1 v1" && found == "sp1 v2"
+        ));
+
+        // Other chains' proofs are passed through, even if they look enveloped
+        let mut other = test_message(ChainId::Solana, ChainId::Polkadot, b"test");
+        let enveloped = SerializedProof::new("groth16", 7, vec![5]).encode().unwrap();
+        other.proof.as_mut().unwrap().data = enveloped.clone();
+        assert_eq!(verifier.message_proof(&other).unwrap(), enveloped.as_slice());
+        other.proof.as_mut().unwrap().data = b"FGPE\xff".to_vec();
+        assert_eq!(verifier.message_proof(&other).unwrap(), b"FGPE\xff");
+    }
+
+    #[tokio::test]
+    async fn test_program_load_metrics() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+
+        // Program files are absent in the test environment
+        verifier.get_program(ChainId::Ethereum).await.unwrap();
+        verifier.get_program(ChainId::Ethereum).await.unwrap();
+        verifier.get_program(ChainId::Solana).await.unwrap();
+
+        let metrics = verifier.metrics();
+        assert_eq!(metrics.total_loads(), 2);
+        assert_eq!(metrics.total_dummy_fallbacks(), 2);
+        let ethereum = &metrics.program_loads[&ChainId::Ethereum];
+        assert_eq!((ethereum.cache_hits, ethereum.cache_misses), (1, 1));
+        assert_eq!(ethereum.cache_hit_ratio(), 0.5);
+        assert_eq!(ethereum.dummy_fallbacks, 1);
+    }
+
+    #[cfg(feature = "signing")]
+    #[tokio::test]
+    async fn test_verify_message_full() {
+        use crate::messages::LocalSigner;
 
-        // Create test messages
-        let messages = vec![
-            FrostMessage {
-                id: Uuid::new_v4(),
-                from_chain: ChainId::Ethereum,
-                to_chain: ChainId::Polkadot,
-                payload: b"test1".to_vec(),
-                proof: Some(crate::messages::Proof {
-                    data: vec![1, 2, 3, 4],
-                    metadata: frostgate_zkip::types::ProofMetadata {
-                        generation_time: Duration::from_secs(1),
-                        proof_size: 4,
-                        program_hash: "dummy".to_string(),
-                        timestamp: SystemTime::now(),
-                    },
-                }),
-                timestamp: 1_725_000_000,
-                nonce: 1,
-                signature: None,
-                fee: None,
-                metadata: None,
-            },
-            FrostMessage {
-                id: Uuid::new_v4(),
-                from_chain: ChainId::Solana,
-                to_chain: ChainId::Ethereum,
-                payload: b"test2".to_vec(),
-                proof: Some(crate::messages::Proof {
-                    data: vec![5, 6, 7, 8],
-                    metadata: frostgate_zkip::types::ProofMetadata {
-                        generation_time: Duration::from_secs(1),
-                        proof_size: 4,
-                        program_hash: "dummy".to_string(),
-                        timestamp: SystemTime::now(),
-                    },
-                }),
-                timestamp: 1_725_000_001,
-                nonce: 2,
-                signature: None,
-                fee: None,
-                metadata: None,
-            },
+        let signer = LocalSigner::from_bytes(&[7u8; 32]);
+        let mut signed = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        signed.sign_with(&signer).await.unwrap();
+        let mut forged = signed.clone();
+        forged.sign_with(&LocalSigner::from_bytes(&[8u8; 32])).await.unwrap();
+        let key = signer.public_key();
+
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let outcome = verifier.verify_message_full(&signed, &key).await.unwrap();
+        assert_eq!(outcome, FullOutcome { signature_ok: true, proof_ok: true });
+        assert!(outcome.is_valid());
+
+        let rejecting = MessageVerifier::from_config(Arc::new(RejectingBackend), test_config());
+        let outcome = rejecting.verify_message_full(&signed, &key).await.unwrap();
+        assert_eq!(outcome, FullOutcome { signature_ok: true, proof_ok: false });
+
+        // An invalid signature short-circuits before the backend call
+        let outcome = verifier.verify_message_full(&forged, &key).await.unwrap();
+        assert_eq!(outcome, FullOutcome { signature_ok: false, proof_ok: false });
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let outcome = rejecting.verify_message_full(&forged, &key).await.unwrap();
+        assert_eq!(outcome, FullOutcome { signature_ok: false, proof_ok: false });
+        assert!(!outcome.is_valid());
+    }
+
+    // Mock backend that is slow for proofs starting with 0xFF and records
+    // the order in which verifications complete
+    #[derive(Debug, Default)]
+    struct SlowBackend {
+        completed: parking_lot::Mutex<Vec<bool>>,
+    }
+
+    test_backend!(SlowBackend, |self, proof| {
+        let slow = proof.first() == Some(&0xFF);
+        tokio::time::sleep(Duration::from_millis(if slow { 20 } else { 1 })).await;
+        self.completed.lock().push(slow);
+        Ok(true)
+    });
+
+    #[tokio::test]
+    async fn test_slow_verification_reported() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = reported.clone();
+        let verifier = MessageVerifier::from_config(Arc::new(SlowBackend::default()), VerifierConfig {
+            slow_threshold: Some(Duration::from_millis(15)),
+            on_slow_verification: Some(SlowVerificationCallback::new(move |slow| sink.lock().push(slow.clone()))),
+            ..test_config()
+        });
+
+        let mut slow = test_message(ChainId::Ethereum, ChainId::Polkadot, b"slow");
+        slow.proof.as_mut().unwrap().data = vec![0xFF; 6];
+        let fast = test_message(ChainId::Solana, ChainId::Polkadot, b"fast");
+        assert!(verifier.verify_message(&fast).await.unwrap());
+        assert!(verifier.verify_message(&slow).await.unwrap());
+
+        let reported = reported.lock();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].message_id, slow.id);
+        assert_eq!((reported[0].chain_id, reported[0].proof_size), (ChainId::Ethereum, 6));
+        assert!(reported[0].elapsed >= Duration::from_millis(15));
+    }
+
+    // Mock backend tracking how many verifications run at once; proofs
+    // starting with an odd byte are valid
+    #[derive(Debug, Default)]
+    struct ConcurrencyBackend {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    test_backend!(ConcurrencyBackend, |self, proof| {
+        use std::sync::atomic::Ordering;
+        let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(proof[0] % 2 == 1)
+    });
+
+    #[tokio::test]
+    async fn test_batch_size_bounds_concurrency() {
+        let backend = Arc::new(ConcurrencyBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            max_batch_size: 3,
+            max_batch_concurrency: Some(16),
+            max_concurrent_verifications: 16,
+            ..test_config()
+        });
+        let messages: Vec<FrostMessage> = (0..8u8)
+            .map(|i| {
+                let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, &[i]);
+                message.proof.as_mut().unwrap().data = vec![i];
+                message
+            })
+            .collect();
+
+        let results = verifier.verify_messages_batch(&messages).await.unwrap();
+        let expected: Vec<bool> = (0..8u8).map(|i| i % 2 == 1).collect();
+        assert_eq!(results, expected);
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_batch_concurrency() {
+        let messages: Vec<FrostMessage> = (0..12u8)
+            .map(|i| {
+                let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, &[i]);
+                message.proof.as_mut().unwrap().data = vec![i];
+                message
+            })
+            .collect();
+        let expected: Vec<bool> = (0..12u8).map(|i| i % 2 == 1).collect();
+
+        // Defaults to the backend's reported limit
+        let backend = Arc::new(ConcurrencyBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        assert_eq!(verifier.batch_concurrency(), 1);
+        assert_eq!(verifier.verify_messages_batch(&messages).await.unwrap(), expected);
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let backend = Arc::new(ConcurrencyBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            max_batch_concurrency: Some(4),
+            max_concurrent_verifications: 16,
+            ..test_config()
+        });
+        assert_eq!(verifier.verify_messages_batch(&messages).await.unwrap(), expected);
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 4);
+
+        // A failing message fails the batch, but not the lenient variant
+        let mut failing = messages.clone();
+        failing[5].proof.as_mut().unwrap().data.clear();
+        assert!(verifier.verify_messages_batch(&failing).await.is_err());
+        let results = verifier.verify_messages_batch_lenient(&failing).await;
+        assert_eq!(results.len(), 12);
+        for (i, result) in results.into_iter().enumerate() {
+            match i {
+                5 => assert!(matches!(result, Err(VerificationError::InvalidFormat(_)))),
+                _ => assert_eq!(result.unwrap(), expected[i]),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lenient_batch_reports_missing_proof() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        let mut messages: Vec<FrostMessage> = (0..4u8)
+            .map(|i| test_message(ChainId::Ethereum, ChainId::Solana, &[i]))
+            .collect();
+        messages[1].proof = None;
+
+        let results = verifier.verify_messages_batch_lenient(&messages).await;
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[1], Err(VerificationError::MissingProof)));
+        for i in [0, 2, 3] {
+            assert!(matches!(results[i], Ok(true)));
+        }
+        assert!(matches!(
+            verifier.verify_messages_batch(&messages).await,
+            Err(VerificationError::MissingProof)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fair_scheduling_across_chains() {
+        let backend = Arc::new(SlowBackend::default());
+        let verifier = Arc::new(MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            max_concurrent_verifications: 1,
+            ..test_config()
+        }));
+
+        let mut slow = test_message(ChainId::Ethereum, ChainId::Polkadot, b"slow");
+        slow.proof.as_mut().unwrap().data = vec![0xFF; 4];
+        let fast = test_message(ChainId::Solana, ChainId::Polkadot, b"fast");
+
+        // Queue the slow chain's messages first, then the fast chain's
+        let mut tasks = tokio::task::JoinSet::new();
+        for message in std::iter::repeat_n(slow, 6).chain(std::iter::repeat_n(fast, 3)) {
+            let verifier = verifier.clone();
+            tasks.spawn(async move { verifier.verify_message(&message).await.unwrap() });
+            tokio::task::yield_now().await;
+        }
+        while let Some(result) = tasks.join_next().await {
+            assert!(result.unwrap());
+        }
+
+        // The fast chain completes before the slow chain's backlog drains
+        let completed = backend.completed.lock();
+        assert_eq!(completed.len(), 9);
+        let last_fast = completed.iter().rposition(|slow| !slow).unwrap();
+        assert!(last_fast < 8, "fast chain starved: {:?}", *completed);
+    }
+
+    #[tokio::test]
+    async fn test_input_endianness_per_chain() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            input_endianness: HashMap::from([(ChainId::Solana, Endianness::Little)]),
+            ..test_config()
+        });
+        let be = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        let le = test_message(ChainId::Solana, ChainId::Polkadot, b"test");
+
+        let be_input = verifier.encode_input(&be).unwrap();
+        let le_input = verifier.encode_input(&le).unwrap();
+        assert_eq!(be_input, InputEncoding::V1.encode(&be));
+        assert_eq!(le_input, InputEncoding::V1.encode_with(&le, Endianness::Little));
+
+        // Integer fields are byte-reversed; the payload is unchanged
+        assert_eq!(&be_input[16..24], &4u64.to_be_bytes());
+        assert_eq!(&le_input[..8], &ChainId::Solana.to_u64().to_le_bytes());
+        assert_eq!(&le_input[16..24], &4u64.to_le_bytes());
+        assert_eq!(&le_input[24..28], b"test");
+        assert_eq!(&le_input[28..36], &1u64.to_le_bytes());
+        assert_eq!(&le_input[36..], &1_725_000_000u64.to_le_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_empty_proof_rejected() {
+        let backend = Arc::new(RecordingBackend::default());
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        message.proof.as_mut().unwrap().data.clear();
+
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let result = verifier.verify_message(&message).await;
+        assert!(matches!(result, Err(VerificationError::InvalidFormat(ref e)) if e == "empty proof"));
+        let (result, trace) = verifier.verify_message_traced(&message).await;
+        assert!(matches!(result, Err(VerificationError::InvalidFormat(_))));
+        assert!(matches!(trace.steps[..], [TraceStep::ChainValidation { valid: false, .. }]));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        let permissive = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            allow_empty_proofs: true,
+            ..test_config()
+        });
+        assert!(permissive.verify_message(&message).await.unwrap());
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_proof_size_limit() {
+        let backend = Arc::new(RecordingBackend::default());
+        let capabilities = ChainCapabilities {
+            supports_smart_contracts: true,
+            supports_native_tokens: true,
+            supports_onchain_verification: true,
+            max_message_size: 4096,
+            proof_types: vec!["groth16".to_string()],
+            finality_type: FinalityType::Deterministic,
+            max_proof_size: Some(8),
+            supports_parallel_execution: false,
+            features: HashMap::new(),
+        };
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            chain_capabilities: HashMap::from([(ChainId::Ethereum, capabilities)]),
+            ..test_config()
+        });
+        let with_proof = |from_chain, len| {
+            let mut message = test_message(from_chain, ChainId::Polkadot, b"test");
+            message.proof.as_mut().unwrap().data = vec![1; len];
+            message
+        };
+
+        // Under and at the limit
+        assert!(verifier.verify_message(&with_proof(ChainId::Ethereum, 4)).await.unwrap());
+        assert!(verifier.verify_message(&with_proof(ChainId::Ethereum, 8)).await.unwrap());
+
+        // Over the limit, rejected before the backend is called
+        assert!(matches!(
+            verifier.verify_message(&with_proof(ChainId::Ethereum, 9)).await,
+            Err(VerificationError::InvalidFormat(ref e)) if e.contains("exceeds")
+        ));
+        assert!(matches!(
+            verifier.verify_message(&with_proof(ChainId::Ethereum, 0)).await,
+            Err(VerificationError::InvalidFormat(ref e)) if e == "empty proof"
+        ));
+        assert!(verifier.verify_raw(ChainId::Ethereum, &[1; 9], b"input").await.is_err());
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // Chains without registered capabilities have no limit
+        assert!(verifier.verify_message(&with_proof(ChainId::Solana, 1024)).await.unwrap());
+    }
+
+    #[test]
+    fn test_error_codes_and_retryability() {
+        let timeout = VerificationError::Backend(ZkError::Timeout(Duration::from_secs(5)));
+        assert_eq!(timeout.code(), "backend");
+        assert!(timeout.is_retryable());
+        assert!(!VerificationError::Backend(ZkError::Backend("bad proof".to_string())).is_retryable());
+
+        let system = VerificationError::System("disk full".to_string());
+        assert_eq!((system.code(), system.is_retryable()), ("system", true));
+
+        // Redaction keeps retryability
+        let exhausted = VerificationError::Backend(ZkError::ResourceExhausted("gpu 0 out of memory".to_string()));
+        for error in [timeout, exhausted, system] {
+            let redacted = error.redacted();
+            assert!(redacted.is_retryable());
+            assert_eq!(redacted.code(), error.code());
+        }
+        assert!(matches!(
+            VerificationError::Backend(ZkError::Timeout(Duration::from_secs(5))).redacted(),
+            VerificationError::Backend(ZkError::Timeout(duration)) if duration == Duration::from_secs(5)
+        ));
+        assert!(!VerificationError::Backend(ZkError::ResourceExhausted("/dev/nvidia0".to_string()))
+            .redacted()
+            .to_string()
+            .contains("nvidia"));
+
+        for (error, code) in [
+            (VerificationError::InvalidFormat("empty proof".to_string()), "invalid_format"),
+            (VerificationError::MissingProof, "missing_proof"),
+            (VerificationError::InvalidChainId, "invalid_chain_id"),
+            (VerificationError::ProgramNotFound(ChainId::Solana), "program_not_found"),
+        ] {
+            assert_eq!(error.code(), code);
+            assert!(!error.is_retryable());
+            // Codes and retryability survive redaction
+            assert_eq!(error.redacted().code(), code);
+            assert!(!error.redacted().is_retryable());
+        }
+    }
+
+    #[test]
+    fn test_describe_input() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            input_endianness: HashMap::from([(ChainId::Solana, Endianness::Little)]),
+            ..test_config()
+        });
+
+        for from_chain in [ChainId::Ethereum, ChainId::Solana] {
+            let message = test_message(from_chain, ChainId::Polkadot, b"test");
+            let segments = verifier.describe_input(&message).unwrap();
+
+            let labels: Vec<&str> = segments.iter().map(|(label, _)| label.as_str()).collect();
+            assert_eq!(labels, ["from_chain", "to_chain", "payload_len", "payload", "nonce", "timestamp"]);
+            assert_eq!(segments[3].1, b"test");
+            let concatenated: Vec<u8> = segments.into_iter().flat_map(|(_, bytes)| bytes).collect();
+            assert_eq!(concatenated, verifier.encode_input(&message).unwrap());
+        }
+    }
+
+    // In-memory params loader counting how often it is asked for parameters
+    #[derive(Debug, Default)]
+    struct MemoryParamsLoader {
+        params: HashMap<ChainId, Vec<u8>>,
+        loads: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ParamsLoader for MemoryParamsLoader {
+        async fn load_params(&self, chain_id: ChainId) -> VerificationResult<Vec<u8>> {
+            self.loads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.params.get(&chain_id).cloned().ok_or(VerificationError::InvalidChainId)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_params() {
+        let vk = b"groth16-vk".to_vec();
+        let loader = Arc::new(MemoryParamsLoader {
+            params: HashMap::from([(ChainId::Ethereum, vk.clone())]),
+            ..Default::default()
+        });
+        let backend = Arc::new(RecordingBackend::default());
+        let mut verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            params_loader: Some(loader.clone()),
+            ..test_config()
+        });
+
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        assert!(verifier.verify_message_with_params(&message).await.unwrap());
+        assert!(verifier.verify_message_with_params(&message).await.unwrap());
+        assert_eq!(*backend.params.lock(), vec![vk.clone(), vk]);
+        assert_eq!(loader.loads.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(verifier.cached_chains(), vec![ChainId::Ethereum]);
+
+        // Missing parameters fail without reaching the backend
+        let other = test_message(ChainId::Solana, ChainId::Polkadot, b"test");
+        assert!(verifier.verify_message_with_params(&other).await.is_err());
+        assert_eq!(backend.params.lock().len(), 2);
+
+        verifier.clear_cache().await.unwrap();
+        verifier.verify_message_with_params(&message).await.unwrap();
+        assert_eq!(loader.loads.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    // Verifier with parameters for every chain, so that all message entry
+    // points can reach the backend
+    fn entry_point_verifier(backend: Arc<RecordingBackend>, config: VerifierConfig) -> MessageVerifier<RecordingBackend> {
+        let loader = MemoryParamsLoader {
+            params: [ChainId::Ethereum, ChainId::Polkadot, ChainId::Solana]
+                .into_iter()
+                .map(|chain_id| (chain_id, b"vk".to_vec()))
+                .collect(),
+            ..Default::default()
+        };
+        MessageVerifier::from_config(backend, VerifierConfig {
+            params_loader: Some(Arc::new(loader)),
+            ..config
+        })
+    }
+
+    // Result of verifying a message through each message entry point
+    async fn entry_point_results(
+        verifier: &MessageVerifier<RecordingBackend>,
+        message: &FrostMessage,
+    ) -> Vec<(&'static str, VerificationResult<bool>)> {
+        let programs = [b"program".to_vec()];
+        let tree = MerkleTree::from_programs(&programs, HashAlgorithm::default());
+        let merkle_proof = tree.proof(0).unwrap();
+        vec![
+            ("verify_message", verifier.verify_message(message).await),
+            ("traced", verifier.verify_message_traced(message).await.0),
+            ("params", verifier.verify_message_with_params(message).await),
+            ("chunked", verifier.verify_message_chunked(message).await),
+            (
+                "program_proof",
+                verifier
+                    .verify_message_with_program_proof(message, &programs[0], &merkle_proof, &tree.root(), tree.height())
+                    .await,
+            ),
+            ("inputs", verifier.verify_message_with_inputs(message, b"inputs").await),
+            ("block_hash", verifier.verify_message_with_block_hash(message, [0xB1; 32]).await),
+            ("outputs", verifier.verify_message_with_outputs(message).await.map(|(valid, _)| valid)),
+        ]
+    }
+
+    // Backend calls made through any entry point
+    fn backend_calls(backend: &RecordingBackend) -> usize {
+        backend.verifications.load(std::sync::atomic::Ordering::SeqCst)
+            + backend.inputs.lock().len()
+            + backend.params.lock().len()
+    }
+
+    #[tokio::test]
+    async fn test_entry_points_check_origin() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = entry_point_verifier(backend.clone(), VerifierConfig {
+            sender_allowlist: SenderAllowlist::new().allow_sender(ChainId::Ethereum, "0xbridge"),
+            ..test_config()
+        });
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::SENDER_KEY.to_string(), "0xbridge".to_string());
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            assert!(matches!(result, Ok(true)), "{}", entry_point);
+        }
+        let calls = backend_calls(&backend);
+
+        message.metadata.as_mut().unwrap()
+            .insert(crate::messages::SENDER_KEY.to_string(), "0xmallory".to_string());
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            assert!(matches!(result, Err(VerificationError::UnauthorizedSender { .. })), "{}", entry_point);
+        }
+        assert_eq!(backend_calls(&backend), calls);
+    }
+
+    #[tokio::test]
+    async fn test_entry_points_apply_route_requirements() {
+        let backend = Arc::new(RecordingBackend::default());
+        let routes = RouteRequirements::new()
+            .require(ChainId::Solana, ChainId::Ethereum, RequiredArtifacts::Signature);
+        #[cfg(feature = "signing")]
+        let signer = crate::messages::LocalSigner::from_bytes(&[3u8; 32]);
+        #[cfg(feature = "signing")]
+        let routes = routes.trust_signer(ChainId::Solana, ChainId::Ethereum, signer.public_key());
+        let verifier = entry_point_verifier(backend.clone(), VerifierConfig {
+            route_requirements: routes,
+            ..test_config()
+        });
+        let mut message = test_message(ChainId::Solana, ChainId::Ethereum, b"test");
+        message.proof = None;
+
+        // Signature-only routes are decided without a proof or backend call
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            #[cfg(feature = "signing")]
+            assert!(matches!(result, Ok(false)), "{}", entry_point);
+            #[cfg(not(feature = "signing"))]
+            assert!(matches!(result, Err(VerificationError::System(_))), "{}", entry_point);
+        }
+        #[cfg(feature = "signing")]
+        {
+            signer.sign_message(&mut message);
+            for (entry_point, result) in entry_point_results(&verifier, &message).await {
+                assert!(matches!(result, Ok(true)), "{}", entry_point);
+            }
+        }
+        assert_eq!(backend_calls(&backend), 0);
+    }
+
+    #[tokio::test]
+    async fn test_entry_points_require_program_hash() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = entry_point_verifier(backend.clone(), VerifierConfig {
+            require_program_hash: true,
+            ..test_config()
+        });
+        let (_, hash, _) = verifier.program_for(ChainId::Ethereum).await.unwrap();
+        let mut other = hash;
+        other[0] ^= 1;
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        message.proof.as_mut().unwrap().metadata.program_hash = hex::encode(other);
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            assert!(matches!(result, Err(VerificationError::ProgramHashMismatch { .. })), "{}", entry_point);
+        }
+        message.proof.as_mut().unwrap().metadata.program_hash = "dummy".to_string();
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            assert!(matches!(result, Err(VerificationError::MalformedProgramHash(_))), "{}", entry_point);
+        }
+        assert_eq!(backend_calls(&backend), 0);
+
+        // Caller-supplied programs are checked against their own hash
+        let program = b"program".to_vec();
+        let tree = MerkleTree::from_programs(std::slice::from_ref(&program), HashAlgorithm::default());
+        message.proof.as_mut().unwrap().metadata.program_hash = hex::encode(HashAlgorithm::default().digest(&program));
+        assert!(verifier
+            .verify_message_with_program_proof(&message, &program, &tree.proof(0).unwrap(), &tree.root(), tree.height())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_entry_points_capture_and_record() {
+        let backend = Arc::new(RecordingBackend {
+            reject: true,
+            ..Default::default()
+        });
+        let recorder = Arc::new(SessionRecorder::new());
+        let verifier = entry_point_verifier(backend.clone(), VerifierConfig {
+            capture_failures: true,
+            session_recorder: Some(recorder.clone()),
+            ..test_config()
+        });
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        let results = entry_point_results(&verifier, &message).await;
+        for (entry_point, result) in &results {
+            assert!(matches!(result, Ok(false)), "{}", entry_point);
+        }
+        let failures = verifier.take_failures();
+        assert_eq!(failures.len(), results.len());
+        assert_eq!(recorder.entries().len(), results.len());
+        assert!(failures.iter().any(|failure| failure.input == b"inputs"));
+    }
+
+    #[tokio::test]
+    async fn test_entry_points_share_result_cache() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = entry_point_verifier(backend.clone(), VerifierConfig {
+            result_cache_size: 16,
+            ..test_config()
+        });
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        entry_point_results(&verifier, &message).await;
+        let calls = backend_calls(&backend);
+
+        // Only public outputs are never served from the cache
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            assert!(matches!(result, Ok(true)), "{}", entry_point);
+        }
+        assert_eq!(backend_calls(&backend), calls + 1);
+    }
+
+    #[cfg(feature = "kzg")]
+    #[tokio::test]
+    async fn test_entry_points_check_kzg() {
+        let backend = Arc::new(RecordingBackend::default());
+        let routes = RouteRequirements::new()
+            .require(ChainId::Solana, ChainId::Ethereum, RequiredArtifacts::Signature);
+        #[cfg(feature = "signing")]
+        let signer = crate::messages::LocalSigner::from_bytes(&[3u8; 32]);
+        #[cfg(feature = "signing")]
+        let routes = routes.trust_signer(ChainId::Solana, ChainId::Ethereum, signer.public_key());
+        let verifier = entry_point_verifier(backend.clone(), VerifierConfig {
+            kzg: Some(Arc::new(KzgVerifier::ethereum())),
+            route_requirements: routes,
+            ..test_config()
+        });
+        let (commitment, proof) = kzg::tests::open(&kzg::tests::blob(3));
+        let with_bad_opening = |from_chain, to_chain| {
+            let mut message = test_message(from_chain, to_chain, &kzg::tests::blob(4));
+            message.set_attachment(KZG_COMMITMENT_ATTACHMENT, commitment.clone());
+            message.set_attachment(KZG_PROOF_ATTACHMENT, proof.clone());
+            message
+        };
+
+        let message = with_bad_opening(ChainId::Ethereum, ChainId::Polkadot);
+        for (entry_point, result) in entry_point_results(&verifier, &message).await {
+            assert!(matches!(result, Err(VerificationError::Kzg(KzgError::InvalidOpening))), "{}", entry_point);
+        }
+        #[cfg(feature = "signing")]
+        {
+            let mut message = with_bad_opening(ChainId::Solana, ChainId::Ethereum);
+            signer.sign_message(&mut message);
+            for (entry_point, result) in entry_point_results(&verifier, &message).await {
+                assert!(matches!(result, Err(VerificationError::Kzg(KzgError::InvalidOpening))), "{}", entry_point);
+            }
+        }
+        assert_eq!(backend_calls(&backend), 0);
+    }
+
+    #[tokio::test]
+    async fn test_verify_with_client_quota() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            quota: Some(Arc::new(QuotaGuard::new(2, Duration::from_secs(3600)))),
+            ..test_config()
+        });
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        assert!(verifier.verify_message_for_client("alice", &message).await.unwrap());
+        assert!(verifier.verify_message_for_client("alice", &message).await.unwrap());
+        let result = verifier.verify_message_for_client("alice", &message).await;
+        assert!(matches!(result, Err(VerificationError::QuotaExceeded { ref client_id, .. }) if client_id == "alice"));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        assert!(verifier.verify_message_for_client("bob", &message).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_log() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        let first = test_message(ChainId::Ethereum, ChainId::Polkadot, b"first");
+        let unproven = FrostMessage::new(ChainId::Solana, ChainId::Ethereum, b"second".to_vec(), 2, 1_725_000_000);
+        let broken_id = Uuid::new_v4();
+        let log = [
+            serde_json::to_string(&first).unwrap(),
+            String::new(),
+            "not json".to_string(),
+            serde_json::to_string(&unproven).unwrap(),
+            format!(r#"{{"id":"{}","payload":5}}"#, broken_id),
+        ]
+        .join("\n");
+
+        let results: Vec<_> = verifier.verify_log(log.as_bytes()).collect().await;
+        assert_eq!(results.len(), 4);
+        assert!(matches!(&results[0], (id, Ok(true)) if *id == first.id));
+        assert!(matches!(&results[1], (id, Err(VerificationError::InvalidFormat(e))) if id.is_nil() && e.contains("line 3")));
+        assert!(matches!(&results[2], (id, Err(VerificationError::MissingProof)) if *id == unproven.id));
+        assert!(matches!(&results[3], (id, Err(VerificationError::InvalidFormat(_))) if *id == broken_id));
+    }
+
+    #[tokio::test]
+    async fn test_verify_frames() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), test_config());
+        let first = test_message(ChainId::Ethereum, ChainId::Polkadot, b"first");
+        let unproven = FrostMessage::new(ChainId::Solana, ChainId::Ethereum, b"second".to_vec(), 2, 1_725_000_000);
+        let frames: Vec<Result<Vec<u8>, String>> = vec![
+            Ok(serde_json::to_vec(&first).unwrap()),
+            Ok(b"{not json".to_vec()),
+            Err("connection reset".to_string()),
+            Ok(serde_json::to_vec(&unproven).unwrap()),
         ];
 
-        // Test batch verification
-        let results = verifier.verify_messages_batch(&messages).await;
-        assert!(results.is_ok());
-        assert_eq!(results.unwrap().len(), 2);
+        let results: Vec<_> = verifier.verify_frames(futures::stream::iter(frames)).collect().await;
+        assert_eq!(results.len(), 4);
+        assert!(matches!(&results[0], (id, Ok(true)) if *id == first.id));
+        assert!(matches!(&results[1], (id, Err(VerificationError::InvalidFormat(e))) if id.is_nil() && e.contains("frame 2")));
+        assert!(matches!(&results[2], (id, Err(VerificationError::System(e))) if id.is_nil() && e.contains("connection reset")));
+        assert!(matches!(&results[3], (id, Err(VerificationError::MissingProof)) if *id == unproven.id));
     }
 
     #[tokio::test]
-    async fn test_program_cache() {
-        // Create backend and verifier with small cache
-        let backend = Arc::new(MockBackend);
-        let verifier = MessageVerifier::with_config(backend, 2, 1);
+    async fn test_verify_stream() {
+        let backend = Arc::new(ConcurrencyBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            max_batch_concurrency: Some(3),
+            max_concurrent_verifications: 16,
+            ..test_config()
+        });
 
-        // Test program loading
-        let program1 = verifier.get_program(ChainId::Ethereum).await;
-        assert!(program1.is_ok());
+        let (sender, receiver) = tokio::sync::mpsc::channel(4);
+        let producer = tokio::spawn(async move {
+            for i in 0..10u8 {
+                let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, &[i]);
+                message.proof.as_mut().unwrap().data = vec![i];
+                if i == 4 {
+                    message.proof = None;
+                }
+                sender.send(message).await.unwrap();
+            }
+        });
+        let messages = futures::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|message| (message, receiver))
+        });
 
-        let program2 = verifier.get_program(ChainId::Polkadot).await;
-        assert!(program2.is_ok());
+        let results: HashMap<Uuid, VerificationResult<bool>> = verifier.verify_stream(messages).collect().await;
+        producer.await.unwrap();
+        assert_eq!(results.len(), 10);
+        assert_eq!(results.values().filter(|result| matches!(result, Ok(true))).count(), 5);
+        assert_eq!(results.values().filter(|result| matches!(result, Err(VerificationError::MissingProof))).count(), 1);
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
 
-        let program3 = verifier.get_program(ChainId::Solana).await;
-        assert!(program3.is_ok());
+    #[tokio::test]
+    async fn test_batch_skips_verified_messages() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let messages: Vec<_> = (0..4)
+            .map(|i| test_message(ChainId::Ethereum, ChainId::Polkadot, &[i]))
+            .collect();
+        let verified: std::collections::HashSet<Uuid> = [messages[0].id, messages[2].id].into();
 
-        // Check cache size is maintained
-        assert_eq!(verifier.program_cache.read().len(), 2);
+        let results = verifier
+            .verify_messages_batch_skipping(&messages, |m| verified.contains(&m.id))
+            .await
+            .unwrap();
+        assert_eq!(
+            results,
+            vec![BatchResult::Skipped, BatchResult::Verified(true), BatchResult::Skipped, BatchResult::Verified(true)]
+        );
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // The remaining messages are verified concurrently
+        let backend = Arc::new(ConcurrencyBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            max_batch_concurrency: Some(4),
+            max_concurrent_verifications: 16,
+            ..test_config()
+        });
+        let results = verifier
+            .verify_messages_batch_skipping(&messages, |m| m.id == messages[0].id)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0], BatchResult::Skipped);
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_proof_freshness() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), test_config());
+        let mut message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        assert!(matches!(
+            verifier.verify_message_with_freshness(&message, 1_000, 10).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+
+        message.metadata.as_mut().unwrap().insert(PROOF_BLOCK_HEIGHT_KEY.to_string(), "990".to_string());
+        assert!(verifier.verify_message_with_freshness(&message, 1_000, 10).await.unwrap());
+        assert!(matches!(
+            verifier.verify_message_with_freshness(&message, 1_011, 10).await,
+            Err(VerificationError::StaleProof { proof_height: 990, current_height: 1_011, max_lag: 10 })
+        ));
+        assert!(matches!(
+            verifier.verify_message_with_freshness(&message, 989, 10).await,
+            Err(VerificationError::InvalidFormat(_))
+        ));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_verification_confidence() {
+        let verifier = MessageVerifier::from_config(Arc::new(MockBackend), VerifierConfig {
+            finality: HashMap::from([(ChainId::Ethereum, FinalityType::Probabilistic { confirmations: 12 })]),
+            ..test_config()
+        });
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+
+        for (confirmations, expected) in [(0, 0.0), (3, 0.25), (6, 0.5), (12, 1.0), (40, 1.0)] {
+            let (valid, confidence) = verifier.verify_message_with_confidence(&message, confirmations).await.unwrap();
+            assert!(valid);
+            assert_eq!(confidence, expected, "{} confirmations", confirmations);
+        }
+
+        // Chains without probabilistic finality are fully confident
+        let solana = test_message(ChainId::Solana, ChainId::Polkadot, b"test");
+        assert_eq!(verifier.verify_message_with_confidence(&solana, 0).await.unwrap(), (true, 1.0));
+
+        let rejecting = MessageVerifier::from_config(Arc::new(RejectingBackend), test_config());
+        assert_eq!(rejecting.verify_message_with_confidence(&message, 12).await.unwrap(), (false, 0.0));
+    }
+
+    #[test]
+    fn test_redacted_error() {
+        let error = VerificationError::System(
+            "failed to read /opt/frostgate/programs/eth_verifier.sp1".to_string(),
+        );
+
+        assert!(error.to_string().contains("/opt/frostgate"));
+
+        let redacted = error.redacted().to_string();
+        assert!(redacted.contains("System error"));
+        assert!(!redacted.contains("/opt/frostgate"));
+
+        let redacted = VerificationError::InvalidFormat("bad".to_string())
+            .with_verbosity(ErrorVerbosity::Redacted);
+        assert!(matches!(redacted, VerificationError::InvalidFormat(ref s) if s == REDACTED));
+        assert!(matches!(VerificationError::MissingProof.redacted(), VerificationError::MissingProof));
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_optimistic_verification() {
+        let backend = Arc::new(ConcurrencyBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            strategy: VerificationStrategy::Optimistic {
+                challenge_window: Duration::from_secs(60),
+            },
+            ..test_config()
+        });
+
+        let valid = test_message(ChainId::Ethereum, ChainId::Polkadot, b"valid");
+        let mut invalid = test_message(ChainId::Ethereum, ChainId::Polkadot, b"invalid");
+        invalid.proof.as_mut().unwrap().data = vec![2];
+        let before = Instant::now();
+        assert!(verifier.verify_message(&valid).await.unwrap());
+        assert!(verifier.verify_message(&invalid).await.unwrap());
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert_eq!(verifier.pending_challenge_count(), 2);
+        assert!(verifier.next_challenge_deadline().unwrap() >= before + Duration::from_secs(60));
+
+        let outcomes = verifier.verify_pending_challenges().await;
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].message_id, valid.id);
+        assert!(matches!(outcomes[0].result, Ok(true)));
+        assert_eq!(outcomes[1].message_id, invalid.id);
+        assert!(matches!(outcomes[1].result, Ok(false)));
+        assert!(outcomes.iter().all(|outcome| !outcome.window_closed));
+        assert_eq!(verifier.pending_challenge_count(), 0);
+        assert!(verifier.verify_pending_challenges().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_optimistic_verification_checks_and_bounds() {
+        let backend = Arc::new(RecordingBackend::default());
+        let verifier = MessageVerifier::from_config(backend.clone(), VerifierConfig {
+            strategy: VerificationStrategy::Optimistic {
+                challenge_window: Duration::ZERO,
+            },
+            max_pending_challenges: 1,
+            ..test_config()
+        });
+
+        // Structural failures are reported at once instead of being queued
+        let mut missing = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        missing.proof = None;
+        assert!(matches!(verifier.verify_message(&missing).await, Err(VerificationError::MissingProof)));
+        let mut empty = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        empty.proof.as_mut().unwrap().data.clear();
+        assert!(matches!(verifier.verify_message(&empty).await, Err(VerificationError::InvalidFormat(_))));
+        assert_eq!(verifier.pending_challenge_count(), 0);
+
+        // The queue is bounded
+        let message = test_message(ChainId::Ethereum, ChainId::Polkadot, b"test");
+        assert!(verifier.verify_message(&message).await.unwrap());
+        assert!(matches!(verifier.verify_message(&message).await, Err(VerificationError::System(_))));
+        assert_eq!(backend.verifications.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        // Verification after the window closed is flagged
+        let outcomes = verifier.verify_pending_challenges().await;
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(outcomes[0].result, Ok(true)));
+        assert!(outcomes[0].window_closed);
+        assert!(verifier.verify_message(&message).await.unwrap());
+    }
+}
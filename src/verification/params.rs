@@ -0,0 +1,53 @@
+//! Trusted setup parameters for proof systems that need them
+//!
+//! Groth16-style systems verify against a verification key produced by a
+//! trusted setup rather than against the program alone. Parameters are
+//! resolved per chain by a [`ParamsLoader`] and cached separately from
+//! verification programs.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use super::{VerificationError, VerificationResult};
+use crate::messages::ChainId;
+
+/// Source of verification parameters (e.g. a verification key) per chain
+#[async_trait]
+pub trait ParamsLoader: Send + Sync + std::fmt::Debug {
+    /// Load the verification parameters for a chain
+    async fn load_params(&self, chain_id: ChainId) -> VerificationResult<Vec<u8>>;
+}
+
+/// Loads verification parameters from per-chain files
+#[derive(Debug, Clone, Default)]
+pub struct FileParamsLoader {
+    paths: HashMap<ChainId, PathBuf>,
+}
+
+impl FileParamsLoader {
+    /// Create a loader with no parameter files
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the parameters file for a chain
+    pub fn with_path(mut self, chain_id: ChainId, path: impl Into<PathBuf>) -> Self {
+        self.paths.insert(chain_id, path.into());
+        self
+    }
+}
+
+#[async_trait]
+impl ParamsLoader for FileParamsLoader {
+    async fn load_params(&self, chain_id: ChainId) -> VerificationResult<Vec<u8>> {
+        let path = self.paths.get(&chain_id).ok_or_else(|| {
+            VerificationError::System(format!("No parameters file configured for {:?}", chain_id))
+        })?;
+        fs::read(path).map_err(|e| {
+            VerificationError::System(format!("Failed to read parameters file {}: {}", path.display(), e))
+        })
+    }
+}
@@ -0,0 +1,179 @@
+//! Per-client verification quotas
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use super::VerificationError;
+
+/// Source of the current time, replaceable in tests
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Get the current instant
+    fn now(&self) -> Instant;
+}
+
+/// [`Clock`] reading the system monotonic clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Remaining allowance of a single client
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Limits how many verifications each client may run per time window.
+///
+/// Each client has a token bucket holding up to `max_verifications` tokens,
+/// refilled continuously at `max_verifications` per `window`. A verification
+/// takes one token; when none is left the request is rejected with
+/// [`VerificationError::QuotaExceeded`].
+#[derive(Debug)]
+pub struct QuotaGuard {
+    max_verifications: u32,
+    window: Duration,
+    clock: Arc<dyn Clock>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl QuotaGuard {
+    /// Create a guard allowing `max_verifications` per `window` per client
+    pub fn new(max_verifications: u32, window: Duration) -> Self {
+        Self::with_clock(max_verifications, window, Arc::new(SystemClock))
+    }
+
+    /// Create a guard reading time from the given clock
+    pub fn with_clock(max_verifications: u32, window: Duration, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            max_verifications,
+            window,
+            clock,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take one verification from the client's quota
+    pub fn try_acquire(&self, client_id: &str) -> Result<(), VerificationError> {
+        let now = self.clock.now();
+        let mut buckets = self.buckets.lock();
+        let bucket = buckets.entry(client_id.to_string()).or_insert(Bucket {
+            tokens: f64::from(self.max_verifications),
+            updated: now,
+        });
+        self.refill(bucket, now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+        let retry_after = match self.rate() {
+            rate if rate > 0.0 => Duration::from_secs_f64((1.0 - bucket.tokens) / rate),
+            _ => self.window,
+        };
+        Err(VerificationError::QuotaExceeded {
+            client_id: client_id.to_string(),
+            retry_after,
+        })
+    }
+
+    /// Number of whole verifications the client can currently run
+    pub fn remaining(&self, client_id: &str) -> u32 {
+        let now = self.clock.now();
+        let mut buckets = self.buckets.lock();
+        match buckets.get_mut(client_id) {
+            Some(bucket) => {
+                self.refill(bucket, now);
+                bucket.tokens as u32
+            }
+            None => self.max_verifications,
+        }
+    }
+
+    /// Tokens refilled per second
+    fn rate(&self) -> f64 {
+        f64::from(self.max_verifications) / self.window.as_secs_f64()
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate()).min(f64::from(self.max_verifications));
+        bucket.updated = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Clock advanced manually by the test
+    #[derive(Debug)]
+    struct MockClock {
+        now: Mutex<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                now: Mutex::new(Instant::now()),
+            })
+        }
+
+        fn advance(&self, by: Duration) {
+            *self.now.lock() += by;
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            *self.now.lock()
+        }
+    }
+
+    #[test]
+    fn test_quota_exhaustion_and_refill() {
+        let clock = MockClock::new();
+        let guard = QuotaGuard::with_clock(3, Duration::from_secs(60), clock.clone());
+
+        for _ in 0..3 {
+            guard.try_acquire("alice").unwrap();
+        }
+        match guard.try_acquire("alice") {
+            Err(VerificationError::QuotaExceeded { client_id, retry_after }) => {
+                assert_eq!(client_id, "alice");
+                assert_eq!(retry_after, Duration::from_secs(20));
+            }
+            other => panic!("expected quota error, got {:?}", other),
+        }
+
+        // One token is refilled every 20 seconds
+        clock.advance(Duration::from_secs(20));
+        assert_eq!(guard.remaining("alice"), 1);
+        guard.try_acquire("alice").unwrap();
+        assert!(guard.try_acquire("alice").is_err());
+
+        // The bucket never holds more than the quota
+        clock.advance(Duration::from_secs(600));
+        assert_eq!(guard.remaining("alice"), 3);
+    }
+
+    #[test]
+    fn test_clients_are_independent() {
+        let clock = MockClock::new();
+        let guard = QuotaGuard::with_clock(1, Duration::from_secs(60), clock);
+
+        guard.try_acquire("alice").unwrap();
+        assert!(guard.try_acquire("alice").is_err());
+        guard.try_acquire("bob").unwrap();
+        assert_eq!(guard.remaining("carol"), 1);
+    }
+}
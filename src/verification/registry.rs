@@ -0,0 +1,41 @@
+//! Locations of the verification programs for each chain
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::messages::ChainId;
+
+/// Paths of the verification program files, per source chain
+///
+/// Supplied through
+/// [`MessageVerifier::with_config_and_registry`](super::MessageVerifier::with_config_and_registry).
+/// Chains without an entry fall back to the default program locations
+/// relative to the working directory.
+#[derive(Debug, Clone, Default)]
+pub struct ProgramRegistry {
+    paths: HashMap<ChainId, PathBuf>,
+}
+
+impl ProgramRegistry {
+    /// Create a registry with no programs registered
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the program file for a chain
+    pub fn register(mut self, chain_id: ChainId, path: impl Into<PathBuf>) -> Self {
+        self.paths.insert(chain_id, path.into());
+        self
+    }
+
+    /// Get the program file registered for a chain
+    pub fn path(&self, chain_id: ChainId) -> Option<&Path> {
+        self.paths.get(&chain_id).map(PathBuf::as_path)
+    }
+}
+
+impl From<HashMap<ChainId, PathBuf>> for ProgramRegistry {
+    fn from(paths: HashMap<ChainId, PathBuf>) -> Self {
+        Self { paths }
+    }
+}
@@ -0,0 +1,111 @@
+//! Per-route requirements on the artifacts a message must carry
+
+use std::collections::HashMap;
+
+use super::{VerificationError, VerificationResult};
+use crate::messages::{ChainId, FrostMessage};
+
+/// Artifacts a message must carry to be accepted on a route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RequiredArtifacts {
+    /// A valid ZK proof
+    #[default]
+    Proof,
+    /// A signature by one of the route's trusted signers; the proof is not
+    /// checked
+    Signature,
+    /// Both a trusted signature and a valid ZK proof
+    ProofAndSignature,
+}
+
+impl RequiredArtifacts {
+    /// Whether a ZK proof must be verified
+    pub fn requires_proof(&self) -> bool {
+        matches!(self, RequiredArtifacts::Proof | RequiredArtifacts::ProofAndSignature)
+    }
+
+    /// Whether a trusted signature must be verified
+    pub fn requires_signature(&self) -> bool {
+        matches!(self, RequiredArtifacts::Signature | RequiredArtifacts::ProofAndSignature)
+    }
+}
+
+/// Required artifacts per `(from_chain, to_chain)` route
+///
+/// Routes without an entry require a proof. Signatures are ed25519
+/// signatures over the message's canonical hash and are checked against
+/// the route's trusted signer keys, which needs the `signing` feature.
+#[derive(Debug, Clone, Default)]
+pub struct RouteRequirements {
+    routes: HashMap<(ChainId, ChainId), RequiredArtifacts>,
+    #[cfg(feature = "signing")]
+    signers: HashMap<(ChainId, ChainId), Vec<[u8; 32]>>,
+}
+
+impl RouteRequirements {
+    /// Create requirements demanding a proof on every route
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the artifacts required on a route
+    pub fn require(mut self, from_chain: ChainId, to_chain: ChainId, artifacts: RequiredArtifacts) -> Self {
+        self.routes.insert((from_chain, to_chain), artifacts);
+        self
+    }
+
+    /// Trust an ed25519 key to sign messages on a route
+    #[cfg(feature = "signing")]
+    pub fn trust_signer(mut self, from_chain: ChainId, to_chain: ChainId, public_key: [u8; 32]) -> Self {
+        self.signers.entry((from_chain, to_chain)).or_default().push(public_key);
+        self
+    }
+
+    /// Artifacts required on a route
+    pub fn requirement(&self, from_chain: ChainId, to_chain: ChainId) -> RequiredArtifacts {
+        self.routes.get(&(from_chain, to_chain)).copied().unwrap_or_default()
+    }
+
+    /// Artifacts required for a message's route
+    pub fn for_message(&self, message: &FrostMessage) -> RequiredArtifacts {
+        self.requirement(message.from_chain, message.to_chain)
+    }
+
+    /// Whether the message is signed by one of its route's trusted signers
+    ///
+    /// Without the `signing` feature signatures cannot be checked and a
+    /// [`VerificationError::System`] error is returned.
+    #[cfg_attr(not(feature = "signing"), allow(unused_variables))]
+    pub fn verify_signature(&self, message: &FrostMessage) -> VerificationResult<bool> {
+        #[cfg(feature = "signing")]
+        return Ok(self
+            .signers
+            .get(&(message.from_chain, message.to_chain))
+            .is_some_and(|keys| message.verify_signature_multi(keys).is_some()));
+        #[cfg(not(feature = "signing"))]
+        Err(VerificationError::System(
+            "route requires a signature but the `signing` feature is disabled".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_lookup() {
+        let routes = RouteRequirements::new()
+            .require(ChainId::Ethereum, ChainId::Polkadot, RequiredArtifacts::Signature)
+            .require(ChainId::Solana, ChainId::Ethereum, RequiredArtifacts::ProofAndSignature);
+
+        assert_eq!(routes.requirement(ChainId::Ethereum, ChainId::Polkadot), RequiredArtifacts::Signature);
+        // Routes are directional
+        assert_eq!(routes.requirement(ChainId::Polkadot, ChainId::Ethereum), RequiredArtifacts::Proof);
+
+        let both = routes.requirement(ChainId::Solana, ChainId::Ethereum);
+        assert!(both.requires_proof() && both.requires_signature());
+        assert!(!RequiredArtifacts::Signature.requires_proof());
+        assert!(!RequiredArtifacts::Proof.requires_signature());
+    }
+}
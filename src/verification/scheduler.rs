@@ -0,0 +1,249 @@
+//! Weighted fair scheduling of backend verifications across chains
+//!
+//! Backend calls share a fixed concurrency budget. Once the budget is used
+//! up, waiting verifications are admitted by stride scheduling: every
+//! admission advances the chain's virtual clock by `1 / weight`, and the
+//! waiting chain with the lowest clock goes next. A chain with a deep queue
+//! of slow verifications therefore cannot starve other chains, and a chain
+//! with weight 2 is admitted twice as often as one with weight 1 while both
+//! are waiting.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::oneshot;
+
+use crate::messages::ChainId;
+
+/// Weighted fair admission of verifications into a shared concurrency budget
+#[derive(Debug)]
+pub struct FairScheduler {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// Relative share per chain; unlisted chains have weight 1
+    weights: HashMap<ChainId, u32>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Unused slots of the concurrency budget
+    available: usize,
+    /// Virtual start time of the most recent admission
+    now: f64,
+    /// Virtual finish time of each chain's most recent admission
+    pass: HashMap<ChainId, f64>,
+    /// Waiters per chain, in arrival order
+    waiting: HashMap<ChainId, VecDeque<oneshot::Sender<FairPermit>>>,
+}
+
+/// Permission to run one verification; the slot is released on drop
+#[derive(Debug)]
+pub struct FairPermit {
+    inner: Option<Arc<Inner>>,
+}
+
+impl FairScheduler {
+    /// Create a scheduler admitting up to `capacity` concurrent verifications
+    pub fn new(capacity: usize, weights: HashMap<ChainId, u32>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                weights,
+                state: Mutex::new(State {
+                    available: capacity.max(1),
+                    ..Default::default()
+                }),
+            }),
+        }
+    }
+
+    /// Get the weight of a chain
+    pub fn weight(&self, chain_id: ChainId) -> u32 {
+        self.inner.weight(chain_id)
+    }
+
+    /// Number of unused slots in the concurrency budget
+    pub fn available(&self) -> usize {
+        self.inner.state.lock().available
+    }
+
+    /// Wait for a slot to verify a message from the given chain
+    pub async fn acquire(&self, chain_id: ChainId) -> FairPermit {
+        let receiver = {
+            let mut state = self.inner.state.lock();
+            if state.available > 0 && state.waiting.is_empty() {
+                state.available -= 1;
+                self.inner.charge(&mut state, chain_id);
+                return FairPermit::new(self.inner.clone());
+            }
+            let (sender, receiver) = oneshot::channel();
+            state.waiting.entry(chain_id).or_default().push_back(sender);
+            receiver
+        };
+        // Waiters are only removed from the queue to be sent a permit
+        receiver.await.expect("fair scheduler dropped a waiter")
+    }
+}
+
+impl Inner {
+    fn weight(&self, chain_id: ChainId) -> u32 {
+        self.weights.get(&chain_id).copied().unwrap_or(1).max(1)
+    }
+
+    /// Virtual time at which the chain's next admission would start
+    fn start(&self, state: &State, chain_id: ChainId) -> f64 {
+        state.pass.get(&chain_id).copied().unwrap_or(0.0).max(state.now)
+    }
+
+    /// Record an admission for the chain
+    fn charge(&self, state: &mut State, chain_id: ChainId) {
+        let start = self.start(state, chain_id);
+        state.now = start;
+        state.pass.insert(chain_id, start + 1.0 / f64::from(self.weight(chain_id)));
+    }
+
+    /// Take the next waiter to admit, charging its chain
+    fn next_waiter(&self, state: &mut State) -> Option<oneshot::Sender<FairPermit>> {
+        let chain_id = state
+            .waiting
+            .keys()
+            .copied()
+            .min_by(|a, b| {
+                self.start(state, *a)
+                    .total_cmp(&self.start(state, *b))
+                    .then(a.cmp(b))
+            })?;
+        let queue = state.waiting.get_mut(&chain_id)?;
+        let sender = queue.pop_front();
+        if queue.is_empty() {
+            state.waiting.remove(&chain_id);
+        }
+        self.charge(state, chain_id);
+        sender
+    }
+
+    /// Hand a released slot to the next waiter, or return it to the budget
+    fn release(self: &Arc<Self>) {
+        loop {
+            // Send outside the lock: a permit dropped by the channel releases
+            // the slot again, which takes the lock
+            let sender = {
+                let mut state = self.state.lock();
+                match self.next_waiter(&mut state) {
+                    Some(sender) => sender,
+                    None => {
+                        state.available += 1;
+                        return;
+                    }
+                }
+            };
+            match sender.send(FairPermit::new(self.clone())) {
+                Ok(()) => return,
+                // The waiter gave up; offer the slot to the next one
+                Err(permit) => permit.disarm(),
+            }
+        }
+    }
+}
+
+impl FairPermit {
+    fn new(inner: Arc<Inner>) -> Self {
+        Self { inner: Some(inner) }
+    }
+
+    /// Drop the permit without releasing its slot
+    fn disarm(mut self) {
+        self.inner = None;
+    }
+}
+
+impl Drop for FairPermit {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            inner.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn queue(scheduler: &Arc<FairScheduler>, order: &Arc<Mutex<Vec<ChainId>>>, chain_id: ChainId) {
+        let (scheduler, order) = (scheduler.clone(), order.clone());
+        tokio::spawn(async move {
+            let _permit = scheduler.acquire(chain_id).await;
+            order.lock().push(chain_id);
+        });
+        tokio::task::yield_now().await;
+    }
+
+    #[tokio::test]
+    async fn test_waiting_chain_is_not_starved() {
+        let scheduler = Arc::new(FairScheduler::new(1, HashMap::new()));
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let permit = scheduler.acquire(ChainId::Ethereum).await;
+        for _ in 0..3 {
+            queue(&scheduler, &order, ChainId::Ethereum).await;
+        }
+        queue(&scheduler, &order, ChainId::Solana).await;
+        drop(permit);
+        for _ in 0..4 {
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(
+            *order.lock(),
+            vec![ChainId::Solana, ChainId::Ethereum, ChainId::Ethereum, ChainId::Ethereum]
+        );
+        assert_eq!(scheduler.available(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_weighted_share() {
+        let weights = HashMap::from([(ChainId::Ethereum, 2)]);
+        let scheduler = Arc::new(FairScheduler::new(1, weights));
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let permit = scheduler.acquire(ChainId::Polkadot).await;
+        for _ in 0..4 {
+            queue(&scheduler, &order, ChainId::Polkadot).await;
+        }
+        for _ in 0..4 {
+            queue(&scheduler, &order, ChainId::Ethereum).await;
+        }
+        drop(permit);
+        for _ in 0..8 {
+            tokio::task::yield_now().await;
+        }
+
+        // Ethereum is admitted twice per Polkadot admission while both wait
+        let order = order.lock();
+        let first_six = &order[..6];
+        assert_eq!(first_six.iter().filter(|c| **c == ChainId::Ethereum).count(), 4);
+        assert_eq!(scheduler.weight(ChainId::Ethereum), 2);
+        assert_eq!(scheduler.weight(ChainId::Solana), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_waiter_does_not_leak_slot() {
+        let scheduler = Arc::new(FairScheduler::new(1, HashMap::new()));
+        let permit = scheduler.acquire(ChainId::Ethereum).await;
+
+        let waiter = {
+            let scheduler = scheduler.clone();
+            tokio::spawn(async move { scheduler.acquire(ChainId::Solana).await })
+        };
+        tokio::task::yield_now().await;
+        waiter.abort();
+        let _ = waiter.await;
+        drop(permit);
+
+        assert_eq!(scheduler.available(), 1);
+    }
+}
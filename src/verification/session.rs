@@ -0,0 +1,172 @@
+//! Recording and replaying verification sessions
+//!
+//! A [`SessionRecorder`] captures the exact backend inputs and outcome of each
+//! verification. Sessions are stored as newline-delimited JSON and can be fed
+//! to a new backend build with a [`SessionReplayer`] to detect regressions.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{VerificationError, VerificationResult};
+use frostgate_zkip::{ZkBackend, ZkResult};
+
+/// A single recorded verification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedVerification {
+    /// ID of the verified message
+    pub message_id: Uuid,
+    /// Program bytes passed to the backend
+    pub program: Vec<u8>,
+    /// Proof bytes passed to the backend
+    pub proof: Vec<u8>,
+    /// Public input constructed for the message
+    pub input: Vec<u8>,
+    /// Backend outcome, with errors rendered as strings
+    pub outcome: Result<bool, String>,
+}
+
+impl RecordedVerification {
+    /// Capture a verification and its backend result
+    pub fn new(message_id: Uuid, program: &[u8], proof: &[u8], input: &[u8], result: &ZkResult<bool>) -> Self {
+        Self {
+            message_id,
+            program: program.to_vec(),
+            proof: proof.to_vec(),
+            input: input.to_vec(),
+            outcome: result.as_ref().map(|valid| *valid).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Collects verifications for later replay
+#[derive(Debug, Default)]
+pub struct SessionRecorder {
+    entries: Mutex<Vec<RecordedVerification>>,
+}
+
+impl SessionRecorder {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a verification
+    pub fn record(&self, entry: RecordedVerification) {
+        self.entries.lock().push(entry);
+    }
+
+    /// Get a copy of the recorded verifications
+    pub fn entries(&self) -> Vec<RecordedVerification> {
+        self.entries.lock().clone()
+    }
+
+    /// Write the session as newline-delimited JSON
+    pub fn write_to<W: Write>(&self, writer: W) -> VerificationResult<()> {
+        let mut writer = BufWriter::new(writer);
+        for entry in self.entries.lock().iter() {
+            serde_json::to_writer(&mut writer, entry)
+                .map_err(|e| VerificationError::System(format!("Failed to encode session: {}", e)))?;
+            writer.write_all(b"\n")
+                .map_err(|e| VerificationError::System(format!("Failed to write session: {}", e)))?;
+        }
+        writer.flush()
+            .map_err(|e| VerificationError::System(format!("Failed to write session: {}", e)))
+    }
+
+    /// Save the session to a file
+    pub fn save(&self, path: impl AsRef<Path>) -> VerificationResult<()> {
+        let file = File::create(path)
+            .map_err(|e| VerificationError::System(format!("Failed to create session file: {}", e)))?;
+        self.write_to(file)
+    }
+}
+
+/// Difference between a recorded and replayed outcome
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayMismatch {
+    /// ID of the verified message
+    pub message_id: Uuid,
+    /// Outcome at recording time
+    pub recorded: Result<bool, String>,
+    /// Outcome from the replay backend
+    pub replayed: Result<bool, String>,
+}
+
+/// Summary of a session replay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplayReport {
+    /// Number of verifications replayed
+    pub total: usize,
+    /// Verifications whose outcome changed
+    pub mismatches: Vec<ReplayMismatch>,
+}
+
+impl ReplayReport {
+    /// Whether every replayed outcome matched the recording
+    pub fn is_clean(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Replays recorded verifications against a backend
+pub struct SessionReplayer<B: ZkBackend> {
+    backend: Arc<B>,
+}
+
+impl<B: ZkBackend> SessionReplayer<B> {
+    /// Create a replayer for the given backend
+    pub fn new(backend: Arc<B>) -> Self {
+        Self { backend }
+    }
+
+    /// Read a session written by [`SessionRecorder::write_to`]
+    pub fn read_from<R: BufRead>(reader: R) -> VerificationResult<Vec<RecordedVerification>> {
+        let mut entries = Vec::new();
+        for line in reader.lines() {
+            let line = line
+                .map_err(|e| VerificationError::System(format!("Failed to read session: {}", e)))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            entries.push(serde_json::from_str(&line)
+                .map_err(|e| VerificationError::InvalidFormat(format!("Invalid session entry: {}", e)))?);
+        }
+        Ok(entries)
+    }
+
+    /// Load a session from a file
+    pub fn load(path: impl AsRef<Path>) -> VerificationResult<Vec<RecordedVerification>> {
+        let file = File::open(path)
+            .map_err(|e| VerificationError::System(format!("Failed to open session file: {}", e)))?;
+        Self::read_from(BufReader::new(file))
+    }
+
+    /// Replay recorded verifications and report outcome differences
+    ///
+    /// Backend errors are compared by their rendered message, so a changed
+    /// error message is reported as a mismatch.
+    pub async fn replay(&self, entries: &[RecordedVerification]) -> ReplayReport {
+        let mut report = ReplayReport::default();
+        for entry in entries {
+            let replayed = self.backend
+                .verify(&entry.program, &entry.proof, None)
+                .await
+                .map_err(|e| e.to_string());
+            report.total += 1;
+            if replayed != entry.outcome {
+                report.mismatches.push(ReplayMismatch {
+                    message_id: entry.message_id,
+                    recorded: entry.outcome.clone(),
+                    replayed,
+                });
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,46 @@
+//! Reporting of backend verifications exceeding a latency threshold
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use crate::messages::ChainId;
+
+/// A backend verification that took longer than
+/// [`VerifierConfig::slow_threshold`](super::VerifierConfig::slow_threshold)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowVerification {
+    /// ID of the verified message
+    pub message_id: Uuid,
+    /// Source chain of the message
+    pub chain_id: ChainId,
+    /// Size of the proof passed to the backend, in bytes
+    pub proof_size: usize,
+    /// Time spent in the backend call
+    pub elapsed: Duration,
+}
+
+/// Callback invoked for every [`SlowVerification`]
+///
+/// The callback runs on the verifying task, so it should hand expensive
+/// work (e.g. persisting the proof) off elsewhere.
+#[derive(Clone)]
+pub struct SlowVerificationCallback(Arc<dyn Fn(&SlowVerification) + Send + Sync>);
+
+impl SlowVerificationCallback {
+    /// Wrap a callback
+    pub fn new(callback: impl Fn(&SlowVerification) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn call(&self, slow: &SlowVerification) {
+        (self.0)(slow)
+    }
+}
+
+impl std::fmt::Debug for SlowVerificationCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlowVerificationCallback").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,153 @@
+//! Program files shared between verifier instances
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+
+use super::HashAlgorithm;
+
+/// Program bytes and their hash
+type StoredProgram = (Arc<Vec<u8>>, [u8; 32]);
+
+/// Store entry, filled by the first load of its program
+type Slot = Arc<Mutex<Option<StoredProgram>>>;
+
+/// Programs read from disk, shared by every verifier configured with the
+/// same store
+///
+/// Each verifier keeps its own LRU cache, but on a cache miss a verifier
+/// with a [`VerifierConfig::program_store`](super::VerifierConfig::program_store)
+/// asks the store, which reads and hashes each program file at most once
+/// and hands every verifier the same bytes. Programs stay in the store until
+/// [`remove`](Self::remove)d or [`clear`](Self::clear)ed, and are not
+/// reloaded if the file changes.
+#[derive(Debug, Default)]
+pub struct ProgramStore {
+    programs: Mutex<HashMap<(PathBuf, HashAlgorithm), Slot>>,
+    reads: AtomicU64,
+}
+
+impl ProgramStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the process-wide store
+    pub fn global() -> Arc<ProgramStore> {
+        static GLOBAL: OnceLock<Arc<ProgramStore>> = OnceLock::new();
+        GLOBAL.get_or_init(|| Arc::new(ProgramStore::new())).clone()
+    }
+
+    /// Get a program and its hash, reading the file on first use
+    ///
+    /// Concurrent first requests for the same program wait for a single
+    /// read; loads of other programs are not blocked. Failed reads are not
+    /// remembered.
+    pub fn load(&self, path: &Path, algorithm: HashAlgorithm) -> io::Result<StoredProgram> {
+        let key = (path.to_path_buf(), algorithm);
+        let slot = self.programs.lock().entry(key.clone()).or_default().clone();
+        let mut program = slot.lock();
+        if let Some((bytes, hash)) = &*program {
+            return Ok((bytes.clone(), *hash));
+        }
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => Arc::new(bytes),
+            Err(e) => {
+                let mut programs = self.programs.lock();
+                if programs.get(&key).is_some_and(|current| Arc::ptr_eq(current, &slot)) {
+                    programs.remove(&key);
+                }
+                return Err(e);
+            }
+        };
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        let hash = algorithm.digest(&bytes);
+        *program = Some((bytes.clone(), hash));
+        Ok((bytes, hash))
+    }
+
+    /// Drop a program from the store, under every hash algorithm, returning
+    /// whether it was stored
+    ///
+    /// Verifiers keep their cached copy; the memory is freed once they
+    /// evict it too.
+    pub fn remove(&self, path: &Path) -> bool {
+        let mut programs = self.programs.lock();
+        let before = programs.len();
+        programs.retain(|(stored, _), _| stored != path);
+        programs.len() != before
+    }
+
+    /// Drop every program from the store
+    pub fn clear(&self) {
+        self.programs.lock().clear();
+    }
+
+    /// Number of program files read from disk
+    pub fn reads(&self) -> u64 {
+        self.reads.load(Ordering::Relaxed)
+    }
+
+    /// Number of stored programs, including loads in progress
+    pub fn len(&self) -> usize {
+        self.programs.lock().len()
+    }
+
+    /// Whether no programs are stored
+    pub fn is_empty(&self) -> bool {
+        self.programs.lock().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_file(contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("frostgate-program-{}.sp1", uuid::Uuid::new_v4()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_concurrent_loads_read_once() {
+        let store = Arc::new(ProgramStore::new());
+        let path = program_file(b"shared-program");
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (store, path) = (store.clone(), path.clone());
+                std::thread::spawn(move || store.load(&path, HashAlgorithm::default()).unwrap())
+            })
+            .collect();
+        let loaded: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+        assert_eq!(store.reads(), 1);
+        assert!(loaded.iter().all(|(bytes, _)| Arc::ptr_eq(bytes, &loaded[0].0)));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_failed_loads_and_removal() {
+        let store = ProgramStore::new();
+        let missing = std::env::temp_dir().join(format!("frostgate-missing-{}.sp1", uuid::Uuid::new_v4()));
+        assert!(store.load(&missing, HashAlgorithm::default()).is_err());
+        assert!(store.is_empty());
+
+        let path = program_file(b"program");
+        store.load(&path, HashAlgorithm::Blake2b).unwrap();
+        store.load(&path, HashAlgorithm::Sha256).unwrap();
+        assert_eq!((store.len(), store.reads()), (2, 2));
+
+        assert!(store.remove(&path));
+        assert!(!store.remove(&path));
+        assert!(store.is_empty());
+        store.load(&path, HashAlgorithm::Blake2b).unwrap();
+        assert_eq!(store.reads(), 3);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+//! Helpers shared by the verification tests
+
+use std::time::{Duration, SystemTime};
+
+use frostgate_zkip::types::{ProofMetadata, ResourceUsage};
+
+/// Implement `ZkBackend` for a test backend from the body of `verify`
+///
+/// The caller names `self` and the proof parameter so the body can use
+/// them. Proving returns an empty proof, the backend always reports itself
+/// healthy, and its resource usage is [`idle_usage`] unless a
+/// `resource_usage` body is given.
+macro_rules! test_backend {
+    ($backend:ty, |$this:ident, $proof:ident| $verify:expr) => {
+        test_backend!($backend, |$this, $proof| $verify, |self| $crate::verification::testing::idle_usage());
+    };
+    ($backend:ty, |$this:ident, $proof:ident| $verify:expr, |$usage_this:ident| $usage:expr) => {
+        #[::async_trait::async_trait]
+        impl ::frostgate_zkip::ZkBackend for $backend {
+            async fn prove(
+                &self,
+                _program: &[u8],
+                _input: &[u8],
+                _config: Option<&::frostgate_zkip::types::ZkConfig>,
+            ) -> ::frostgate_zkip::ZkResult<(Vec<u8>, ::frostgate_zkip::types::ProofMetadata)> {
+                Ok((Vec::new(), $crate::verification::testing::proof_metadata()))
+            }
+
+            async fn verify(
+                &$this,
+                _program: &[u8],
+                $proof: &[u8],
+                _config: Option<&::frostgate_zkip::types::ZkConfig>,
+            ) -> ::frostgate_zkip::ZkResult<bool> {
+                $verify
+            }
+
+            async fn health_check(&self) -> ::frostgate_zkip::types::HealthStatus {
+                ::frostgate_zkip::types::HealthStatus::Healthy
+            }
+
+            fn resource_usage(&$usage_this) -> ::frostgate_zkip::types::ResourceUsage {
+                $usage
+            }
+        }
+    };
+}
+
+/// Metadata of the proofs test backends generate
+pub(crate) fn proof_metadata() -> ProofMetadata {
+    ProofMetadata {
+        generation_time: Duration::from_secs(1),
+        proof_size: 4,
+        program_hash: "dummy".to_string(),
+        timestamp: SystemTime::now(),
+    }
+}
+
+/// Resource usage of an idle backend verifying one proof at a time
+pub(crate) fn idle_usage() -> ResourceUsage {
+    ResourceUsage {
+        cpu_usage: 0.0,
+        memory_usage: 0,
+        active_tasks: 0,
+        max_concurrent: 1,
+        queue_depth: 0,
+    }
+}
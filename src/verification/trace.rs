@@ -0,0 +1,82 @@
+//! Step-by-step verification traces for debugging
+
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::messages::ChainId;
+
+/// A single step taken while verifying a message
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceStep {
+    /// The message's origin, route, proof and payload were checked before
+    /// the backend
+    ChainValidation {
+        /// The message's source chain
+        chain_id: ChainId,
+        /// Whether the checks passed
+        valid: bool,
+    },
+    /// The verification program was resolved
+    ProgramLoad {
+        /// The chain the program belongs to
+        chain_id: ChainId,
+        /// Whether the program was served from cache
+        cache_hit: bool,
+        /// Hash of the program bytes
+        program_hash: [u8; 32],
+    },
+    /// The public input was constructed from the message fields
+    InputConstruction {
+        /// Labeled segments and their byte lengths, in encoding order
+        layout: Vec<(&'static str, usize)>,
+    },
+    /// The backend was asked to verify the proof
+    BackendCall {
+        /// Time spent in the backend
+        latency: Duration,
+        /// The backend's result, with errors rendered as strings
+        outcome: Result<bool, String>,
+    },
+}
+
+/// Structured log of the steps taken during a verification
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationTrace {
+    /// ID of the verified message
+    pub message_id: Uuid,
+    /// Steps in the order they were taken
+    pub steps: Vec<TraceStep>,
+}
+
+impl VerificationTrace {
+    /// Create an empty trace for a message
+    pub fn new(message_id: Uuid) -> Self {
+        Self {
+            message_id,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Append a step to the trace
+    pub fn record(&mut self, step: TraceStep) {
+        self.steps.push(step);
+    }
+
+    /// Whether the program was served from cache, if it was loaded
+    pub fn program_cache_hit(&self) -> Option<bool> {
+        self.steps.iter().find_map(|step| match step {
+            TraceStep::ProgramLoad { cache_hit, .. } => Some(*cache_hit),
+            _ => None,
+        })
+    }
+
+    /// Total byte length of the constructed input, if it was constructed
+    pub fn input_len(&self) -> Option<usize> {
+        self.steps.iter().find_map(|step| match step {
+            TraceStep::InputConstruction { layout } => {
+                Some(layout.iter().map(|(_, len)| len).sum())
+            }
+            _ => None,
+        })
+    }
+}
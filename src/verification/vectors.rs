@@ -0,0 +1,143 @@
+//! Deterministic verification test vectors for integration tests
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use frostgate_zkip::types::ProofMetadata;
+use rand_chacha::rand_core::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::messages::{ChainId, FrostMessage, Proof};
+use super::InputEncoding;
+
+/// A generated vector: the message, its expected public input bytes, and
+/// whether verification is expected to succeed
+pub type TestVector = (FrostMessage, Vec<u8>, bool);
+
+const CHAINS: [ChainId; 3] = [ChainId::Ethereum, ChainId::Polkadot, ChainId::Solana];
+const BASE_TIMESTAMP: u64 = 1_725_000_000;
+
+/// Builder for reproducible verification test vectors.
+///
+/// Every field of the generated messages, including the message ID, is
+/// drawn from a ChaCha RNG seeded with the given seed, so the same seed and
+/// settings always yield the same vectors. Vectors expected to verify carry
+/// random placeholder proof bytes, which mock backends accept; backends
+/// checking proofs cryptographically should replace them with real proofs
+/// of the expected input. Vectors expected to fail carry an empty proof,
+/// which the verifier rejects before reaching the backend.
+#[derive(Debug, Clone)]
+pub struct TestVectorBuilder {
+    seed: u64,
+    count: usize,
+    max_payload_len: usize,
+    invalid_ratio: f64,
+    encoding: InputEncoding,
+}
+
+impl TestVectorBuilder {
+    /// Create a builder generating vectors from `seed`
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            count: 16,
+            max_payload_len: 256,
+            invalid_ratio: 0.25,
+            encoding: InputEncoding::CURRENT,
+        }
+    }
+
+    /// Set the number of vectors to generate
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Set the maximum payload length (at least one byte is always generated)
+    pub fn max_payload_len(mut self, len: usize) -> Self {
+        self.max_payload_len = len.max(1);
+        self
+    }
+
+    /// Set the fraction of vectors expected to fail verification
+    pub fn invalid_ratio(mut self, ratio: f64) -> Self {
+        self.invalid_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the encoding used for the expected input bytes
+    pub fn encoding(mut self, encoding: InputEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Generate the vectors
+    pub fn build(&self) -> Vec<TestVector> {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        (0..self.count).map(|_| self.generate(&mut rng)).collect()
+    }
+
+    fn generate(&self, rng: &mut ChaCha8Rng) -> TestVector {
+        let from = rng.next_u32() as usize % CHAINS.len();
+        let to = (from + 1 + rng.next_u32() as usize % (CHAINS.len() - 1)) % CHAINS.len();
+
+        let mut payload = vec![0u8; 1 + rng.next_u32() as usize % self.max_payload_len];
+        rng.fill_bytes(&mut payload);
+        let nonce = rng.next_u64();
+        let timestamp = BASE_TIMESTAMP + rng.next_u32() as u64;
+
+        let mut id = [0u8; 16];
+        rng.fill_bytes(&mut id);
+        let valid = (rng.next_u64() as f64 / u64::MAX as f64) >= self.invalid_ratio;
+
+        let mut data = Vec::new();
+        if valid {
+            data.resize(32, 0);
+            rng.fill_bytes(&mut data);
+        }
+
+        let mut message = FrostMessage::new(CHAINS[from], CHAINS[to], payload, nonce, timestamp);
+        message.id = uuid::Builder::from_random_bytes(id).into_uuid();
+        message.proof = Some(Proof {
+            metadata: ProofMetadata {
+                generation_time: Duration::ZERO,
+                proof_size: data.len(),
+                program_hash: String::new(),
+                timestamp: UNIX_EPOCH + Duration::from_secs(timestamp),
+            },
+            data,
+        });
+
+        let input = self.encoding.encode(&message);
+        (message, input, valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(vectors: &[TestVector]) -> Vec<String> {
+        vectors
+            .iter()
+            .map(|vector| serde_json::to_string(vector).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_same_seed_identical_vectors() {
+        let first = TestVectorBuilder::new(42).count(32).build();
+        let second = TestVectorBuilder::new(42).count(32).build();
+        assert_eq!(first.len(), 32);
+        assert_eq!(fingerprint(&first), fingerprint(&second));
+
+        let other = TestVectorBuilder::new(43).count(32).build();
+        assert_ne!(fingerprint(&first), fingerprint(&other));
+
+        for (message, input, valid) in &first {
+            assert_ne!(message.from_chain, message.to_chain);
+            assert_eq!(message.id.get_version_num(), 4);
+            assert_eq!(input, &InputEncoding::CURRENT.encode(message));
+            assert_eq!(message.proof.as_ref().unwrap().data.is_empty(), !valid);
+        }
+    }
+}